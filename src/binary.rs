@@ -0,0 +1,255 @@
+// Copyright (c) 2019, Bayu Aldi Yansyah <bayualdiyansyah@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Native binary file format.
+//!
+//! A numeric vector or a matrix can be saved using [`Vector::save`] and
+//! [`Matrix::save`], and loaded back using [`Vector::load`] and
+//! [`Matrix::load`]. Loading validates the element type, the number of
+//! dimensions and the checksum of the file.
+//!
+//! # Format
+//! The file consists of the following fields, in order. The integers of the
+//! header and the checksum are in little endian.
+//!
+//! | Size         | Field                                                |
+//! |--------------|------------------------------------------------------|
+//! | 8 bytes      | Magic string `b"CRABSFMT"`                           |
+//! | 1 byte       | Format version, currently `1`                        |
+//! | 1 byte       | Byte order of the elements, `0` little, `1` big      |
+//! | 1 byte       | Size of the element type in bytes                    |
+//! | 1 byte       | Number of dimensions, `1` vector, `2` matrix         |
+//! | 1 byte       | Length of the element type name                      |
+//! | *n* bytes    | Element type name, for example `f64` or `i32`        |
+//! | 8 bytes each | Length of each dimension as `u64`                    |
+//! | *m* bytes    | Elements in row-major order                          |
+//! | 4 bytes      | CRC-32 checksum of all the preceding bytes as `u32`  |
+//!
+//! The elements are saved in the byte order of the platform and converted
+//! when loaded on a platform with a different byte order.
+//!
+//! # Examples
+//! ```
+//! # use crabsformer::prelude::*;
+//! # let path = std::env::temp_dir().join("crabsformer_doc_binary.bin");
+//! let w = matrix![1.0, 2.0; 3.0, 4.0];
+//! w.save(&path).unwrap();
+//! let v: Matrix<f64> = Matrix::load(&path).unwrap();
+//! assert_eq!(v, w);
+//! ```
+//!
+//! [`Vector::save`]: ../vector/struct.Vector.html#method.save
+//! [`Matrix::save`]: ../matrix/struct.Matrix.html#method.save
+//! [`Vector::load`]: ../vector/struct.Vector.html#method.load
+//! [`Matrix::load`]: ../matrix/struct.Matrix.html#method.load
+
+use crate::utils::{self, BinaryElement};
+use crate::vector::errors::{VectorLoadError, VectorLoadErrorKind};
+use std::io::{self, Read};
+use std::mem;
+
+const MAGIC: &[u8] = b"CRABSFMT";
+const VERSION: u8 = 1;
+
+// Number of elements converted to bytes at once
+const BLOCK_SIZE: usize = 8192;
+
+fn invalid_format(message: String) -> VectorLoadError {
+    VectorLoadError::new(VectorLoadErrorKind::InvalidFormat, message)
+}
+
+// Reader that computes the checksum of the bytes read
+struct ChecksumReader<R> {
+    inner: R,
+    crc: u32,
+}
+
+impl<R> ChecksumReader<R>
+where
+    R: io::Read,
+{
+    // Same as `read_exact` but reports the end of file as invalid format
+    fn read_bytes(&mut self, len: usize) -> Result<Vec<u8>, VectorLoadError> {
+        // Do not trust the header to allocate the buffer
+        let mut bytes = Vec::new();
+        self.by_ref().take(len as u64).read_to_end(&mut bytes)?;
+        if bytes.len() != len {
+            return Err(invalid_format(String::from(
+                "unexpected end of file",
+            )));
+        }
+        Ok(bytes)
+    }
+
+    fn read_u8(&mut self) -> Result<u8, VectorLoadError> {
+        Ok(self.read_bytes(1)?[0])
+    }
+
+    // Read bytes without updating the checksum
+    fn inner_bytes(
+        &mut self,
+        len: usize,
+    ) -> Result<Vec<u8>, VectorLoadError> {
+        let mut bytes = vec![0; len];
+        self.inner.read_exact(&mut bytes).map_err(|error| {
+            match error.kind() {
+                io::ErrorKind::UnexpectedEof => {
+                    invalid_format(String::from("unexpected end of file"))
+                }
+                _ => VectorLoadError::from(error),
+            }
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl<R> io::Read for ChecksumReader<R>
+where
+    R: io::Read,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let len = self.inner.read(buf)?;
+        self.crc = utils::crc32_update(self.crc, &buf[..len]);
+        Ok(len)
+    }
+}
+
+// Read array in native binary format from `reader`, returns its shape and
+// its elements in row-major order.
+pub(crate) fn read_array<T, R>(
+    reader: R,
+) -> Result<(Vec<usize>, Vec<T>), VectorLoadError>
+where
+    T: BinaryElement,
+    R: io::Read,
+{
+    let mut reader = ChecksumReader {
+        inner: reader,
+        crc: 0,
+    };
+    if reader.read_bytes(MAGIC.len())? != MAGIC {
+        return Err(invalid_format(String::from("invalid magic string")));
+    }
+    let version = reader.read_u8()?;
+    if version != VERSION {
+        return Err(invalid_format(format!(
+            "unsupported format version {}",
+            version
+        )));
+    }
+    let big_endian = match reader.read_u8()? {
+        0 => false,
+        1 => true,
+        order => {
+            return Err(invalid_format(format!(
+                "invalid byte order {}",
+                order
+            )))
+        }
+    };
+    let size = reader.read_u8()? as usize;
+    let ndim = reader.read_u8()? as usize;
+    let len = reader.read_u8()? as usize;
+    let name = String::from_utf8_lossy(&reader.read_bytes(len)?).into_owned();
+    if name != T::type_name() || size != mem::size_of::<T>() {
+        return Err(VectorLoadError::new(
+            VectorLoadErrorKind::InvalidDataType,
+            format!(
+                "cannot load {} ({} bytes) elements as {}",
+                name,
+                size,
+                T::type_name()
+            ),
+        ));
+    }
+
+    let mut shape = Vec::with_capacity(ndim);
+    for _ in 0..ndim {
+        let bytes = reader.read_bytes(8)?;
+        let len = u64::from_bytes(&bytes, false);
+        if len > usize::MAX as u64 {
+            return Err(VectorLoadError::new(
+                VectorLoadErrorKind::InvalidShape,
+                format!("dimension {} is too large", len),
+            ));
+        }
+        shape.push(len as usize);
+    }
+    let nbytes = shape
+        .iter()
+        .try_fold(size, |n, len| n.checked_mul(*len))
+        .ok_or_else(|| {
+            VectorLoadError::new(
+                VectorLoadErrorKind::InvalidShape,
+                format!("shape {:?} is too large", shape),
+            )
+        })?;
+    let bytes = reader.read_bytes(nbytes)?;
+
+    // The checksum field itself is not checksummed
+    let crc = reader.crc;
+    let expected = u32::from_bytes(&reader.inner_bytes(4)?, false);
+    if crc != expected {
+        return Err(invalid_format(String::from("checksum does not match")));
+    }
+    if reader.inner_bytes(1).is_ok() {
+        return Err(invalid_format(String::from(
+            "unexpected data after checksum",
+        )));
+    }
+
+    let data = bytes
+        .chunks(size)
+        .map(|chunk| T::from_bytes(chunk, big_endian))
+        .collect();
+    Ok((shape, data))
+}
+
+// Write `data`, the elements of array with the given shape in row-major
+// order, in native binary format.
+pub(crate) fn write_array<T, W>(
+    writer: &mut W,
+    shape: &[usize],
+    data: &[T],
+) -> io::Result<()>
+where
+    T: BinaryElement,
+    W: io::Write,
+{
+    let big_endian = cfg!(target_endian = "big");
+    let name = T::type_name();
+    let mut header = MAGIC.to_vec();
+    header.push(VERSION);
+    header.push(big_endian as u8);
+    header.push(mem::size_of::<T>() as u8);
+    header.push(shape.len() as u8);
+    header.push(name.len() as u8);
+    header.extend_from_slice(name.as_bytes());
+    for len in shape {
+        (*len as u64).write_bytes(&mut header, false);
+    }
+    writer.write_all(&header)?;
+    let mut crc = utils::crc32(&header);
+
+    let mut buf = Vec::with_capacity(BLOCK_SIZE * mem::size_of::<T>());
+    for block in data.chunks(BLOCK_SIZE) {
+        buf.clear();
+        for element in block {
+            element.write_bytes(&mut buf, big_endian);
+        }
+        writer.write_all(&buf)?;
+        crc = utils::crc32_update(crc, &buf);
+    }
+    writer.write_all(&crc.to_le_bytes())
+}
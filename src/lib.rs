@@ -467,7 +467,12 @@
 //! [Apache-2.0]: https://github.com/pyk/crabsformer/blob/master/LICENSE
 //!
 
+pub mod binary;
 pub mod matrix;
+pub mod npy;
 pub mod prelude;
+pub mod printing;
+pub mod random;
 pub mod utils;
 pub mod vector;
+pub mod wav;
@@ -17,6 +17,7 @@
 //! TODO(pyk): Add docs here
 //!
 
+use crate::printing::{self, MatrixDisplay, PrintOptions};
 use crate::vector::*;
 use num::Num;
 use std::fmt;
@@ -28,6 +29,8 @@ pub mod indexing;
 pub mod iterators;
 pub mod loaders;
 pub mod operations;
+#[cfg(feature = "serde")]
+mod serialization;
 pub mod slicing;
 
 /// Matrix.
@@ -66,6 +69,33 @@ where
     pub fn shape(&self) -> [usize; 2] {
         [self.nrows, self.ncols]
     }
+
+    /// Format the matrix with the given print options instead of the
+    /// [global print options].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let w = matrix![1.0, 2.5; 3.0, 4.0];
+    /// let options = PrintOptions {
+    ///     precision: Some(2),
+    ///     ..PrintOptions::default()
+    /// };
+    /// assert_eq!(
+    ///     format!("{}", w.display(options)),
+    ///     "[[1.00 2.50]\n [3.00 4.00]]"
+    /// );
+    /// ```
+    ///
+    /// [global print options]: ../printing/fn.set_print_options.html
+    pub fn display(&self, options: PrintOptions) -> MatrixDisplay<'_, T> {
+        MatrixDisplay {
+            data: self.vec.as_slice(),
+            shape: self.shape(),
+            options,
+        }
+    }
 }
 
 impl<T> fmt::Debug for Matrix<T>
@@ -73,33 +103,25 @@ where
     T: Num + Copy + fmt::Debug + ToString,
 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let mut output = String::from("[");
-        for (i, row) in self.rows().enumerate() {
-            let mut row_output = String::from("");
-            // add space to the next line
-            if i > 0 {
-                row_output += " ";
-            }
-            row_output += "[";
-            for (j, value) in row.elements().enumerate() {
-                row_output += &value.to_string();
-                // Don't add comma to the end of line
-                if j < self.ncols - 1 {
-                    row_output += ", ";
-                } else {
-                    row_output += "]";
-                }
-            }
-            // Don't add new line to the end of row
-            if i < self.ncols - 1 {
-                row_output += ",\n";
-            } else {
-                row_output += "]";
-            }
-            output += &row_output.to_string();
-        }
+        let rows: Vec<String> = self
+            .rows()
+            .map(|row| {
+                let elements: Vec<String> =
+                    row.elements().map(|value| value.to_string()).collect();
+                format!("[{}]", elements.join(", "))
+            })
+            .collect();
+        write!(f, "[{}]", rows.join(",\n "))
+    }
+}
 
-        write!(f, "{}", output)
+impl<T> fmt::Display for Matrix<T>
+where
+    T: Num + Copy + fmt::Display + fmt::LowerExp,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let options = printing::print_options();
+        fmt::Display::fmt(&self.display(options), f)
     }
 }
 
@@ -372,6 +394,16 @@ mod tests {
         println!("{:?}", w);
     }
 
+    #[test]
+    fn test_matrix_debug_non_square() {
+        let w = matrix![1, 2, 3; 4, 5, 6];
+        assert_eq!(format!("{:?}", w), "[[1, 2, 3],\n [4, 5, 6]]");
+        let w = matrix![1, 2; 3, 4; 5, 6];
+        assert_eq!(format!("{:?}", w), "[[1, 2],\n [3, 4],\n [5, 6]]");
+        let w: Matrix<i32> = Matrix::from(vec![vec![]]);
+        assert_eq!(format!("{:?}", w), "[[]]");
+    }
+
     #[test]
     fn test_submatrix_debug() {
         let w = matrix![
@@ -16,15 +16,42 @@
 //!
 //! TODO(pyk): Add docs here
 //!
+//! # Parsing Matrix from String
+//! Matrix can be parsed from a string using [`str::parse`], either in
+//! MATLAB-style syntax where the rows are separated by semicolons or new
+//! lines, or in NumPy-style syntax of nested lists:
 //!
+//! ```
+//! # use crabsformer::prelude::*;
+//! let w: Matrix<i32> = "1 2 3; 4 5 6".parse().unwrap();
+//! let v: Matrix<i32> = "[[1, 2, 3], [4, 5, 6]]".parse().unwrap();
+//! assert_eq!(w, matrix![1, 2, 3; 4, 5, 6]);
+//! assert_eq!(v, w);
+//! ```
+//!
+//! The error reports the byte position of the offending part of the string:
+//!
+//! ```
+//! # use crabsformer::prelude::*;
+//! let error = "1 2; 3 x".parse::<Matrix<i32>>().unwrap_err();
+//! assert_eq!(error.position(), 7);
+//! ```
+//!
+//! [`str::parse`]: https://doc.rust-lang.org/std/primitive.str.html#method.parse
 
-use crate::matrix::errors::MatrixBuilderError;
+use crate::matrix::errors::{
+    MatrixBuilderError, MatrixBuilderErrorKind, MatrixParseError,
+    MatrixParseErrorKind,
+};
 use crate::matrix::Matrix;
+use crate::random::{Generator, RandomState};
+use crate::utils::{self, ParseElement};
 use crate::vector::builders::RandomVectorBuilder;
 use crate::vector::Vector;
-use num::{FromPrimitive, Num};
+use num::{Float, FromPrimitive, Num};
 use rand::distributions::uniform::SampleUniform;
 use std::fmt;
+use std::str::FromStr;
 
 /// Creates a [matrix] containing the arguments.
 ///
@@ -119,6 +146,39 @@ where
     }
 }
 
+// Parse matrix from MATLAB-style or NumPy-style string
+impl<T> FromStr for Matrix<T>
+where
+    T: Num + Copy + ParseElement,
+{
+    type Err = MatrixParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parsed = utils::parse_rows(s)?;
+        let nrows = parsed.rows.len();
+        let ncols = parsed.rows.first().map_or(0, Vec::len);
+        if let Some(i) = parsed.rows.iter().position(|row| row.len() != ncols)
+        {
+            return Err(MatrixParseError::new(
+                MatrixParseErrorKind::InvalidShape,
+                format!(
+                    "row {} has {} elements, expected {}",
+                    i + 1,
+                    parsed.rows[i].len(),
+                    ncols
+                ),
+                parsed.positions[i],
+            ));
+        }
+        let elements: Vec<T> = parsed.rows.into_iter().flatten().collect();
+        Ok(Matrix {
+            nrows,
+            ncols,
+            vec: Vector::from(elements),
+        })
+    }
+}
+
 impl<T> Matrix<T>
 where
     T: Num + Copy,
@@ -257,6 +317,415 @@ where
     }
 }
 
+/// Indexing convention of the matrices returned by [`Matrix::meshgrid`].
+///
+/// [`Matrix::meshgrid`]: struct.Matrix.html#method.meshgrid
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MeshgridIndexing {
+    /// Cartesian indexing. Given numeric vectors `x` and `y` of length `m`
+    /// and `n`, the output matrices have shape `[n, m]`.
+    XY,
+    /// Matrix indexing. Given numeric vectors `x` and `y` of length `m`
+    /// and `n`, the output matrices have shape `[m, n]`.
+    IJ,
+}
+
+impl<T> Matrix<T>
+where
+    T: Num + Copy,
+{
+    /// Create a new matrix of given shape `shape` by calling `f(i, j)` for
+    /// each element at row `i` and column `j`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let w = Matrix::from_fn([2, 3], |i, j| (i * 10 + j) as i32);
+    /// assert_eq!(w, matrix![
+    ///     0, 1, 2;
+    ///     10, 11, 12;
+    /// ]);
+    /// ```
+    pub fn from_fn<F>(shape: [usize; 2], mut f: F) -> Matrix<T>
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        let nrows = shape[0];
+        let ncols = shape[1];
+        let mut data = Vec::with_capacity(nrows * ncols);
+        for i in 0..nrows {
+            for j in 0..ncols {
+                data.push(f(i, j));
+            }
+        }
+        let vec = Vector::from(data);
+        Matrix { nrows, ncols, vec }
+    }
+
+    /// Create coordinate matrices from coordinate numeric vectors `x` and
+    /// `y`.
+    ///
+    /// It returns a pair of matrices `(xx, yy)` that can be used to evaluate
+    /// functions on a grid. The shape of the matrices depends on `indexing`,
+    /// see [`MeshgridIndexing`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let x = vector![1, 2, 3];
+    /// let y = vector![4, 5];
+    ///
+    /// let (xx, yy) = Matrix::meshgrid(&x, &y, MeshgridIndexing::XY);
+    /// assert_eq!(xx, matrix![1, 2, 3; 1, 2, 3]);
+    /// assert_eq!(yy, matrix![4, 4, 4; 5, 5, 5]);
+    ///
+    /// let (xx, yy) = Matrix::meshgrid(&x, &y, MeshgridIndexing::IJ);
+    /// assert_eq!(xx, matrix![1, 1; 2, 2; 3, 3]);
+    /// assert_eq!(yy, matrix![4, 5; 4, 5; 4, 5]);
+    /// ```
+    ///
+    /// [`MeshgridIndexing`]: enum.MeshgridIndexing.html
+    pub fn meshgrid(
+        x: &Vector<T>,
+        y: &Vector<T>,
+        indexing: MeshgridIndexing,
+    ) -> (Matrix<T>, Matrix<T>) {
+        match indexing {
+            MeshgridIndexing::XY => {
+                let shape = [y.len(), x.len()];
+                let xx = Matrix::from_fn(shape, |_, j| x[j]);
+                let yy = Matrix::from_fn(shape, |i, _| y[i]);
+                (xx, yy)
+            }
+            MeshgridIndexing::IJ => {
+                let shape = [x.len(), y.len()];
+                let xx = Matrix::from_fn(shape, |i, _| x[i]);
+                let yy = Matrix::from_fn(shape, |_, j| y[j]);
+                (xx, yy)
+            }
+        }
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Num + Copy,
+{
+    /// Create a new square matrix with the elements of numeric vector `v`
+    /// on the diagonal and zeros elsewhere.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let w = Matrix::diag(&vector![1, 2, 3]);
+    /// assert_eq!(w, matrix![
+    ///     1, 0, 0;
+    ///     0, 2, 0;
+    ///     0, 0, 3;
+    /// ]);
+    /// ```
+    pub fn diag(v: &Vector<T>) -> Matrix<T> {
+        let n = v.len();
+        Matrix::from_fn([n, n], |i, j| if i == j { v[i] } else { T::zero() })
+    }
+
+    /// Get the `k`-th diagonal of the matrix as a new numeric vector.
+    ///
+    /// Use `k = 0` for the main diagonal, `k > 0` for diagonals above the
+    /// main diagonal and `k < 0` for diagonals below the main diagonal. If
+    /// `k` is out of the matrix, it returns an empty numeric vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let w = matrix![
+    ///     1, 2, 3;
+    ///     4, 5, 6;
+    ///     7, 8, 9;
+    /// ];
+    /// assert_eq!(w.diagonal(0), vector![1, 5, 9]);
+    /// assert_eq!(w.diagonal(1), vector![2, 6]);
+    /// assert_eq!(w.diagonal(-2), vector![7]);
+    /// ```
+    pub fn diagonal(&self, k: isize) -> Vector<T> {
+        // Position of the first element of the diagonal
+        let (row_start, col_start) = if k >= 0 {
+            (0, k as usize)
+        } else {
+            ((-k) as usize, 0)
+        };
+        let mut data = Vec::new();
+        let mut i = row_start;
+        let mut j = col_start;
+        while i < self.nrows && j < self.ncols {
+            data.push(*self.at(i, j));
+            i += 1;
+            j += 1;
+        }
+        Vector::from(data)
+    }
+
+    /// Create a new matrix of given shape `shape` with ones at and below
+    /// the `k`-th diagonal and zeros elsewhere.
+    ///
+    /// Use `k = 0` for the main diagonal, `k > 0` for diagonals above the
+    /// main diagonal and `k < 0` for diagonals below the main diagonal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let w: Matrix<i32> = Matrix::tri([3, 3], 0);
+    /// assert_eq!(w, matrix![
+    ///     1, 0, 0;
+    ///     1, 1, 0;
+    ///     1, 1, 1;
+    /// ]);
+    /// ```
+    pub fn tri(shape: [usize; 2], k: isize) -> Matrix<T> {
+        Matrix::from_fn(shape, |i, j| {
+            if (j as isize) - (i as isize) <= k {
+                T::one()
+            } else {
+                T::zero()
+            }
+        })
+    }
+
+    /// Create a copy of the matrix with elements above the `k`-th diagonal
+    /// zeroed (lower triangle of the matrix).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let w = matrix![
+    ///     1, 2, 3;
+    ///     4, 5, 6;
+    ///     7, 8, 9;
+    /// ];
+    /// assert_eq!(w.tril(0), matrix![
+    ///     1, 0, 0;
+    ///     4, 5, 0;
+    ///     7, 8, 9;
+    /// ]);
+    /// assert_eq!(w.tril(-1), matrix![
+    ///     0, 0, 0;
+    ///     4, 0, 0;
+    ///     7, 8, 0;
+    /// ]);
+    /// ```
+    pub fn tril(&self, k: isize) -> Matrix<T> {
+        Matrix::from_fn(self.shape(), |i, j| {
+            if (j as isize) - (i as isize) <= k {
+                *self.at(i, j)
+            } else {
+                T::zero()
+            }
+        })
+    }
+
+    /// Create a copy of the matrix with elements below the `k`-th diagonal
+    /// zeroed (upper triangle of the matrix).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let w = matrix![
+    ///     1, 2, 3;
+    ///     4, 5, 6;
+    ///     7, 8, 9;
+    /// ];
+    /// assert_eq!(w.triu(0), matrix![
+    ///     1, 2, 3;
+    ///     0, 5, 6;
+    ///     0, 0, 9;
+    /// ]);
+    /// assert_eq!(w.triu(1), matrix![
+    ///     0, 2, 3;
+    ///     0, 0, 6;
+    ///     0, 0, 0;
+    /// ]);
+    /// ```
+    pub fn triu(&self, k: isize) -> Matrix<T> {
+        Matrix::from_fn(self.shape(), |i, j| {
+            if (j as isize) - (i as isize) >= k {
+                *self.at(i, j)
+            } else {
+                T::zero()
+            }
+        })
+    }
+
+    /// Create a new Vandermonde matrix with `ncols` columns from numeric
+    /// vector `x`.
+    ///
+    /// If `increasing` is `false`, the powers decrease from left to right
+    /// (the last column is `x^0`). Otherwise, the first column is `x^0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let x = vector![1, 2, 3];
+    /// assert_eq!(Matrix::vander(&x, 3, false), matrix![
+    ///     1, 1, 1;
+    ///     4, 2, 1;
+    ///     9, 3, 1;
+    /// ]);
+    /// assert_eq!(Matrix::vander(&x, 3, true), matrix![
+    ///     1, 1, 1;
+    ///     1, 2, 4;
+    ///     1, 3, 9;
+    /// ]);
+    /// ```
+    pub fn vander(
+        x: &Vector<T>,
+        ncols: usize,
+        increasing: bool,
+    ) -> Matrix<T> {
+        Matrix::from_fn([x.len(), ncols], |i, j| {
+            let exp = if increasing { j } else { ncols - 1 - j };
+            num::pow(x[i], exp)
+        })
+    }
+
+    /// Create a new Toeplitz matrix with first column `c` and first row
+    /// `r`. The first element of `r` is ignored, the diagonal is taken from
+    /// `c`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let c = vector![1, 2, 3];
+    /// let r = vector![1, 4, 5, 6];
+    /// assert_eq!(Matrix::toeplitz(&c, &r), matrix![
+    ///     1, 4, 5, 6;
+    ///     2, 1, 4, 5;
+    ///     3, 2, 1, 4;
+    /// ]);
+    /// ```
+    pub fn toeplitz(c: &Vector<T>, r: &Vector<T>) -> Matrix<T> {
+        Matrix::from_fn([c.len(), r.len()], |i, j| {
+            if i >= j {
+                c[i - j]
+            } else {
+                r[j - i]
+            }
+        })
+    }
+
+    /// Create a new Hankel matrix with first column `c` and last row `r`.
+    /// The first element of `r` is ignored, the anti-diagonal is taken from
+    /// `c`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let c = vector![1, 2, 3];
+    /// let r = vector![3, 4, 5];
+    /// assert_eq!(Matrix::hankel(&c, &r), matrix![
+    ///     1, 2, 3;
+    ///     2, 3, 4;
+    ///     3, 4, 5;
+    /// ]);
+    /// ```
+    pub fn hankel(c: &Vector<T>, r: &Vector<T>) -> Matrix<T> {
+        let nrows = c.len();
+        Matrix::from_fn([nrows, r.len()], |i, j| {
+            if i + j < nrows {
+                c[i + j]
+            } else {
+                r[i + j + 1 - nrows]
+            }
+        })
+    }
+
+    /// Create a new circulant matrix with first column `c`. Each column is
+    /// the previous column rotated one element down.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let c = vector![1, 2, 3];
+    /// assert_eq!(Matrix::circulant(&c), matrix![
+    ///     1, 3, 2;
+    ///     2, 1, 3;
+    ///     3, 2, 1;
+    /// ]);
+    /// ```
+    pub fn circulant(c: &Vector<T>) -> Matrix<T> {
+        let n = c.len();
+        Matrix::from_fn([n, n], |i, j| c[(n + i - j) % n])
+    }
+
+    /// Create a new block diagonal matrix from the given `blocks`. Each
+    /// block is placed on the diagonal and the remaining elements are
+    /// zeros.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let a = matrix![1, 2; 3, 4];
+    /// let b = matrix![5];
+    /// assert_eq!(Matrix::block_diag(&[&a, &b]), matrix![
+    ///     1, 2, 0;
+    ///     3, 4, 0;
+    ///     0, 0, 5;
+    /// ]);
+    /// ```
+    pub fn block_diag(blocks: &[&Matrix<T>]) -> Matrix<T> {
+        let nrows = blocks.iter().map(|b| b.nrows).sum();
+        let ncols = blocks.iter().map(|b| b.ncols).sum();
+        let mut data = vec![T::zero(); nrows * ncols];
+        let mut row_offset = 0;
+        let mut col_offset = 0;
+        for block in blocks {
+            for i in 0..block.nrows {
+                for j in 0..block.ncols {
+                    let pos = (row_offset + i) * ncols + col_offset + j;
+                    data[pos] = *block.at(i, j);
+                }
+            }
+            row_offset += block.nrows;
+            col_offset += block.ncols;
+        }
+        let vec = Vector::from(data);
+        Matrix { nrows, ncols, vec }
+    }
+}
+
+impl Matrix<usize> {
+    /// Create a pair of matrices of given shape `shape` representing the
+    /// indices of a grid.
+    ///
+    /// The first matrix contains the row index of each element and the
+    /// second matrix contains the column index of each element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let (rows, cols) = Matrix::indices([2, 3]);
+    /// assert_eq!(rows, matrix![0, 0, 0; 1, 1, 1]);
+    /// assert_eq!(cols, matrix![0, 1, 2; 0, 1, 2]);
+    /// ```
+    pub fn indices(shape: [usize; 2]) -> (Matrix<usize>, Matrix<usize>) {
+        let rows = Matrix::from_fn(shape, |i, _| i);
+        let cols = Matrix::from_fn(shape, |_, j| j);
+        (rows, cols)
+    }
+}
+
 /// Random matrices builder.
 pub struct RandomMatrixBuilder {
     builder: RandomVectorBuilder,
@@ -275,6 +744,31 @@ impl RandomMatrixBuilder {
         RandomMatrixBuilder { builder }
     }
 
+    /// Creates new random matrix builder that draws the samples from the
+    /// given pseudo-random number generator.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let rmb = RandomMatrixBuilder::with_generator(Generator::Xoshiro256PlusPlus);
+    /// ```
+    pub fn with_generator(generator: Generator) -> Self {
+        let builder = RandomVectorBuilder::with_generator(generator);
+        RandomMatrixBuilder { builder }
+    }
+
+    /// Returns the pseudo-random number generator of random matrix builder.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let rmb = RandomMatrixBuilder::new();
+    /// assert_eq!(rmb.generator(), Generator::Xoshiro256StarStar);
+    /// ```
+    pub fn generator(&self) -> Generator {
+        self.builder.generator()
+    }
+
     /// Set the seed of random matrix builder.
     ///
     /// # Examples
@@ -287,6 +781,65 @@ impl RandomMatrixBuilder {
         self.builder.seed(value);
     }
 
+    /// Returns the current state of random matrix builder.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rmb = RandomMatrixBuilder::new();
+    /// let state = rmb.state();
+    /// let x = rmb.uniform([2, 2], 0.0, 1.0).unwrap();
+    /// rmb.set_state(&state);
+    /// let y = rmb.uniform([2, 2], 0.0, 1.0).unwrap();
+    /// assert_eq!(x, y);
+    /// ```
+    pub fn state(&self) -> RandomState {
+        self.builder.state()
+    }
+
+    /// Restore the state of random matrix builder.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let rmb1 = RandomMatrixBuilder::new();
+    /// let mut rmb2 = RandomMatrixBuilder::new();
+    /// rmb2.set_state(&rmb1.state());
+    /// ```
+    pub fn set_state(&mut self, state: &RandomState) {
+        self.builder.set_state(state);
+    }
+
+    /// Advance the state of random matrix builder by 2<sup>128</sup> steps.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rmb = RandomMatrixBuilder::new();
+    /// rmb.jump();
+    /// ```
+    pub fn jump(&mut self) {
+        self.builder.jump();
+    }
+
+    /// Split off an independent random matrix builder. See
+    /// [`RandomVectorBuilder::split`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rmb = RandomMatrixBuilder::new();
+    /// rmb.seed(12);
+    /// let mut worker = rmb.split();
+    /// let w = worker.uniform([2, 2], 0.0, 1.0).unwrap();
+    /// ```
+    ///
+    /// [`RandomVectorBuilder::split`]: ../../vector/builders/struct.RandomVectorBuilder.html#method.split
+    pub fn split(&mut self) -> RandomMatrixBuilder {
+        let builder = self.builder.split();
+        RandomMatrixBuilder { builder }
+    }
+
     /// Create a new matrix of the given shape `shape` and populate it with
     /// random samples from a uniform distribution over the half-open
     /// interval `[low, high)` (includes `low`, but excludes `high`).
@@ -321,7 +874,8 @@ impl RandomMatrixBuilder {
     /// Create a new matrix of the given shape `shape` and populate it with
     /// random samples from a normal distribution `N(mean, std_dev**2)`.
     ///
-    /// **Note that**: If `std_dev < 0` it will returns an error.
+    /// **Note that**: If `std_dev < 0` or either `mean` or `std_dev` is not
+    /// finite it will returns an error.
     ///
     /// # Examples
     /// ```
@@ -329,12 +883,15 @@ impl RandomMatrixBuilder {
     /// let mut rmb = RandomMatrixBuilder::new();
     /// let v = rmb.normal([5, 5], 0.0, 1.0); // Gaussian mean=0.0 std_dev=1.0
     /// ```
-    pub fn normal(
+    pub fn normal<T>(
         &mut self,
         shape: [usize; 2],
-        mean: f64,
-        std_dev: f64,
-    ) -> Result<Matrix<f64>, MatrixBuilderError> {
+        mean: T,
+        std_dev: T,
+    ) -> Result<Matrix<T>, MatrixBuilderError>
+    where
+        T: Float + FromPrimitive + fmt::Display,
+    {
         let total_elements = shape.iter().product();
         let vec = self.builder.normal(total_elements, mean, std_dev)?;
         Ok(Matrix {
@@ -343,4 +900,1035 @@ impl RandomMatrixBuilder {
             vec,
         })
     }
+
+    /// Create a new matrix of the given shape `shape` and populate it with
+    /// random samples from a log-normal distribution. The logarithm of the
+    /// samples is normally distributed with mean `mean` and standard
+    /// deviation `std_dev`.
+    ///
+    /// **Note that**: If `std_dev < 0` or either `mean` or `std_dev` is not
+    /// finite it will returns an error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rmb = RandomMatrixBuilder::new();
+    /// let w = rmb.log_normal([5, 5], 0.0, 1.0).unwrap();
+    /// ```
+    pub fn log_normal<T>(
+        &mut self,
+        shape: [usize; 2],
+        mean: T,
+        std_dev: T,
+    ) -> Result<Matrix<T>, MatrixBuilderError>
+    where
+        T: Float + FromPrimitive + fmt::Display,
+    {
+        let total_elements = shape.iter().product();
+        let vec = self.builder.log_normal(total_elements, mean, std_dev)?;
+        Ok(Matrix {
+            nrows: shape[0],
+            ncols: shape[1],
+            vec,
+        })
+    }
+
+    /// Create a new matrix of the given shape `shape` and populate it with
+    /// random samples from an exponential distribution with rate `lambda`.
+    ///
+    /// **Note that**: If `lambda <= 0` it will returns an error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rmb = RandomMatrixBuilder::new();
+    /// let w = rmb.exponential([5, 5], 1.5).unwrap();
+    /// ```
+    pub fn exponential<T>(
+        &mut self,
+        shape: [usize; 2],
+        lambda: T,
+    ) -> Result<Matrix<T>, MatrixBuilderError>
+    where
+        T: Float + FromPrimitive + fmt::Display,
+    {
+        let total_elements = shape.iter().product();
+        let vec = self.builder.exponential(total_elements, lambda)?;
+        Ok(Matrix {
+            nrows: shape[0],
+            ncols: shape[1],
+            vec,
+        })
+    }
+
+    /// Create a new matrix of the given shape `shape` and populate it with
+    /// random samples from a gamma distribution with shape `k` and scale
+    /// `theta`.
+    ///
+    /// **Note that**: If `k <= 0` or `theta <= 0` it will returns an error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rmb = RandomMatrixBuilder::new();
+    /// let w = rmb.gamma([5, 5], 2.0, 1.0).unwrap();
+    /// ```
+    pub fn gamma<T>(
+        &mut self,
+        shape: [usize; 2],
+        k: T,
+        theta: T,
+    ) -> Result<Matrix<T>, MatrixBuilderError>
+    where
+        T: Float + FromPrimitive + fmt::Display,
+    {
+        let total_elements = shape.iter().product();
+        let vec = self.builder.gamma(total_elements, k, theta)?;
+        Ok(Matrix {
+            nrows: shape[0],
+            ncols: shape[1],
+            vec,
+        })
+    }
+
+    /// Create a new matrix of the given shape `shape` and populate it with
+    /// random samples from a beta distribution with shape parameters `alpha`
+    /// and `beta`.
+    ///
+    /// **Note that**: If `alpha <= 0` or `beta <= 0` it will returns an
+    /// error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rmb = RandomMatrixBuilder::new();
+    /// let w = rmb.beta([5, 5], 2.0, 5.0).unwrap();
+    /// ```
+    pub fn beta<T>(
+        &mut self,
+        shape: [usize; 2],
+        alpha: T,
+        beta: T,
+    ) -> Result<Matrix<T>, MatrixBuilderError>
+    where
+        T: Float + FromPrimitive + fmt::Display,
+    {
+        let total_elements = shape.iter().product();
+        let vec = self.builder.beta(total_elements, alpha, beta)?;
+        Ok(Matrix {
+            nrows: shape[0],
+            ncols: shape[1],
+            vec,
+        })
+    }
+
+    /// Create a new matrix of the given shape `shape` and populate it with
+    /// random samples from a chi-squared distribution with `k` degrees of
+    /// freedom.
+    ///
+    /// **Note that**: If `k <= 0` it will returns an error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rmb = RandomMatrixBuilder::new();
+    /// let w = rmb.chi_squared([5, 5], 3.0).unwrap();
+    /// ```
+    pub fn chi_squared<T>(
+        &mut self,
+        shape: [usize; 2],
+        k: T,
+    ) -> Result<Matrix<T>, MatrixBuilderError>
+    where
+        T: Float + FromPrimitive + fmt::Display,
+    {
+        let total_elements = shape.iter().product();
+        let vec = self.builder.chi_squared(total_elements, k)?;
+        Ok(Matrix {
+            nrows: shape[0],
+            ncols: shape[1],
+            vec,
+        })
+    }
+
+    /// Create a new matrix of the given shape `shape` and populate it with
+    /// random samples from a Student's t distribution with `dof` degrees of
+    /// freedom.
+    ///
+    /// **Note that**: If `dof <= 0` it will returns an error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rmb = RandomMatrixBuilder::new();
+    /// let w = rmb.student_t([5, 5], 10.0).unwrap();
+    /// ```
+    pub fn student_t<T>(
+        &mut self,
+        shape: [usize; 2],
+        dof: T,
+    ) -> Result<Matrix<T>, MatrixBuilderError>
+    where
+        T: Float + FromPrimitive + fmt::Display,
+    {
+        let total_elements = shape.iter().product();
+        let vec = self.builder.student_t(total_elements, dof)?;
+        Ok(Matrix {
+            nrows: shape[0],
+            ncols: shape[1],
+            vec,
+        })
+    }
+
+    /// Create a new matrix of the given shape `shape` and populate it with
+    /// random samples from a binomial distribution. Each sample is the number
+    /// of successes in `n` trials with probability of success `p`.
+    ///
+    /// **Note that**: If `p < 0` or `p > 1` it will returns an error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rmb = RandomMatrixBuilder::new();
+    /// let w = rmb.binomial([5, 5], 10, 0.5).unwrap();
+    /// ```
+    pub fn binomial(
+        &mut self,
+        shape: [usize; 2],
+        n: u64,
+        p: f64,
+    ) -> Result<Matrix<u64>, MatrixBuilderError> {
+        let total_elements = shape.iter().product();
+        let vec = self.builder.binomial(total_elements, n, p)?;
+        Ok(Matrix {
+            nrows: shape[0],
+            ncols: shape[1],
+            vec,
+        })
+    }
+
+    /// Create a new matrix of the given shape `shape` and populate it with
+    /// random samples from a Poisson distribution with expected number of
+    /// events `lambda`.
+    ///
+    /// **Note that**: If `lambda <= 0` it will returns an error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rmb = RandomMatrixBuilder::new();
+    /// let w = rmb.poisson([5, 5], 4.0).unwrap();
+    /// ```
+    pub fn poisson(
+        &mut self,
+        shape: [usize; 2],
+        lambda: f64,
+    ) -> Result<Matrix<u64>, MatrixBuilderError> {
+        let total_elements = shape.iter().product();
+        let vec = self.builder.poisson(total_elements, lambda)?;
+        Ok(Matrix {
+            nrows: shape[0],
+            ncols: shape[1],
+            vec,
+        })
+    }
+
+    /// Create a new matrix of the given shape `shape` and populate it with
+    /// random samples from a geometric distribution. Each sample is the number
+    /// of trials until the first success, where each trial has probability
+    /// of success `p`.
+    ///
+    /// **Note that**: If `p <= 0` or `p > 1` it will returns an error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rmb = RandomMatrixBuilder::new();
+    /// let w = rmb.geometric([5, 5], 0.25).unwrap();
+    /// ```
+    pub fn geometric(
+        &mut self,
+        shape: [usize; 2],
+        p: f64,
+    ) -> Result<Matrix<u64>, MatrixBuilderError> {
+        let total_elements = shape.iter().product();
+        let vec = self.builder.geometric(total_elements, p)?;
+        Ok(Matrix {
+            nrows: shape[0],
+            ncols: shape[1],
+            vec,
+        })
+    }
+
+    /// Create a new matrix of the given shape `shape` and populate it with
+    /// random samples from a Bernoulli distribution. Each element is one with
+    /// probability `p` and zero otherwise. You need to explicitly annotate
+    /// the numeric type.
+    ///
+    /// **Note that**: If `p < 0` or `p > 1` it will returns an error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rmb = RandomMatrixBuilder::new();
+    /// let w: Matrix<u8> = rmb.bernoulli([5, 5], 0.3).unwrap();
+    /// ```
+    pub fn bernoulli<T>(
+        &mut self,
+        shape: [usize; 2],
+        p: f64,
+    ) -> Result<Matrix<T>, MatrixBuilderError>
+    where
+        T: Num + Copy,
+    {
+        let total_elements = shape.iter().product();
+        let vec = self.builder.bernoulli(total_elements, p)?;
+        Ok(Matrix {
+            nrows: shape[0],
+            ncols: shape[1],
+            vec,
+        })
+    }
+
+    /// Create a new matrix of the given shape `shape` and populate it with
+    /// random samples from a categorical distribution. Each sample is an index
+    /// `i` of `weights`, drawn with probability proportional to
+    /// `weights[i]`.
+    ///
+    /// **Note that**: If `weights` is empty, contains a negative value or
+    /// sums to zero it will returns an error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rmb = RandomMatrixBuilder::new();
+    /// let w = rmb.categorical([5, 5], &vector![0.2, 0.5, 0.3]).unwrap();
+    /// ```
+    pub fn categorical(
+        &mut self,
+        shape: [usize; 2],
+        weights: &Vector<f64>,
+    ) -> Result<Matrix<usize>, MatrixBuilderError> {
+        let total_elements = shape.iter().product();
+        let vec = self.builder.categorical(total_elements, weights)?;
+        Ok(Matrix {
+            nrows: shape[0],
+            ncols: shape[1],
+            vec,
+        })
+    }
+
+    /// Shuffle the rows of matrix `m` in place. The order of the elements
+    /// within each row is not changed.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rmb = RandomMatrixBuilder::new();
+    /// let mut w = matrix![
+    ///     1, 2;
+    ///     3, 4;
+    ///     5, 6;
+    /// ];
+    /// rmb.shuffle_rows(&mut w);
+    /// ```
+    pub fn shuffle_rows<T>(&mut self, m: &mut Matrix<T>)
+    where
+        T: Num + Copy,
+    {
+        let permutation = self.builder.permutation(m.nrows);
+        let mut data = Vec::with_capacity(m.nrows * m.ncols);
+        for i in permutation.elements() {
+            for value in m.row(*i).elements() {
+                data.push(value);
+            }
+        }
+        m.vec = Vector::from(data);
+    }
+
+    /// Create a new matrix of shape `[len, d]` and populate each row with a
+    /// random sample from a multivariate normal distribution with mean
+    /// `mean` and covariance matrix `cov`, where `d` is the length of
+    /// `mean`.
+    ///
+    /// **Note that**:
+    /// 1. If `cov` shape is not `[d, d]` it will returns an error.
+    /// 2. If `cov` is not symmetric positive semi-definite it will returns
+    ///    an error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rmb = RandomMatrixBuilder::new();
+    /// let mean = vector![0.0, 1.0];
+    /// let cov = matrix![1.0, 0.5; 0.5, 2.0];
+    /// let w = rmb.multivariate_normal(&mean, &cov, 100).unwrap();
+    /// assert_eq!(w.shape(), [100, 2]);
+    /// ```
+    pub fn multivariate_normal(
+        &mut self,
+        mean: &Vector<f64>,
+        cov: &Matrix<f64>,
+        len: usize,
+    ) -> Result<Matrix<f64>, MatrixBuilderError> {
+        let d = mean.len();
+        if cov.shape() != [d, d] {
+            return Err(MatrixBuilderError::new(
+                MatrixBuilderErrorKind::InvalidShape,
+                format!(
+                    "covariance matrix shape {:?} should be [{}, {}]",
+                    cov.shape(),
+                    d,
+                    d
+                ),
+            ));
+        }
+        let l = cholesky(cov)?;
+
+        // x = mean + L * z where z ~ N(0, I)
+        let z = self.builder.normal(len * d, 0.0, 1.0)?;
+        let mut data = Vec::with_capacity(len * d);
+        for n in 0..len {
+            for i in 0..d {
+                let mut value = mean[i];
+                for k in 0..=i {
+                    value += l[i * d + k] * z[n * d + k];
+                }
+                data.push(value);
+            }
+        }
+        let vec = Vector::from(data);
+        Ok(Matrix {
+            nrows: len,
+            ncols: d,
+            vec,
+        })
+    }
+
+    /// Create a new matrix and populate it with a random sample from a
+    /// Wishart distribution with scale matrix `scale` and `dof` degrees of
+    /// freedom.
+    ///
+    /// The sample is generated using the Bartlett decomposition.
+    ///
+    /// **Note that**:
+    /// 1. If `scale` is not a square symmetric positive definite matrix it
+    ///    will returns an error.
+    /// 2. If `dof <= d - 1` where `d` is the number of rows of `scale` it
+    ///    will returns an error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rmb = RandomMatrixBuilder::new();
+    /// let scale: Matrix<f64> = Matrix::eye([3, 3]);
+    /// let w = rmb.wishart(&scale, 5.0).unwrap();
+    /// assert_eq!(w.shape(), [3, 3]);
+    /// ```
+    pub fn wishart(
+        &mut self,
+        scale: &Matrix<f64>,
+        dof: f64,
+    ) -> Result<Matrix<f64>, MatrixBuilderError> {
+        let d = scale.nrows;
+        if scale.ncols != d {
+            return Err(MatrixBuilderError::new(
+                MatrixBuilderErrorKind::InvalidShape,
+                format!("scale matrix {:?} should be square", scale.shape()),
+            ));
+        }
+        if dof.is_nan() || dof <= (d as f64) - 1.0 {
+            return Err(MatrixBuilderError::new(
+                MatrixBuilderErrorKind::NonPositiveParameter,
+                format!(
+                    "dof={} should be greater than {}",
+                    dof,
+                    d as f64 - 1.0
+                ),
+            ));
+        }
+        let l = cholesky(scale)?;
+        if (0..d).any(|i| l[i * d + i] == 0.0) {
+            return Err(MatrixBuilderError::new(
+                MatrixBuilderErrorKind::InvalidCovariance,
+                "scale matrix should be positive definite".to_string(),
+            ));
+        }
+
+        // Lower triangular matrix A with chi-distributed diagonal and
+        // standard normal elements below the diagonal
+        let mut a = vec![0.0; d * d];
+        for i in 0..d {
+            let chi_squared = self.builder.chi_squared(1, dof - i as f64)?;
+            a[i * d + i] = chi_squared[0].sqrt();
+            let z = self.builder.normal(i, 0.0, 1.0)?;
+            for j in 0..i {
+                a[i * d + j] = z[j];
+            }
+        }
+
+        // W = (L * A) * (L * A)^T
+        let la = multiply(&l, &a, d);
+        let mut data = vec![0.0; d * d];
+        for i in 0..d {
+            for j in 0..d {
+                data[i * d + j] =
+                    (0..d).map(|k| la[i * d + k] * la[j * d + k]).sum();
+            }
+        }
+        let vec = Vector::from(data);
+        Ok(Matrix {
+            nrows: d,
+            ncols: d,
+            vec,
+        })
+    }
+
+    /// Create a new random orthogonal matrix of shape `[n, n]` drawn from
+    /// the Haar measure, i.e. uniformly distributed over the orthogonal
+    /// group.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rmb = RandomMatrixBuilder::new();
+    /// let q = rmb.orthogonal(3);
+    /// assert_eq!(q.shape(), [3, 3]);
+    /// ```
+    pub fn orthogonal(&mut self, n: usize) -> Matrix<f64> {
+        // QR decomposition of a standard normal matrix using the modified
+        // Gram-Schmidt process. The diagonal of R is always positive,
+        // which makes Q Haar distributed.
+        let mut q: Vec<f64> = self
+            .builder
+            .normal(n * n, 0.0, 1.0)
+            .unwrap()
+            .elements()
+            .cloned()
+            .collect();
+        for j in 0..n {
+            for k in 0..j {
+                let r: f64 =
+                    (0..n).map(|i| q[i * n + k] * q[i * n + j]).sum();
+                for i in 0..n {
+                    q[i * n + j] -= r * q[i * n + k];
+                }
+            }
+            let norm = (0..n)
+                .map(|i| q[i * n + j] * q[i * n + j])
+                .sum::<f64>()
+                .sqrt();
+            for i in 0..n {
+                q[i * n + j] /= norm;
+            }
+        }
+        let vec = Vector::from(q);
+        Matrix {
+            nrows: n,
+            ncols: n,
+            vec,
+        }
+    }
+
+    /// Create a new random symmetric positive definite matrix of shape
+    /// `[n, n]`.
+    ///
+    /// The matrix is `Q * D * Q^T` where `Q` is a random orthogonal matrix
+    /// and `D` is a diagonal matrix of eigenvalues drawn uniformly from
+    /// `[1, 2)`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rmb = RandomMatrixBuilder::new();
+    /// let w = rmb.symmetric_positive_definite(3);
+    /// assert_eq!(w.shape(), [3, 3]);
+    /// ```
+    pub fn symmetric_positive_definite(&mut self, n: usize) -> Matrix<f64> {
+        let q = self.orthogonal(n);
+        let eigenvalues = self.builder.uniform(n, 1.0, 2.0).unwrap();
+        Matrix::from_fn([n, n], |i, j| {
+            (0..n)
+                .map(|k| q.at(i, k) * eigenvalues[k] * q.at(j, k))
+                .sum()
+        })
+    }
+
+    /// Create a new random sparse matrix of the given shape `shape`. The
+    /// fraction `density` of the elements are non-zero random samples from
+    /// a uniform distribution over the half-open interval `[low, high)`, the
+    /// remaining elements are zeros.
+    ///
+    /// **Note that**:
+    /// 1. If `density < 0` or `density > 1` it will returns an error.
+    /// 2. If `low >= high` it will returns an error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rmb = RandomMatrixBuilder::new();
+    /// let w = rmb.sparse([10, 10], 0.1, 1.0, 2.0).unwrap();
+    /// ```
+    pub fn sparse<T>(
+        &mut self,
+        shape: [usize; 2],
+        density: f64,
+        low: T,
+        high: T,
+    ) -> Result<Matrix<T>, MatrixBuilderError>
+    where
+        T: Num + Copy + SampleUniform + PartialOrd + fmt::Display,
+    {
+        if !(0.0..=1.0).contains(&density) {
+            return Err(MatrixBuilderError::new(
+                MatrixBuilderErrorKind::InvalidProbability,
+                format!("density={} should be within [0, 1]", density),
+            ));
+        }
+        let total_elements: usize = shape.iter().product();
+        let nnz = (density * total_elements as f64).round() as usize;
+        let values = self.builder.uniform(nnz, low, high)?;
+
+        // Select the positions of non-zero elements
+        let positions = self.builder.permutation(total_elements);
+        let mut data = vec![T::zero(); total_elements];
+        for k in 0..nnz {
+            data[positions[k]] = values[k];
+        }
+        let vec = Vector::from(data);
+        Ok(Matrix {
+            nrows: shape[0],
+            ncols: shape[1],
+            vec,
+        })
+    }
+}
+
+// Primitive polynomials and initial direction numbers of the Sobol sequence
+// for dimensions 2 to 21, taken from the Joe & Kuo `new-joe-kuo-6.21201`
+// table. Each entry is `(s, a, m)` where `s` is the degree of the
+// polynomial, `a` encodes its coefficients and `m` are the first `s`
+// direction numbers.
+const SOBOL_DIRECTION_NUMBERS: [(usize, u32, [u32; 7]); 20] = [
+    (1, 0, [1, 0, 0, 0, 0, 0, 0]),
+    (2, 1, [1, 3, 0, 0, 0, 0, 0]),
+    (3, 1, [1, 3, 1, 0, 0, 0, 0]),
+    (3, 2, [1, 1, 1, 0, 0, 0, 0]),
+    (4, 1, [1, 1, 3, 3, 0, 0, 0]),
+    (4, 4, [1, 3, 5, 13, 0, 0, 0]),
+    (5, 2, [1, 1, 5, 5, 17, 0, 0]),
+    (5, 4, [1, 1, 5, 5, 5, 0, 0]),
+    (5, 7, [1, 1, 7, 11, 19, 0, 0]),
+    (5, 11, [1, 1, 5, 1, 1, 0, 0]),
+    (5, 13, [1, 1, 1, 3, 11, 0, 0]),
+    (5, 14, [1, 3, 5, 5, 31, 0, 0]),
+    (6, 1, [1, 3, 3, 9, 7, 49, 0]),
+    (6, 13, [1, 1, 1, 15, 21, 21, 0]),
+    (6, 16, [1, 3, 1, 13, 27, 49, 0]),
+    (6, 19, [1, 1, 1, 15, 7, 5, 0]),
+    (6, 22, [1, 3, 1, 15, 13, 25, 0]),
+    (6, 25, [1, 1, 5, 5, 19, 61, 0]),
+    (7, 1, [1, 3, 7, 11, 23, 15, 103]),
+    (7, 4, [1, 3, 7, 13, 13, 15, 69]),
+];
+
+// Number of bits of the Sobol sequence points
+const SOBOL_BITS: usize = 32;
+
+/// Quasi-random matrices builder.
+///
+/// Quasi-random (low-discrepancy) point sets cover the unit hypercube more
+/// evenly than pseudo-random samples, which makes them useful for
+/// design-of-experiments and Monte Carlo integration. Each builder
+/// function returns a matrix of shape `[n, dims]`, where each row is a
+/// point in `[0, 1)^dims`.
+///
+/// The Sobol and Halton points are deterministic unless scrambling is
+/// enabled via [`scramble`]. The Latin hypercube sample always assigns the
+/// points to the cells randomly, even if the scrambling is disabled. The
+/// builder can be explicitly seeded via [`seed`] to make the results
+/// reproducible.
+///
+/// [`scramble`]: #method.scramble
+/// [`seed`]: #method.seed
+pub struct QuasiRandomMatrixBuilder {
+    builder: RandomVectorBuilder,
+    scramble: bool,
+}
+
+impl QuasiRandomMatrixBuilder {
+    /// Creates new quasi-random matrix builder. The scrambling is disabled
+    /// by default.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut qmb = QuasiRandomMatrixBuilder::new();
+    /// ```
+    pub fn new() -> Self {
+        let builder = RandomVectorBuilder::new();
+        QuasiRandomMatrixBuilder {
+            builder,
+            scramble: false,
+        }
+    }
+
+    /// Set the seed of the scrambling.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut qmb = QuasiRandomMatrixBuilder::new();
+    /// qmb.seed(12);
+    /// ```
+    pub fn seed(&mut self, value: u64) {
+        self.builder.seed(value);
+    }
+
+    /// Returns the current state of the scrambling.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let qmb = QuasiRandomMatrixBuilder::new();
+    /// let state = qmb.state();
+    /// ```
+    pub fn state(&self) -> RandomState {
+        self.builder.state()
+    }
+
+    /// Restore the state of the scrambling.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut qmb = QuasiRandomMatrixBuilder::new();
+    /// let state = qmb.state();
+    /// qmb.set_state(&state);
+    /// ```
+    pub fn set_state(&mut self, state: &RandomState) {
+        self.builder.set_state(state);
+    }
+
+    /// Set to true to randomly scramble the generated points. By default,
+    /// it is set to false.
+    ///
+    /// Sobol points are scrambled using a random digital shift, Halton
+    /// points using random permutations of the digits at each position and
+    /// Latin hypercube points are jittered within their cells instead of
+    /// being centered.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut qmb = QuasiRandomMatrixBuilder::new();
+    /// qmb.scramble(true);
+    /// ```
+    pub fn scramble(&mut self, yes: bool) {
+        self.scramble = yes;
+    }
+
+    /// Create a new matrix of shape `[n, dims]` and populate it with the
+    /// first `n` points of the Sobol sequence. The first point is the
+    /// origin if the scrambling is disabled.
+    ///
+    /// **Note that**: If `dims = 0`, `dims > 21` or `n > 2^32` it will
+    /// returns an error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut qmb = QuasiRandomMatrixBuilder::new();
+    /// let w = qmb.sobol(4, 2).unwrap();
+    /// assert_eq!(w, matrix![
+    ///     0.0, 0.0;
+    ///     0.5, 0.5;
+    ///     0.75, 0.25;
+    ///     0.25, 0.75;
+    /// ]);
+    /// ```
+    pub fn sobol(
+        &mut self,
+        n: usize,
+        dims: usize,
+    ) -> Result<Matrix<f64>, MatrixBuilderError> {
+        let max_dims = SOBOL_DIRECTION_NUMBERS.len() + 1;
+        if dims == 0 || dims > max_dims {
+            return Err(MatrixBuilderError::new(
+                MatrixBuilderErrorKind::InvalidShape,
+                format!("dims={} should be within [1, {}]", dims, max_dims),
+            ));
+        }
+        if n as u64 > 1 << SOBOL_BITS {
+            return Err(MatrixBuilderError::new(
+                MatrixBuilderErrorKind::InvalidShape,
+                format!("n={} should not be greater than 2^32", n),
+            ));
+        }
+
+        // Direction numbers of each dimension
+        let mut directions = vec![[0u32; SOBOL_BITS]; dims];
+        for (k, v) in directions[0].iter_mut().enumerate() {
+            *v = 1 << (SOBOL_BITS - 1 - k);
+        }
+        for d in 1..dims {
+            let (s, a, m) = SOBOL_DIRECTION_NUMBERS[d - 1];
+            let v = &mut directions[d];
+            for k in 0..SOBOL_BITS {
+                if k < s {
+                    v[k] = m[k] << (SOBOL_BITS - 1 - k);
+                } else {
+                    v[k] = v[k - s] ^ (v[k - s] >> s);
+                    for j in 1..s {
+                        if (a >> (s - 1 - j)) & 1 == 1 {
+                            v[k] ^= v[k - j];
+                        }
+                    }
+                }
+            }
+        }
+
+        // Random digital shift of each dimension
+        let mut point = vec![0u32; dims];
+        if self.scramble {
+            let shift = self.builder.uniform(dims, 0, u32::MAX)?;
+            for d in 0..dims {
+                point[d] = shift[d];
+            }
+        }
+
+        // Generate the points in Gray code order
+        let scale = (1u64 << SOBOL_BITS) as f64;
+        let mut data = Vec::with_capacity(n * dims);
+        for i in 0..n {
+            if i > 0 {
+                // Index of the rightmost zero bit of i - 1
+                let c = (i - 1).trailing_ones() as usize;
+                for d in 0..dims {
+                    point[d] ^= directions[d][c];
+                }
+            }
+            for value in point.iter() {
+                data.push(f64::from(*value) / scale);
+            }
+        }
+        let vec = Vector::from(data);
+        Ok(Matrix {
+            nrows: n,
+            ncols: dims,
+            vec,
+        })
+    }
+
+    /// Create a new matrix of shape `[n, dims]` and populate it with the
+    /// first `n` points of the Halton sequence, using the first `dims`
+    /// prime numbers as bases. The first point is the origin if the
+    /// scrambling is disabled.
+    ///
+    /// **Note that**: If `dims = 0` it will returns an error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut qmb = QuasiRandomMatrixBuilder::new();
+    /// let w = qmb.halton(4, 2).unwrap();
+    /// assert_eq!(w.row(1).elements().collect::<Vec<f64>>(), vec![0.5, 1.0 / 3.0]);
+    /// ```
+    pub fn halton(
+        &mut self,
+        n: usize,
+        dims: usize,
+    ) -> Result<Matrix<f64>, MatrixBuilderError> {
+        if dims == 0 {
+            return Err(MatrixBuilderError::new(
+                MatrixBuilderErrorKind::InvalidShape,
+                "dims=0 should be greater than zero".to_string(),
+            ));
+        }
+        let bases = primes(dims);
+
+        // Digit permutations of each digit position of each dimension. All
+        // digits are permuted, including the trailing zero digits, so that
+        // the scrambling also changes the base 2 dimension. The digits
+        // beyond the precision of `f64` are ignored.
+        let mut permutations = Vec::with_capacity(dims);
+        for base in bases.iter() {
+            let mut positions: Vec<Vec<usize>> = Vec::new();
+            if self.scramble {
+                let mut f = 1.0;
+                while f > f64::EPSILON {
+                    let permutation = self.builder.permutation(*base);
+                    positions.push(permutation.elements().cloned().collect());
+                    f /= *base as f64;
+                }
+            }
+            permutations.push(positions);
+        }
+
+        let mut data = Vec::with_capacity(n * dims);
+        for i in 0..n {
+            for (base, positions) in bases.iter().zip(permutations.iter()) {
+                // Radical inverse of i in the given base
+                let mut value = 0.0;
+                let mut f = 1.0 / *base as f64;
+                let mut k = i;
+                if self.scramble {
+                    for permutation in positions.iter() {
+                        value += permutation[k % base] as f64 * f;
+                        k /= base;
+                        f /= *base as f64;
+                    }
+                    // Rounding should not reach the excluded upper bound
+                    value = value.min(1.0 - f64::EPSILON / 2.0);
+                } else {
+                    while k > 0 {
+                        value += (k % base) as f64 * f;
+                        k /= base;
+                        f /= *base as f64;
+                    }
+                }
+                data.push(value);
+            }
+        }
+        let vec = Vector::from(data);
+        Ok(Matrix {
+            nrows: n,
+            ncols: dims,
+            vec,
+        })
+    }
+
+    /// Create a new matrix of shape `[n, dims]` and populate it with a
+    /// Latin hypercube sample. Each dimension is divided into `n` cells of
+    /// equal size and each cell contains exactly one point.
+    ///
+    /// The cells are always assigned to the points by a random permutation,
+    /// so the builder should be seeded via [`seed`] to make the sample
+    /// reproducible. The points are placed at the center of the cells, or
+    /// randomly within the cells if the scrambling is enabled.
+    ///
+    /// **Note that**: If `dims = 0` it will returns an error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut qmb = QuasiRandomMatrixBuilder::new();
+    /// let w = qmb.latin_hypercube(10, 3).unwrap();
+    /// assert_eq!(w.shape(), [10, 3]);
+    /// ```
+    ///
+    /// [`seed`]: #method.seed
+    pub fn latin_hypercube(
+        &mut self,
+        n: usize,
+        dims: usize,
+    ) -> Result<Matrix<f64>, MatrixBuilderError> {
+        if dims == 0 {
+            return Err(MatrixBuilderError::new(
+                MatrixBuilderErrorKind::InvalidShape,
+                "dims=0 should be greater than zero".to_string(),
+            ));
+        }
+        let mut data = vec![0.0; n * dims];
+        for d in 0..dims {
+            let cells = self.builder.permutation(n);
+            let offsets = if self.scramble {
+                self.builder.uniform(n, 0.0, 1.0)?
+            } else {
+                Vector::full(n, 0.5)
+            };
+            for i in 0..n {
+                data[i * dims + d] =
+                    (cells[i] as f64 + offsets[i]) / n as f64;
+            }
+        }
+        let vec = Vector::from(data);
+        Ok(Matrix {
+            nrows: n,
+            ncols: dims,
+            vec,
+        })
+    }
+}
+
+impl Default for QuasiRandomMatrixBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Returns the first `n` prime numbers.
+fn primes(n: usize) -> Vec<usize> {
+    let mut primes: Vec<usize> = Vec::with_capacity(n);
+    let mut candidate = 2;
+    while primes.len() < n {
+        if primes.iter().all(|p| candidate % p != 0) {
+            primes.push(candidate);
+        }
+        candidate += 1;
+    }
+    primes
+}
+
+// Cholesky decomposition `a = l * l^T` of the symmetric positive
+// semi-definite matrix `a`. It returns the lower triangular matrix `l` in
+// row-major order, with zero columns for the zero pivots.
+fn cholesky(a: &Matrix<f64>) -> Result<Vec<f64>, MatrixBuilderError> {
+    let n = a.nrows;
+    // Tolerance relative to the magnitude of the matrix
+    let max_diag = (0..n).map(|i| a.at(i, i).abs()).fold(0.0, f64::max);
+    let tol = 1e-10 * max_diag.max(1.0);
+
+    for i in 0..n {
+        for j in 0..i {
+            if (a.at(i, j) - a.at(j, i)).abs() > tol {
+                return Err(MatrixBuilderError::new(
+                    MatrixBuilderErrorKind::InvalidCovariance,
+                    "matrix should be symmetric".to_string(),
+                ));
+            }
+        }
+    }
+
+    let not_positive_semidefinite = || {
+        MatrixBuilderError::new(
+            MatrixBuilderErrorKind::InvalidCovariance,
+            "matrix should be positive semi-definite".to_string(),
+        )
+    };
+    let mut l = vec![0.0; n * n];
+    for j in 0..n {
+        let d =
+            a.at(j, j) - (0..j).map(|k| l[j * n + k].powi(2)).sum::<f64>();
+        if d < -tol {
+            return Err(not_positive_semidefinite());
+        }
+        let l_jj = if d > tol { d.sqrt() } else { 0.0 };
+        l[j * n + j] = l_jj;
+        for i in (j + 1)..n {
+            let s = a.at(i, j)
+                - (0..j).map(|k| l[i * n + k] * l[j * n + k]).sum::<f64>();
+            if l_jj > 0.0 {
+                l[i * n + j] = s / l_jj;
+            } else if s.abs() > tol {
+                return Err(not_positive_semidefinite());
+            }
+        }
+    }
+    Ok(l)
+}
+
+// Multiply square matrices `a` and `b` of size `n` stored in row-major
+// order.
+fn multiply(a: &[f64], b: &[f64], n: usize) -> Vec<f64> {
+    let mut c = vec![0.0; n * n];
+    for i in 0..n {
+        for k in 0..n {
+            let a_ik = a[i * n + k];
+            for j in 0..n {
+                c[i * n + j] += a_ik * b[k * n + j];
+            }
+        }
+    }
+    c
 }
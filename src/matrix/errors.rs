@@ -14,10 +14,13 @@
 
 //! Matrix errors.
 //!
-//! An error which can be returned when creating, operating, loading and
-//! indexing matrices.
+//! An error which can be returned when creating, operating, loading,
+//! parsing and indexing matrices.
 
-use crate::vector::errors::{VectorBuilderError, VectorBuilderErrorKind};
+use crate::vector::errors::{
+    VectorBuilderError, VectorBuilderErrorKind, VectorLoadError,
+    VectorLoadErrorKind, VectorParseError, VectorParseErrorKind,
+};
 use csv;
 use std::convert;
 use std::fmt;
@@ -38,6 +41,46 @@ pub enum MatrixBuilderErrorKind {
     /// new random matrix using normal distribution with `std_dev < 0`.
     NegativeStandardDeviation,
 
+    /// Distribution parameter should be positive.
+    ///
+    /// Among other causes, this variant will be constructed when creating
+    /// new random matrix using exponential distribution with `lambda <= 0`.
+    NonPositiveParameter,
+
+    /// Distribution parameter should be finite.
+    ///
+    /// Among other causes, this variant will be constructed when creating
+    /// new random matrix using normal distribution with NaN or infinite
+    /// `mean`.
+    NonFiniteParameter,
+
+    /// Probability should be within the closed interval `[0, 1]`.
+    ///
+    /// Among other causes, this variant will be constructed when creating
+    /// new random matrix using binomial distribution with `p > 1`.
+    InvalidProbability,
+
+    /// Invalid weights for categorical distribution.
+    ///
+    /// Among other causes, this variant will be constructed when creating
+    /// new random matrix using categorical distribution with negative
+    /// weights.
+    InvalidWeights,
+
+    /// Invalid shape for matrix input.
+    ///
+    /// Among other causes, this variant will be constructed when creating
+    /// new random matrix using multivariate normal distribution with
+    /// covariance matrix that does not match the mean length.
+    InvalidShape,
+
+    /// Invalid covariance matrix.
+    ///
+    /// Among other causes, this variant will be constructed when creating
+    /// new random matrix using multivariate normal distribution with
+    /// covariance matrix that is not symmetric positive semi-definite.
+    InvalidCovariance,
+
     /// Any error not part of this list.
     Other,
 }
@@ -70,6 +113,29 @@ impl MatrixBuilderError {
                  {}",
                 self.message
             ),
+            MatrixBuilderErrorKind::NonPositiveParameter => format!(
+                "Random matrix builder invalid parameter: {}",
+                self.message
+            ),
+            MatrixBuilderErrorKind::NonFiniteParameter => format!(
+                "Random matrix builder parameter should be finite: {}",
+                self.message
+            ),
+            MatrixBuilderErrorKind::InvalidProbability => format!(
+                "Random matrix builder invalid probability: {}",
+                self.message
+            ),
+            MatrixBuilderErrorKind::InvalidWeights => format!(
+                "Random matrix builder invalid weights: {}",
+                self.message
+            ),
+            MatrixBuilderErrorKind::InvalidShape => {
+                format!("Matrix builder invalid shape: {}", self.message)
+            }
+            MatrixBuilderErrorKind::InvalidCovariance => format!(
+                "Random matrix builder invalid covariance matrix: {}",
+                self.message
+            ),
             MatrixBuilderErrorKind::Other => {
                 format!("Matrix builder error: {}", self.message)
             }
@@ -85,7 +151,9 @@ impl convert::From<VectorBuilderError> for MatrixBuilderError {
                 MatrixBuilderErrorKind::InvalidRange,
                 error.message,
             ),
-            VectorBuilderErrorKind::InvalidStepValue => {
+            VectorBuilderErrorKind::InvalidStepValue
+            | VectorBuilderErrorKind::InvalidLength
+            | VectorBuilderErrorKind::InvalidState => {
                 MatrixBuilderError::new(
                     MatrixBuilderErrorKind::Other,
                     error.message,
@@ -97,6 +165,30 @@ impl convert::From<VectorBuilderError> for MatrixBuilderError {
                     error.message,
                 )
             }
+            VectorBuilderErrorKind::NonPositiveParameter => {
+                MatrixBuilderError::new(
+                    MatrixBuilderErrorKind::NonPositiveParameter,
+                    error.message,
+                )
+            }
+            VectorBuilderErrorKind::NonFiniteParameter => {
+                MatrixBuilderError::new(
+                    MatrixBuilderErrorKind::NonFiniteParameter,
+                    error.message,
+                )
+            }
+            VectorBuilderErrorKind::InvalidProbability => {
+                MatrixBuilderError::new(
+                    MatrixBuilderErrorKind::InvalidProbability,
+                    error.message,
+                )
+            }
+            VectorBuilderErrorKind::InvalidWeights => {
+                MatrixBuilderError::new(
+                    MatrixBuilderErrorKind::InvalidWeights,
+                    error.message,
+                )
+            }
         }
     }
 }
@@ -114,11 +206,12 @@ impl fmt::Display for MatrixBuilderError {
 }
 
 /// Enum to store the various types of errors that can cause loading a matrix to fail.
+#[derive(Clone)]
 pub enum MatrixLoadErrorKind {
     /// I/O Error
     ///
-    /// Among other causes, this variant will be constructed when failed loading a file
-    /// due to I/O problem.
+    /// Among other causes, this variant will be constructed when failed reading or
+    /// writing a file due to I/O problem.
     IOError,
     /// CSV Error
     ///
@@ -133,15 +226,71 @@ pub enum MatrixLoadErrorKind {
     /// Among other causes, this variant will be constructed when parsing a string that
     /// contains non-numeric letter.
     InvalidElement,
+    /// Invalid header names.
+    ///
+    /// Among other causes, this variant will be constructed when writing a CSV file
+    /// with the number of header names different from the number of columns.
+    InvalidHeaders,
+    /// Selected column does not exist.
+    ///
+    /// Among other causes, this variant will be constructed when loading a CSV file
+    /// with a header name that is not found in the header row.
+    InvalidColumn,
+    /// Contains a missing value.
+    ///
+    /// Among other causes, this variant will be constructed when loading a CSV file
+    /// that contains an empty field without setting how to handle missing values.
+    MissingValue,
+    /// File is not in the expected format.
+    ///
+    /// Among other causes, this variant will be constructed when loading a NPY file
+    /// with an invalid header.
+    InvalidFormat,
+    /// Element type of the file does not match the numeric type.
+    ///
+    /// Among other causes, this variant will be constructed when loading a NPY file
+    /// that contains `f64` elements as `Matrix<i32>`.
+    InvalidDataType,
+    /// Shape of the array in the file is invalid.
+    ///
+    /// Among other causes, this variant will be constructed when loading a NPY file
+    /// that contains one-dimensional array as matrix.
+    InvalidShape,
+    /// Selected array does not exist.
+    ///
+    /// Among other causes, this variant will be constructed when loading an array
+    /// that is not found in a NPZ archive.
+    InvalidName,
 }
 
 /// An error which can be returned when loading matrix from a file.
 ///
 /// # Potential causes
 /// Among other causes, `LoadError` can be thrown because of loaded file is not exists.
+///
+/// # Location
+/// If the error is caused by a specific field, the 1-based [`line`], [`record`] and
+/// [`column`] of the field, and its [`header`] name if any, are available.
+///
+/// [`line`]: #method.line
+/// [`record`]: #method.record
+/// [`column`]: #method.column
+/// [`header`]: #method.header
+#[derive(Clone)]
 pub struct MatrixLoadError {
     pub(crate) kind: MatrixLoadErrorKind,
     pub(crate) message: String,
+    location: Box<Location>,
+    errors: Vec<MatrixLoadError>,
+}
+
+// Location of the field that causes the error
+#[derive(Clone, Default)]
+struct Location {
+    line: Option<u64>,
+    record: Option<u64>,
+    column: Option<usize>,
+    header: Option<String>,
 }
 
 impl MatrixLoadError {
@@ -150,7 +299,41 @@ impl MatrixLoadError {
         kind: MatrixLoadErrorKind,
         message: String,
     ) -> MatrixLoadError {
-        MatrixLoadError { kind, message }
+        MatrixLoadError {
+            kind,
+            message,
+            location: Box::default(),
+            errors: Vec::new(),
+        }
+    }
+
+    // Set the location of the field that causes the error.
+    pub(crate) fn at(
+        self,
+        line: Option<u64>,
+        record: Option<u64>,
+        column: Option<usize>,
+        header: Option<String>,
+    ) -> MatrixLoadError {
+        let location = Location {
+            line,
+            record,
+            column,
+            header,
+        };
+        MatrixLoadError {
+            location: Box::new(location),
+            ..self
+        }
+    }
+
+    // Combine the errors collected in lenient mode, `errors` should not be
+    // empty.
+    pub(crate) fn collect(errors: Vec<MatrixLoadError>) -> MatrixLoadError {
+        MatrixLoadError {
+            errors: errors.clone(),
+            ..errors[0].clone()
+        }
     }
 
     /// Outputs the detailed cause of loading file failing.
@@ -158,10 +341,80 @@ impl MatrixLoadError {
         &self.kind
     }
 
+    /// Returns the 1-based line number where the error occurred, if any.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let result: Result<Matrix<i32>, MatrixLoadError> =
+    ///     Matrix::from_csv_str("a,b\n1,2\n3,x").has_headers(true).load();
+    /// let error = result.unwrap_err();
+    /// assert_eq!(error.line(), Some(3));
+    /// assert_eq!(error.record(), Some(3));
+    /// assert_eq!(error.column(), Some(2));
+    /// assert_eq!(error.header(), Some("b"));
+    /// ```
+    pub fn line(&self) -> Option<u64> {
+        self.location.line
+    }
+
+    /// Returns the 1-based record number where the error occurred, if any.
+    /// The header row and the skipped rows are counted as records.
+    pub fn record(&self) -> Option<u64> {
+        self.location.record
+    }
+
+    /// Returns the 1-based column number where the error occurred, if any.
+    pub fn column(&self) -> Option<usize> {
+        self.location.column
+    }
+
+    /// Returns the header name of the column where the error occurred, if
+    /// any.
+    pub fn header(&self) -> Option<&str> {
+        self.location.header.as_deref()
+    }
+
+    /// Returns all errors collected in lenient mode, including this one. It
+    /// is empty if lenient mode is disabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let result: Result<Matrix<i32>, MatrixLoadError> =
+    ///     Matrix::from_csv_str("1,x\ny,4").lenient(true).load();
+    /// let error = result.unwrap_err();
+    /// assert_eq!(error.errors().len(), 2);
+    /// ```
+    pub fn errors(&self) -> &[MatrixLoadError] {
+        &self.errors
+    }
+
+    fn location(&self) -> String {
+        let mut location = Vec::new();
+        if let Some(line) = self.location.line {
+            location.push(format!("line {}", line));
+        }
+        if let Some(record) = self.location.record {
+            location.push(format!("record {}", record));
+        }
+        if let Some(column) = self.location.column {
+            location.push(format!("column {}", column));
+        }
+        if let Some(ref header) = self.location.header {
+            location.push(format!("header {:?}", header));
+        }
+        if location.is_empty() {
+            String::new()
+        } else {
+            format!(" (at {})", location.join(", "))
+        }
+    }
+
     fn description(&self) -> String {
-        match self.kind {
+        let description = match self.kind {
             MatrixLoadErrorKind::IOError => format!(
-                "Cannot load Matrix from file due to: {}",
+                "Cannot read or write Matrix file due to: {}",
                 self.message
             ),
             MatrixLoadErrorKind::CSVError => {
@@ -174,6 +427,38 @@ impl MatrixLoadError {
                 "Cannot load Matrix, invalid element: {}",
                 self.message
             ),
+            MatrixLoadErrorKind::MissingValue => {
+                format!("Cannot load Matrix, missing value: {}", self.message)
+            }
+            MatrixLoadErrorKind::InvalidColumn => format!(
+                "Cannot load Matrix, invalid column: {}",
+                self.message
+            ),
+            MatrixLoadErrorKind::InvalidFormat => format!(
+                "Cannot load Matrix, invalid format: {}",
+                self.message
+            ),
+            MatrixLoadErrorKind::InvalidDataType => format!(
+                "Cannot load Matrix, invalid data type: {}",
+                self.message
+            ),
+            MatrixLoadErrorKind::InvalidShape => {
+                format!("Cannot load Matrix, invalid shape: {}", self.message)
+            }
+            MatrixLoadErrorKind::InvalidName => {
+                format!("Cannot load Matrix, invalid name: {}", self.message)
+            }
+            MatrixLoadErrorKind::InvalidHeaders => {
+                format!(
+                    "Cannot write Matrix, invalid headers: {}",
+                    self.message
+                )
+            }
+        };
+        let description = description + &self.location();
+        match self.errors.len() {
+            0 | 1 => description,
+            n => format!("{} and {} more errors", description, n - 1),
         }
     }
 }
@@ -181,20 +466,62 @@ impl MatrixLoadError {
 /// Convert `io::Error` to `matrix::LoadError`
 impl convert::From<io::Error> for MatrixLoadError {
     fn from(error: io::Error) -> Self {
-        MatrixLoadError {
-            kind: MatrixLoadErrorKind::IOError,
-            message: format!("{}", error),
-        }
+        MatrixLoadError::new(
+            MatrixLoadErrorKind::IOError,
+            format!("{}", error),
+        )
+    }
+}
+
+/// Convert `VectorLoadError` to `MatrixLoadError`
+impl convert::From<VectorLoadError> for MatrixLoadError {
+    fn from(error: VectorLoadError) -> Self {
+        let kind = match error.kind {
+            VectorLoadErrorKind::IOError => MatrixLoadErrorKind::IOError,
+            VectorLoadErrorKind::CSVError => MatrixLoadErrorKind::CSVError,
+            VectorLoadErrorKind::Empty => MatrixLoadErrorKind::Empty,
+            VectorLoadErrorKind::InvalidElement => {
+                MatrixLoadErrorKind::InvalidElement
+            }
+            VectorLoadErrorKind::InvalidColumn => {
+                MatrixLoadErrorKind::InvalidColumn
+            }
+            VectorLoadErrorKind::InvalidFormat => {
+                MatrixLoadErrorKind::InvalidFormat
+            }
+            VectorLoadErrorKind::InvalidDataType => {
+                MatrixLoadErrorKind::InvalidDataType
+            }
+            VectorLoadErrorKind::InvalidShape => {
+                MatrixLoadErrorKind::InvalidShape
+            }
+            VectorLoadErrorKind::InvalidName => {
+                MatrixLoadErrorKind::InvalidName
+            }
+        };
+        MatrixLoadError::new(kind, error.message)
     }
 }
 
 /// Convert `csv::Error` to `matrix::LoadError`
 impl convert::From<csv::Error> for MatrixLoadError {
     fn from(error: csv::Error) -> Self {
-        MatrixLoadError {
-            kind: MatrixLoadErrorKind::CSVError,
-            message: format!("{}", error),
-        }
+        let (line, record) = match error.position() {
+            Some(position) => {
+                (Some(position.line()), Some(position.record() + 1))
+            }
+            None => (None, None),
+        };
+        let message = match error.kind() {
+            // Remove the position from the message, it is already part of
+            // the location
+            csv::ErrorKind::Utf8 { err, .. } => {
+                format!("invalid UTF-8: {}", err)
+            }
+            _ => format!("{}", error),
+        };
+        MatrixLoadError::new(MatrixLoadErrorKind::CSVError, message)
+            .at(line, record, None, None)
     }
 }
 
@@ -209,3 +536,126 @@ impl fmt::Display for MatrixLoadError {
         write!(f, "{}", self.description())
     }
 }
+
+/// Enum to store the various types of errors that can cause parsing a matrix
+/// to fail.
+pub enum MatrixParseErrorKind {
+    /// String being parsed is empty.
+    ///
+    /// Among other causes, this variant will be constructed when parsing a
+    /// string that only contains whitespaces.
+    Empty,
+    /// Contains an invalid element.
+    ///
+    /// Among other causes, this variant will be constructed when parsing a
+    /// string that contains non-numeric letter, for example `"1 x; 3 4"`.
+    InvalidElement,
+    /// String is not in the expected syntax.
+    ///
+    /// Among other causes, this variant will be constructed when parsing a
+    /// string with unbalanced brackets, for example `"[[1, 2], [3, 4]"`.
+    InvalidSyntax,
+    /// Shape of the elements is invalid.
+    ///
+    /// Among other causes, this variant will be constructed when parsing a
+    /// string with rows of different lengths, for example `"1 2; 3"`.
+    InvalidShape,
+}
+
+/// An error which can be returned when parsing matrix from a string.
+///
+/// The 0-based byte [`position`] of the offending part of the string is
+/// available.
+///
+/// [`position`]: #method.position
+pub struct MatrixParseError {
+    pub(crate) kind: MatrixParseErrorKind,
+    pub(crate) message: String,
+    pub(crate) position: usize,
+}
+
+impl MatrixParseError {
+    /// Creates a new `MatrixParseError` from a known kind of error, an error
+    /// message and the byte position of the error.
+    pub fn new(
+        kind: MatrixParseErrorKind,
+        message: String,
+        position: usize,
+    ) -> MatrixParseError {
+        MatrixParseError {
+            kind,
+            message,
+            position,
+        }
+    }
+
+    /// Outputs the detailed cause of parsing string failing.
+    pub fn kind(&self) -> &MatrixParseErrorKind {
+        &self.kind
+    }
+
+    /// Returns the 0-based byte position in the string where the error
+    /// occurred.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let error = "1 2; 3".parse::<Matrix<i32>>().unwrap_err();
+    /// assert_eq!(error.position(), 5);
+    /// ```
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    fn description(&self) -> String {
+        let description = match self.kind {
+            MatrixParseErrorKind::Empty => {
+                "Cannot parse Matrix from empty string".to_string()
+            }
+            MatrixParseErrorKind::InvalidElement => format!(
+                "Cannot parse Matrix, invalid element: {}",
+                self.message
+            ),
+            MatrixParseErrorKind::InvalidSyntax => format!(
+                "Cannot parse Matrix, invalid syntax: {}",
+                self.message
+            ),
+            MatrixParseErrorKind::InvalidShape => format!(
+                "Cannot parse Matrix, invalid shape: {}",
+                self.message
+            ),
+        };
+        format!("{} at position {}", description, self.position)
+    }
+}
+
+/// Convert `VectorParseError` to `MatrixParseError`
+impl convert::From<VectorParseError> for MatrixParseError {
+    fn from(error: VectorParseError) -> Self {
+        let kind = match error.kind {
+            VectorParseErrorKind::Empty => MatrixParseErrorKind::Empty,
+            VectorParseErrorKind::InvalidElement => {
+                MatrixParseErrorKind::InvalidElement
+            }
+            VectorParseErrorKind::InvalidSyntax => {
+                MatrixParseErrorKind::InvalidSyntax
+            }
+            VectorParseErrorKind::InvalidShape => {
+                MatrixParseErrorKind::InvalidShape
+            }
+        };
+        MatrixParseError::new(kind, error.message, error.position)
+    }
+}
+
+impl fmt::Debug for MatrixParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl fmt::Display for MatrixParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
@@ -14,14 +14,46 @@
 
 // TODO(pyk): Add docs about how to load matrix from external file here
 
+use crate::binary;
 use crate::matrix::errors::{MatrixLoadError, MatrixLoadErrorKind};
 use crate::matrix::Matrix;
-use crate::utils;
+use crate::npy;
+use crate::utils::{self, ParseElement};
+use crate::vector::Vector;
+use crate::wav::{self, WavSample};
 use csv;
-use num::{FromPrimitive, Num};
+use num::{Float, FromPrimitive, Num, ToPrimitive};
+use std::fmt;
 use std::fs::File;
-use std::marker::PhantomData;
-use std::path::Path;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::mem;
+use std::path::{Path, PathBuf};
+
+// Columns of the CSV file to load
+#[derive(Debug)]
+enum Columns {
+    All,
+    Indices(Vec<usize>),
+    Names(Vec<String>),
+}
+
+// Custom parser of the elements
+type ParseFn<'a, T> = Box<dyn Fn(&str) -> Option<T> + 'a>;
+struct Parser<'a, T>(ParseFn<'a, T>);
+
+impl<'a, T> fmt::Debug for Parser<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Parser")
+    }
+}
+
+// What to do with the missing values
+#[derive(Debug)]
+enum Missing<T> {
+    Error,
+    Fill(T),
+    DropRow,
+}
 
 /// Matrix loader for CSV formatted file.
 ///
@@ -29,20 +61,28 @@ use std::path::Path;
 ///
 /// [`Matrix::from_csv`]: struct.Matrix.html#method.from_csv
 #[derive(Debug)]
-pub struct MatrixLoaderForCSV<T, P>
-where
-    P: AsRef<Path>,
-{
-    file_path: P,
+pub struct MatrixLoaderForCSV<'a, T> {
+    source: utils::CSVSource<'a>,
     has_headers: bool,
-    // We use this to make compiler happy
-    phantom: PhantomData<T>,
+    delimiter: u8,
+    comment: Option<u8>,
+    skip_rows: usize,
+    max_rows: Option<usize>,
+    usecols: Columns,
+    flexible: bool,
+    padding: T,
+    quoting: bool,
+    quote: u8,
+    double_quote: bool,
+    escape: Option<u8>,
+    missing_values: Vec<String>,
+    missing: Missing<T>,
+    lenient: bool,
+    format: utils::NumberFormat,
+    parser: Option<Parser<'a, T>>,
 }
 
-impl<T, P> MatrixLoaderForCSV<T, P>
-where
-    P: AsRef<Path>,
-{
+impl<'a, T> MatrixLoaderForCSV<'a, T> {
     /// Set to true to treat the first row as a special header row. By default, it is set
     /// to false.
     ///
@@ -56,90 +96,2990 @@ where
     ///     .load()
     ///     .unwrap();
     /// ```
-    pub fn has_headers(self, yes: bool) -> MatrixLoaderForCSV<T, P> {
+    pub fn has_headers(self, yes: bool) -> MatrixLoaderForCSV<'a, T> {
         MatrixLoaderForCSV {
-            file_path: self.file_path,
             has_headers: yes,
-            phantom: PhantomData,
+            ..self
         }
     }
 
-    /// Load Matrix from CSV file. You need to explicitly annotate the numeric type.
+    /// Set the field delimiter. By default, it is set to `b','`.
     ///
     /// # Examples
+    ///
     /// ```
     /// use crabsformer::prelude::*;
     ///
-    /// let dataset: Matrix<f32> = Matrix::from_csv("tests/data/weight.csv").load().unwrap();
+    /// let w: Matrix<i32> = Matrix::from_csv("tests/data/vector_semicolon.csv")
+    ///     .delimiter(b';')
+    ///     .load()
+    ///     .unwrap();
+    /// assert_eq!(w, matrix![1, 2, 3; 4, 5, 6]);
     /// ```
-    pub fn load(self) -> Result<Matrix<T>, MatrixLoadError>
+    pub fn delimiter(self, delimiter: u8) -> MatrixLoaderForCSV<'a, T> {
+        MatrixLoaderForCSV { delimiter, ..self }
+    }
+
+    /// Set the comment character. Lines that start with the comment
+    /// character are ignored. By default, there is no comment character.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// let w: Matrix<f64> = Matrix::from_csv("tests/data/matrix_options.csv")
+    ///     .comment(Some(b'#'))
+    ///     .skip_rows(1)
+    ///     .delimiter(b';')
+    ///     .has_headers(true)
+    ///     .load()
+    ///     .unwrap();
+    /// ```
+    pub fn comment(self, comment: Option<u8>) -> MatrixLoaderForCSV<'a, T> {
+        MatrixLoaderForCSV { comment, ..self }
+    }
+
+    /// Skip the first `n` rows, before the header row. Comment lines are not
+    /// counted. By default, no rows are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// let w: Matrix<i32> =
+    ///     Matrix::from_csv("tests/data/matrix_valid_without_header.csv")
+    ///         .skip_rows(2)
+    ///         .load()
+    ///         .unwrap();
+    /// assert_eq!(w, matrix![7, 8, -9]);
+    /// ```
+    pub fn skip_rows(self, n: usize) -> MatrixLoaderForCSV<'a, T> {
+        MatrixLoaderForCSV {
+            skip_rows: n,
+            ..self
+        }
+    }
+
+    /// Load at most `n` rows, not counting the header row. By default, all
+    /// rows are loaded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// let w: Matrix<i32> =
+    ///     Matrix::from_csv("tests/data/matrix_valid_without_header.csv")
+    ///         .max_rows(1)
+    ///         .load()
+    ///         .unwrap();
+    /// assert_eq!(w, matrix![-1, 2, 3]);
+    /// ```
+    pub fn max_rows(self, n: usize) -> MatrixLoaderForCSV<'a, T> {
+        MatrixLoaderForCSV {
+            max_rows: Some(n),
+            ..self
+        }
+    }
+
+    /// Select the columns to load by their zero-based indices, in the given
+    /// order. By default, all columns are loaded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// let w: Matrix<i32> =
+    ///     Matrix::from_csv("tests/data/matrix_valid_without_header.csv")
+    ///         .usecols(&[2, 0])
+    ///         .load()
+    ///         .unwrap();
+    /// assert_eq!(w, matrix![3, -1; 6, 4; -9, 7]);
+    /// ```
+    pub fn usecols(self, indices: &[usize]) -> MatrixLoaderForCSV<'a, T> {
+        MatrixLoaderForCSV {
+            usecols: Columns::Indices(indices.to_vec()),
+            ..self
+        }
+    }
+
+    /// Select the columns to load by their header names, in the given order.
+    /// It treats the first row after the skipped rows as the header row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// let w: Matrix<f32> = Matrix::from_csv("tests/data/dataset.csv")
+    ///     .usecols_by_name(&["feature2"])
+    ///     .load()
+    ///     .unwrap();
+    /// assert_eq!(w, matrix![2.0; 4.0; 6.0]);
+    /// ```
+    pub fn usecols_by_name<S>(self, names: &[S]) -> MatrixLoaderForCSV<'a, T>
     where
-        T: FromPrimitive + Num + Copy + utils::TypeName,
+        S: AsRef<str>,
     {
-        // Open CSV file
-        let file = File::open(self.file_path)?;
-        let mut rdr = csv::ReaderBuilder::new()
-            .has_headers(self.has_headers)
-            .from_reader(file);
+        let names = names.iter().map(|name| name.as_ref().to_string());
+        MatrixLoaderForCSV {
+            has_headers: true,
+            usecols: Columns::Names(names.collect()),
+            ..self
+        }
+    }
 
-        // Collect each row
-        let mut elements = Vec::new();
-        for result in rdr.records() {
-            // Convert each row in the CSV file to RowMatrix
-            let record = result?;
-            let mut rows = Vec::with_capacity(record.len());
-            for value in record.iter() {
-                // It will return error if any
-                let element = match T::from_str_radix(value.trim(), 10) {
-                    Ok(value) => value,
-                    Err(_err) => {
-                        // Return error early
-                        return Err(MatrixLoadError::new(
-                            MatrixLoadErrorKind::InvalidElement,
-                            format!(
-                                "{:?} is not valid {}",
-                                value,
-                                T::type_name()
-                            ),
-                        ));
-                    }
-                };
-                rows.push(element);
-            }
-            elements.push(rows);
+    /// Set to true to allow rows with different number of fields. Missing
+    /// fields are filled with the [`padding`] value. By default, it is set
+    /// to false and loading rows with different number of fields returns an
+    /// error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// let w: Matrix<u32> =
+    ///     Matrix::from_csv("tests/data/matrix_invalid_inconsistent_column.csv")
+    ///         .flexible(true)
+    ///         .load()
+    ///         .unwrap();
+    /// ```
+    ///
+    /// [`padding`]: #method.padding
+    pub fn flexible(self, yes: bool) -> MatrixLoaderForCSV<'a, T> {
+        MatrixLoaderForCSV {
+            flexible: yes,
+            ..self
         }
-        if elements.len() == 0 {
-            return Err(MatrixLoadError::new(
-                MatrixLoadErrorKind::Empty,
-                String::from("Cannot load empty file"),
-            ));
+    }
+
+    /// Set the value used to fill the missing fields of the [`flexible`]
+    /// rows. By default, it is set to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// let w: Matrix<i32> =
+    ///     Matrix::from_csv("tests/data/matrix_invalid_inconsistent_column.csv")
+    ///         .flexible(true)
+    ///         .padding(-1)
+    ///         .load()
+    ///         .unwrap();
+    /// ```
+    ///
+    /// [`flexible`]: #method.flexible
+    pub fn padding(self, value: T) -> MatrixLoaderForCSV<'a, T> {
+        MatrixLoaderForCSV {
+            padding: value,
+            ..self
         }
-        Ok(Matrix::from(elements))
     }
-}
 
-impl<T> Matrix<T>
-where
-    T: Num + Copy,
-{
-    /// Load Matrix from CSV file. You need to explicitly annotate the numeric type.
+    /// Set to false to treat the quote characters as regular characters. By
+    /// default, it is set to true.
     ///
     /// # Examples
     ///
     /// ```
     /// use crabsformer::prelude::*;
     ///
-    /// let dataset: Matrix<f32> = Matrix::from_csv("tests/data/weight.csv").load().unwrap();
+    /// let w: Matrix<f32> = Matrix::from_csv("tests/data/weight.csv")
+    ///     .quoting(false)
+    ///     .load()
+    ///     .unwrap();
+    /// ```
+    pub fn quoting(self, yes: bool) -> MatrixLoaderForCSV<'a, T> {
+        MatrixLoaderForCSV {
+            quoting: yes,
+            ..self
+        }
+    }
+
+    /// Set the quote character. By default, it is set to `b'"'`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// let w: Matrix<f32> = Matrix::from_csv("tests/data/weight.csv")
+    ///     .quote(b'\'')
+    ///     .load()
+    ///     .unwrap();
+    /// ```
+    pub fn quote(self, quote: u8) -> MatrixLoaderForCSV<'a, T> {
+        MatrixLoaderForCSV { quote, ..self }
+    }
+
+    /// Set to false to disable escaping quotes by doubling them. By default,
+    /// it is set to true.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// let w: Matrix<f32> = Matrix::from_csv("tests/data/weight.csv")
+    ///     .double_quote(false)
+    ///     .escape(Some(b'\\'))
+    ///     .load()
+    ///     .unwrap();
+    /// ```
+    pub fn double_quote(self, yes: bool) -> MatrixLoaderForCSV<'a, T> {
+        MatrixLoaderForCSV {
+            double_quote: yes,
+            ..self
+        }
+    }
+
+    /// Set the escape character for quotes, used when [`double_quote`] is
+    /// disabled. By default, there is no escape character.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// let w: Matrix<f32> = Matrix::from_csv("tests/data/weight.csv")
+    ///     .double_quote(false)
+    ///     .escape(Some(b'\\'))
+    ///     .load()
+    ///     .unwrap();
+    /// ```
+    ///
+    /// [`double_quote`]: #method.double_quote
+    pub fn escape(self, escape: Option<u8>) -> MatrixLoaderForCSV<'a, T> {
+        MatrixLoaderForCSV { escape, ..self }
+    }
+
+    /// Set the tokens that mark a missing value. The fields are trimmed
+    /// before they are compared with the tokens. By default, only empty
+    /// fields are missing values.
+    ///
+    /// By default, loading a missing value returns an error, see
+    /// [`fill_missing`], [`fill_nan`], [`drop_missing`] and
+    /// [`load_with_mask`] to handle them instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// let w: Matrix<f64> = Matrix::from_csv("tests/data/matrix_missing.csv")
+    ///     .has_headers(true)
+    ///     .missing_values(&["", "NA"])
+    ///     .fill_missing(-1.0)
+    ///     .load()
+    ///     .unwrap();
+    /// assert_eq!(w, matrix![1.0, -1.0, 3.0; 4.0, 5.0, 6.0; -1.0, 8.0, -1.0]);
     /// ```
     ///
-    pub fn from_csv<P>(file_path: P) -> MatrixLoaderForCSV<T, P>
+    /// [`fill_missing`]: #method.fill_missing
+    /// [`fill_nan`]: #method.fill_nan
+    /// [`drop_missing`]: #method.drop_missing
+    /// [`load_with_mask`]: #method.load_with_mask
+    pub fn missing_values<S>(self, tokens: &[S]) -> MatrixLoaderForCSV<'a, T>
     where
-        P: AsRef<Path>,
+        S: AsRef<str>,
     {
+        let tokens = tokens.iter().map(|token| token.as_ref().to_string());
         MatrixLoaderForCSV {
-            file_path,
-            has_headers: false,
-            phantom: PhantomData,
+            missing_values: tokens.collect(),
+            ..self
+        }
+    }
+
+    /// Fill the missing values with `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// let w: Matrix<f64> = Matrix::from_csv("tests/data/matrix_missing.csv")
+    ///     .has_headers(true)
+    ///     .missing_values(&["", "NA"])
+    ///     .fill_missing(0.0)
+    ///     .load()
+    ///     .unwrap();
+    /// ```
+    pub fn fill_missing(self, value: T) -> MatrixLoaderForCSV<'a, T> {
+        MatrixLoaderForCSV {
+            missing: Missing::Fill(value),
+            ..self
+        }
+    }
+
+    /// Drop the rows that contain missing values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// let w: Matrix<f64> = Matrix::from_csv("tests/data/matrix_missing.csv")
+    ///     .has_headers(true)
+    ///     .missing_values(&["", "NA"])
+    ///     .drop_missing()
+    ///     .load()
+    ///     .unwrap();
+    /// assert_eq!(w, matrix![4.0, 5.0, 6.0]);
+    /// ```
+    pub fn drop_missing(self) -> MatrixLoaderForCSV<'a, T> {
+        MatrixLoaderForCSV {
+            missing: Missing::DropRow,
+            ..self
+        }
+    }
+
+    /// Set the thousands separator, it is removed from the fields before
+    /// they are parsed. By default, there is no thousands separator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// let w: Matrix<i64> = Matrix::from_csv_str("\"1,234\",5\n-7,\"1,000,000\"")
+    ///     .thousands(Some(','))
+    ///     .load()
+    ///     .unwrap();
+    /// assert_eq!(w, matrix![1234, 5; -7, 1000000]);
+    /// ```
+    pub fn thousands(
+        self,
+        separator: Option<char>,
+    ) -> MatrixLoaderForCSV<'a, T> {
+        let format = utils::NumberFormat {
+            thousands: separator,
+            ..self.format
+        };
+        MatrixLoaderForCSV { format, ..self }
+    }
+
+    /// Set the decimal separator. By default, it is set to `'.'`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// let w: Matrix<f64> = Matrix::from_csv_str("1.234,5;0,25\n-3;1e-3")
+    ///     .delimiter(b';')
+    ///     .thousands(Some('.'))
+    ///     .decimal(',')
+    ///     .load()
+    ///     .unwrap();
+    /// assert_eq!(w, matrix![1234.5, 0.25; -3.0, 0.001]);
+    /// ```
+    pub fn decimal(self, separator: char) -> MatrixLoaderForCSV<'a, T> {
+        let format = utils::NumberFormat {
+            decimal: separator,
+            ..self.format
+        };
+        MatrixLoaderForCSV { format, ..self }
+    }
+
+    /// Set a custom parser of the elements, it replaces the default parser
+    /// of [`ParseElement`]. The parser should returns `None` if the field is
+    /// not a valid element. The missing values are handled before the
+    /// parser is called.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// // Percentages
+    /// let w: Matrix<f64> = Matrix::from_csv_str("50%,25%\n100%,1%")
+    ///     .parser(|value| {
+    ///         let value = value.trim().trim_end_matches('%');
+    ///         value.parse::<f64>().ok().map(|x| x / 100.0)
+    ///     })
+    ///     .load()
+    ///     .unwrap();
+    /// assert_eq!(w, matrix![0.5, 0.25; 1.0, 0.01]);
+    /// ```
+    ///
+    /// [`ParseElement`]: ../../utils/trait.ParseElement.html
+    pub fn parser<F>(self, parser: F) -> MatrixLoaderForCSV<'a, T>
+    where
+        F: Fn(&str) -> Option<T> + 'a,
+    {
+        MatrixLoaderForCSV {
+            parser: Some(Parser(Box::new(parser))),
+            ..self
+        }
+    }
+
+    /// Set to true to continue loading after an invalid element, a missing
+    /// value or a row with different number of fields, and to return all of
+    /// them at the end via [`MatrixLoadError::errors`]. By default, it is
+    /// set to false and loading stops at the first error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// let result: Result<Matrix<u32>, MatrixLoadError> =
+    ///     Matrix::from_csv("tests/data/matrix_valid_without_header.csv")
+    ///         .lenient(true)
+    ///         .load();
+    /// let error = result.unwrap_err();
+    /// assert_eq!(error.errors().len(), 3);
+    /// ```
+    ///
+    /// [`MatrixLoadError::errors`]: struct.MatrixLoadError.html#method.errors
+    pub fn lenient(self, yes: bool) -> MatrixLoaderForCSV<'a, T> {
+        MatrixLoaderForCSV {
+            lenient: yes,
+            ..self
+        }
+    }
+
+    /// Load Matrix from CSV file. You need to explicitly annotate the numeric type.
+    ///
+    /// # Examples
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// let dataset: Matrix<f32> = Matrix::from_csv("tests/data/weight.csv").load().unwrap();
+    /// ```
+    pub fn load(self) -> Result<Matrix<T>, MatrixLoadError>
+    where
+        T: Num + Copy + utils::TypeName + utils::ParseElement,
+    {
+        let (matrix, _mask) = self.read(false)?;
+        Ok(matrix)
+    }
+
+    /// Load Matrix from CSV file together with a mask of the missing values.
+    /// The mask has the same shape as the matrix, its elements are `1` where
+    /// the value is missing or padded and `0` elsewhere.
+    ///
+    /// The missing values are filled with the value set via
+    /// [`fill_missing`], or zero if it is not set. The rows dropped via
+    /// [`drop_missing`] are not included.
+    ///
+    /// # Examples
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// let (w, mask): (Matrix<f64>, Matrix<u8>) =
+    ///     Matrix::from_csv("tests/data/matrix_missing.csv")
+    ///         .has_headers(true)
+    ///         .missing_values(&["", "NA"])
+    ///         .fill_nan()
+    ///         .load_with_mask()
+    ///         .unwrap();
+    /// assert_eq!(mask, matrix![0, 1, 0; 0, 0, 0; 1, 0, 1]);
+    /// ```
+    ///
+    /// [`fill_missing`]: #method.fill_missing
+    /// [`drop_missing`]: #method.drop_missing
+    pub fn load_with_mask(
+        self,
+    ) -> Result<(Matrix<T>, Matrix<u8>), MatrixLoadError>
+    where
+        T: Num + Copy + utils::TypeName + utils::ParseElement,
+    {
+        self.read(true)
+    }
+
+    /// Load Matrix from CSV file in chunks of at most `size` rows, so files
+    /// larger than memory can be processed. The chunks are loaded lazily
+    /// while iterating. You need to explicitly annotate the numeric type.
+    ///
+    /// If [`flexible`] is enabled, each chunk is padded to the widest row
+    /// loaded so far. Loading stops at the first error, unless [`lenient`]
+    /// is enabled, in which case the chunks that contain invalid elements
+    /// are returned as errors and loading continues with the next chunk.
+    ///
+    /// # Panics
+    /// Panics if `size` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// let chunks: Vec<Matrix<i32>> =
+    ///     Matrix::from_csv("tests/data/matrix_valid_without_header.csv")
+    ///         .chunks(2)
+    ///         .map(|chunk| chunk.unwrap())
+    ///         .collect();
+    /// assert_eq!(chunks, vec![matrix![-1, 2, 3; 4, -5, 6], matrix![7, 8, -9]]);
+    /// ```
+    ///
+    /// [`flexible`]: #method.flexible
+    /// [`lenient`]: #method.lenient
+    pub fn chunks(self, size: usize) -> MatrixChunksForCSV<'a, T> {
+        assert!(size != 0, "chunk size must be non-zero");
+        MatrixChunksForCSV {
+            state: ChunksState::Pending(self),
+            size,
+        }
+    }
+
+    // Read the whole CSV file, the mask is only valid if `with_mask` is
+    // true.
+    fn read(
+        self,
+        with_mask: bool,
+    ) -> Result<(Matrix<T>, Matrix<u8>), MatrixLoadError>
+    where
+        T: Num + Copy + utils::TypeName + utils::ParseElement,
+    {
+        let mut records = self.open()?;
+        match records.read_chunk(usize::MAX, with_mask)? {
+            Some(chunk) => Ok(chunk),
+            None => Err(MatrixLoadError::new(
+                MatrixLoadErrorKind::Empty,
+                String::from("Cannot load empty file"),
+            )),
+        }
+    }
+
+    // Open the CSV file, skip the rows and read the header row.
+    fn open(self) -> Result<CSVRecords<'a, T>, MatrixLoadError> {
+        // Row lengths are checked after the columns are selected, so the
+        // skipped rows may have any number of fields
+        let reader = self.source.into_reader()?;
+        let (reader, lines) = utils::LineCounter::new(reader, self.comment);
+        let mut records = csv::ReaderBuilder::new()
+            .has_headers(false)
+            .flexible(true)
+            .delimiter(self.delimiter)
+            .comment(self.comment)
+            .quoting(self.quoting)
+            .quote(self.quote)
+            .double_quote(self.double_quote)
+            .escape(self.escape)
+            .from_reader(reader)
+            .into_records();
+        for _ in 0..self.skip_rows {
+            if records.next().is_none() {
+                break;
+            }
+        }
+
+        // Find the indices of the selected columns
+        let headers = if self.has_headers {
+            match records.next() {
+                Some(result) => Some(result?),
+                None => None,
+            }
+        } else {
+            None
+        };
+        let indices = match self.usecols {
+            Columns::All => None,
+            Columns::Indices(indices) => Some(indices),
+            Columns::Names(ref names) => {
+                Some(column_indices(names, headers.as_ref())?)
+            }
+        };
+
+        Ok(CSVRecords {
+            records,
+            lines,
+            headers,
+            indices,
+            remaining: self.max_rows.unwrap_or(usize::MAX),
+            ncols: None,
+            flexible: self.flexible,
+            padding: self.padding,
+            missing_values: self.missing_values,
+            missing: self.missing,
+            lenient: self.lenient,
+            format: self.format,
+            parser: self.parser,
+        })
+    }
+}
+
+// Loaded matrix and its mask of missing values.
+type Chunk<T> = (Matrix<T>, Matrix<u8>);
+
+// Records of the opened CSV file.
+struct CSVRecords<'a, T> {
+    records: csv::StringRecordsIntoIter<
+        utils::LineTrackingReader<Box<dyn io::Read + 'a>>,
+    >,
+    lines: utils::LineCounter,
+    headers: Option<csv::StringRecord>,
+    indices: Option<Vec<usize>>,
+    // Number of records left to load
+    remaining: usize,
+    // Number of columns of the loaded rows
+    ncols: Option<usize>,
+    flexible: bool,
+    padding: T,
+    missing_values: Vec<String>,
+    missing: Missing<T>,
+    lenient: bool,
+    format: utils::NumberFormat,
+    parser: Option<Parser<'a, T>>,
+}
+
+impl<'a, T> CSVRecords<'a, T>
+where
+    T: Num + Copy + utils::TypeName + utils::ParseElement,
+{
+    // Read at most `size` rows directly into the flat storage of the matrix.
+    // Returns `None` if there are no more rows, the mask is only valid if
+    // `with_mask` is true. In lenient mode the errors are collected and
+    // returned at the end of the chunk.
+    fn read_chunk(
+        &mut self,
+        size: usize,
+        with_mask: bool,
+    ) -> Result<Option<Chunk<T>>, MatrixLoadError> {
+        let mut elements: Vec<T> = Vec::new();
+        let mut mask: Vec<u8> = Vec::new();
+        let mut nrows = 0;
+        let mut errors = Vec::new();
+        let mut row = Vec::new();
+        let mut row_mask = Vec::new();
+        'records: while nrows < size && self.remaining > 0 {
+            let record = match self.records.next() {
+                Some(Ok(record)) => record,
+                Some(Err(error)) => {
+                    let lines = &mut self.lines;
+                    let line = error.position().map(|p| lines.line(p.byte()));
+                    let record = error.position().map(|p| p.record() + 1);
+                    let error = MatrixLoadError::from(error);
+                    return Err(error.at(line, record, None, None));
+                }
+                None => break,
+            };
+            self.remaining -= 1;
+            let (line, number) = match record.position() {
+                Some(position) => (
+                    Some(self.lines.line(position.byte())),
+                    Some(position.record() + 1),
+                ),
+                None => (None, None),
+            };
+            // Set the location of the error that occurred at column `index`
+            let headers = &self.headers;
+            let locate = |error: MatrixLoadError, index: Option<usize>| {
+                let header = match (index, headers) {
+                    (Some(index), Some(headers)) => {
+                        headers.get(index).map(|h| h.trim().to_string())
+                    }
+                    _ => None,
+                };
+                error.at(line, number, index.map(|i| i + 1), header)
+            };
+
+            row.clear();
+            row_mask.clear();
+            let ncols = match self.indices {
+                Some(ref indices) => indices.len(),
+                None => record.len(),
+            };
+            for i in 0..ncols {
+                let index = match self.indices {
+                    Some(ref indices) => indices[i],
+                    None => i,
+                };
+                let value = match record.get(index) {
+                    Some(value) => value,
+                    None if self.flexible => {
+                        row.push(self.padding);
+                        row_mask.push(1);
+                        continue;
+                    }
+                    None => {
+                        let error = MatrixLoadError::new(
+                            MatrixLoadErrorKind::InvalidColumn,
+                            format!(
+                                "column is out of bounds, the record has {} \
+                                 fields",
+                                record.len()
+                            ),
+                        );
+                        report(
+                            self.lenient,
+                            &mut errors,
+                            locate(error, Some(index)),
+                        )?;
+                        row.push(T::zero());
+                        row_mask.push(1);
+                        continue;
+                    }
+                };
+                let missing =
+                    self.missing_values.iter().any(|t| t == value.trim());
+                if !missing {
+                    match parse_element(value, &self.format, &self.parser) {
+                        Ok(element) => row.push(element),
+                        Err(error) => {
+                            report(
+                                self.lenient,
+                                &mut errors,
+                                locate(error, Some(index)),
+                            )?;
+                            row.push(T::zero());
+                        }
+                    }
+                    row_mask.push(0);
+                    continue;
+                }
+                match self.missing {
+                    Missing::Fill(fill) => row.push(fill),
+                    Missing::DropRow => continue 'records,
+                    Missing::Error if with_mask => row.push(T::zero()),
+                    Missing::Error => {
+                        let error = MatrixLoadError::new(
+                            MatrixLoadErrorKind::MissingValue,
+                            format!("{:?} is a missing value", value),
+                        );
+                        report(
+                            self.lenient,
+                            &mut errors,
+                            locate(error, Some(index)),
+                        )?;
+                        row.push(T::zero());
+                    }
+                }
+                row_mask.push(1);
+            }
+
+            // The first row sets the number of columns
+            let ncols = *self.ncols.get_or_insert(row.len());
+            if row.len() > ncols && self.flexible {
+                // Pad the rows that are already loaded
+                elements =
+                    widen(&elements, nrows, ncols, row.len(), self.padding);
+                if with_mask {
+                    mask = widen(&mask, nrows, ncols, row.len(), 1);
+                }
+                self.ncols = Some(row.len());
+            } else if row.len() < ncols && self.flexible {
+                row.resize(ncols, self.padding);
+                row_mask.resize(ncols, 1);
+            } else if row.len() != ncols {
+                let error = MatrixLoadError::new(
+                    MatrixLoadErrorKind::CSVError,
+                    format!(
+                        "found record with {} fields, but the previous \
+                         record has {} fields",
+                        row.len(),
+                        ncols
+                    ),
+                );
+                report(self.lenient, &mut errors, locate(error, None))?;
+                continue;
+            }
+            elements.extend_from_slice(&row);
+            if with_mask {
+                mask.extend_from_slice(&row_mask);
+            }
+            nrows += 1;
+        }
+        if !errors.is_empty() {
+            return Err(MatrixLoadError::collect(errors));
+        }
+        if nrows == 0 {
+            return Ok(None);
+        }
+
+        let ncols = self.ncols.unwrap_or(0);
+        let matrix = Matrix {
+            nrows,
+            ncols,
+            vec: Vector::from(elements),
+        };
+        let mask = if with_mask {
+            Matrix {
+                nrows,
+                ncols,
+                vec: Vector::from(mask),
+            }
+        } else {
+            Matrix {
+                nrows: 0,
+                ncols: 0,
+                vec: Vector::from(mask),
+            }
+        };
+        Ok(Some((matrix, mask)))
+    }
+}
+
+/// Iterator over the chunks of CSV file.
+///
+/// See also: [`MatrixLoaderForCSV::chunks`].
+///
+/// [`MatrixLoaderForCSV::chunks`]: struct.MatrixLoaderForCSV.html#method.chunks
+pub struct MatrixChunksForCSV<'a, T> {
+    state: ChunksState<'a, T>,
+    size: usize,
+}
+
+// The CSV file is opened when the first chunk is requested
+enum ChunksState<'a, T> {
+    Pending(MatrixLoaderForCSV<'a, T>),
+    Open(CSVRecords<'a, T>),
+    Done,
+}
+
+impl<'a, T> Iterator for MatrixChunksForCSV<'a, T>
+where
+    T: Num + Copy + utils::TypeName + utils::ParseElement,
+{
+    type Item = Result<Matrix<T>, MatrixLoadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let ChunksState::Pending(_) = self.state {
+            let state = mem::replace(&mut self.state, ChunksState::Done);
+            if let ChunksState::Pending(loader) = state {
+                match loader.open() {
+                    Ok(records) => self.state = ChunksState::Open(records),
+                    Err(error) => return Some(Err(error)),
+                }
+            }
+        }
+        let records = match self.state {
+            ChunksState::Open(ref mut records) => records,
+            _ => return None,
+        };
+        match records.read_chunk(self.size, false) {
+            Ok(Some((matrix, _mask))) => Some(Ok(matrix)),
+            Ok(None) => {
+                self.state = ChunksState::Done;
+                None
+            }
+            Err(error) => {
+                // Only the errors collected in lenient mode are recoverable
+                if !records.lenient || error.errors().is_empty() {
+                    self.state = ChunksState::Done;
+                }
+                Some(Err(error))
+            }
+        }
+    }
+}
+
+// Pad each of the `nrows` rows of the flat storage `data` from `ncols` to
+// `new_ncols` columns with `value`.
+fn widen<U>(
+    data: &[U],
+    nrows: usize,
+    ncols: usize,
+    new_ncols: usize,
+    value: U,
+) -> Vec<U>
+where
+    U: Copy,
+{
+    let mut widened = Vec::with_capacity(nrows * new_ncols);
+    for row in data.chunks(ncols.max(1)).take(nrows) {
+        widened.extend_from_slice(row);
+        widened.resize(widened.len() + new_ncols - row.len(), value);
+    }
+    // Rows without columns
+    widened.resize(nrows * new_ncols, value);
+    widened
+}
+
+impl<'a, T> MatrixLoaderForCSV<'a, T>
+where
+    T: Float,
+{
+    /// Fill the missing values with NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// let w: Matrix<f64> = Matrix::from_csv("tests/data/matrix_missing.csv")
+    ///     .has_headers(true)
+    ///     .missing_values(&["", "NA"])
+    ///     .fill_nan()
+    ///     .load()
+    ///     .unwrap();
+    /// assert!(w.at(0, 1).is_nan());
+    /// ```
+    pub fn fill_nan(self) -> MatrixLoaderForCSV<'a, T> {
+        self.fill_missing(T::nan())
+    }
+}
+
+// Returns the indices of the columns named `names` in the header row.
+fn column_indices(
+    names: &[String],
+    headers: Option<&csv::StringRecord>,
+) -> Result<Vec<usize>, MatrixLoadError> {
+    let headers = match headers {
+        Some(headers) => headers,
+        None => {
+            return Err(MatrixLoadError::new(
+                MatrixLoadErrorKind::InvalidColumn,
+                String::from("header row is not found"),
+            ));
+        }
+    };
+    let mut indices = Vec::with_capacity(names.len());
+    for name in names {
+        match headers.iter().position(|h| h.trim() == name) {
+            Some(index) => indices.push(index),
+            None => {
+                return Err(MatrixLoadError::new(
+                    MatrixLoadErrorKind::InvalidColumn,
+                    format!("header {:?} is not found", name),
+                ));
+            }
+        }
+    }
+    Ok(indices)
+}
+
+// Returns `error` if lenient mode is disabled, otherwise push it to `errors`.
+fn report(
+    lenient: bool,
+    errors: &mut Vec<MatrixLoadError>,
+    error: MatrixLoadError,
+) -> Result<(), MatrixLoadError> {
+    if lenient {
+        errors.push(error);
+        Ok(())
+    } else {
+        Err(error)
+    }
+}
+
+// Parse the element using `parser` if any, or the default parser in the
+// given `format`. It will returns an error if `value` is not a valid number
+// of type `T`.
+fn parse_element<T>(
+    value: &str,
+    format: &utils::NumberFormat,
+    parser: &Option<Parser<T>>,
+) -> Result<T, MatrixLoadError>
+where
+    T: utils::TypeName + utils::ParseElement,
+{
+    let element = match parser {
+        Some(Parser(parser)) => parser(value),
+        None => T::parse_element(value, format),
+    };
+    match element {
+        Some(element) => Ok(element),
+        None => Err(MatrixLoadError::new(
+            MatrixLoadErrorKind::InvalidElement,
+            format!("{:?} is not valid {}", value, T::type_name()),
+        )),
+    }
+}
+
+/// Matrix writer for CSV formatted file.
+///
+/// See also: [`Matrix::to_csv`] and [`Matrix::to_csv_writer`].
+///
+/// [`Matrix::to_csv`]: struct.Matrix.html#method.to_csv
+/// [`Matrix::to_csv_writer`]: struct.Matrix.html#method.to_csv_writer
+pub struct MatrixWriterForCSV<'a, T>
+where
+    T: Num + Copy,
+{
+    matrix: &'a Matrix<T>,
+    destination: utils::CSVDestination<'a>,
+    has_headers: bool,
+    headers: Option<Vec<String>>,
+    delimiter: u8,
+    precision: Option<usize>,
+    scientific: bool,
+}
+
+impl<'a, T> MatrixWriterForCSV<'a, T>
+where
+    T: Num + Copy,
+{
+    /// Set to true to write a header row before the elements. By default, it is set
+    /// to false. The column indices are used as header names unless they are
+    /// specified using [`headers`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// let w = matrix![1, 2; 3, 4];
+    /// let mut buf = Vec::new();
+    /// w.to_csv_writer(&mut buf).has_headers(true).write().unwrap();
+    /// assert_eq!(String::from_utf8(buf).unwrap(), "0,1\n1,2\n3,4\n");
+    /// ```
+    ///
+    /// [`headers`]: #method.headers
+    pub fn has_headers(self, yes: bool) -> MatrixWriterForCSV<'a, T> {
+        MatrixWriterForCSV {
+            has_headers: yes,
+            ..self
+        }
+    }
+
+    /// Set the header names and write them as the header row. The number of
+    /// names should be equal to the number of columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// let w = matrix![1, 2; 3, 4];
+    /// let mut buf = Vec::new();
+    /// w.to_csv_writer(&mut buf).headers(&["x", "y"]).write().unwrap();
+    /// assert_eq!(String::from_utf8(buf).unwrap(), "x,y\n1,2\n3,4\n");
+    /// ```
+    pub fn headers<S>(self, names: &[S]) -> MatrixWriterForCSV<'a, T>
+    where
+        S: AsRef<str>,
+    {
+        let names = names.iter().map(|name| name.as_ref().to_string());
+        MatrixWriterForCSV {
+            has_headers: true,
+            headers: Some(names.collect()),
+            ..self
+        }
+    }
+
+    /// Set the field delimiter. By default, it is set to `b','`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// let w = matrix![1, 2; 3, 4];
+    /// let mut buf = Vec::new();
+    /// w.to_csv_writer(&mut buf).delimiter(b'\t').write().unwrap();
+    /// assert_eq!(String::from_utf8(buf).unwrap(), "1\t2\n3\t4\n");
+    /// ```
+    pub fn delimiter(self, delimiter: u8) -> MatrixWriterForCSV<'a, T> {
+        MatrixWriterForCSV { delimiter, ..self }
+    }
+
+    /// Set the number of digits after the decimal point. By default, the
+    /// elements are written using the shortest representation that can be
+    /// loaded back without loss. It has no effect on integer elements unless
+    /// they are written in scientific notation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// let w = matrix![1.0, 0.25; 1.0 / 3.0, 4.0];
+    /// let mut buf = Vec::new();
+    /// w.to_csv_writer(&mut buf).precision(2).write().unwrap();
+    /// assert_eq!(
+    ///     String::from_utf8(buf).unwrap(),
+    ///     "1.00,0.25\n0.33,4.00\n"
+    /// );
+    /// ```
+    pub fn precision(self, precision: usize) -> MatrixWriterForCSV<'a, T> {
+        MatrixWriterForCSV {
+            precision: Some(precision),
+            ..self
+        }
+    }
+
+    /// Set to true to write the elements in scientific notation. By default,
+    /// it is set to false.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// let w = matrix![1500.0, 0.25];
+    /// let mut buf = Vec::new();
+    /// w.to_csv_writer(&mut buf).scientific(true).write().unwrap();
+    /// assert_eq!(String::from_utf8(buf).unwrap(), "1.5e3,2.5e-1\n");
+    /// ```
+    pub fn scientific(self, yes: bool) -> MatrixWriterForCSV<'a, T> {
+        MatrixWriterForCSV {
+            scientific: yes,
+            ..self
+        }
+    }
+
+    /// Write Matrix as CSV. If the destination is a file path, the file is
+    /// created or truncated.
+    ///
+    /// # Examples
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// let w = matrix![1, 2; 3, 4];
+    /// let mut buf = Vec::new();
+    /// w.to_csv_writer(&mut buf).write().unwrap();
+    /// assert_eq!(String::from_utf8(buf).unwrap(), "1,2\n3,4\n");
+    /// ```
+    pub fn write(self) -> Result<(), MatrixLoadError>
+    where
+        T: fmt::Display + fmt::LowerExp,
+    {
+        let ncols = self.matrix.ncols;
+        let headers = match self.headers {
+            Some(ref names) if names.len() != ncols => {
+                return Err(MatrixLoadError::new(
+                    MatrixLoadErrorKind::InvalidHeaders,
+                    format!(
+                        "expected {} header names, got {}",
+                        ncols,
+                        names.len()
+                    ),
+                ));
+            }
+            Some(names) => names,
+            None => (0..ncols).map(|j| j.to_string()).collect(),
+        };
+
+        let mut wtr = csv::WriterBuilder::new()
+            .delimiter(self.delimiter)
+            .from_writer(self.destination.into_writer()?);
+        if self.has_headers {
+            wtr.write_record(&headers)?;
+        }
+        let (precision, scientific) = (self.precision, self.scientific);
+        for row in self.matrix.rows() {
+            let record = row.elements().map(|value| {
+                utils::format_element(value, precision, scientific)
+            });
+            wtr.write_record(record)?;
+        }
+        wtr.flush()?;
+        Ok(())
+    }
+}
+
+/// Matrix writer for NPY formatted file.
+///
+/// See also: [`Matrix::to_npy`].
+///
+/// [`Matrix::to_npy`]: ../struct.Matrix.html#method.to_npy
+pub struct MatrixWriterForNpy<'a, T>
+where
+    T: Num + Copy,
+{
+    matrix: &'a Matrix<T>,
+    file_path: PathBuf,
+    big_endian: bool,
+    fortran_order: bool,
+}
+
+impl<'a, T> MatrixWriterForNpy<'a, T>
+where
+    T: Num + Copy,
+{
+    /// Set to true to write the elements in big endian. By default, it is
+    /// set to false.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// # let path = std::env::temp_dir().join("crabsformer_doc_matrix_big.npy");
+    /// let w = matrix![1.5, 2.0; 3.0, 4.0];
+    /// w.to_npy(&path).big_endian(true).write().unwrap();
+    /// let v: Matrix<f64> = Matrix::from_npy(&path).unwrap();
+    /// assert_eq!(v, w);
+    /// ```
+    pub fn big_endian(self, yes: bool) -> MatrixWriterForNpy<'a, T> {
+        MatrixWriterForNpy {
+            big_endian: yes,
+            ..self
+        }
+    }
+
+    /// Set to true to write the elements in Fortran (column-major) order.
+    /// By default, it is set to false and the elements are written in C
+    /// (row-major) order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// # let path = std::env::temp_dir().join("crabsformer_doc_fortran.npy");
+    /// let w = matrix![1, 2, 3; 4, 5, 6];
+    /// w.to_npy(&path).fortran_order(true).write().unwrap();
+    /// let v: Matrix<i32> = Matrix::from_npy(&path).unwrap();
+    /// assert_eq!(v, w);
+    /// ```
+    pub fn fortran_order(self, yes: bool) -> MatrixWriterForNpy<'a, T> {
+        MatrixWriterForNpy {
+            fortran_order: yes,
+            ..self
+        }
+    }
+
+    /// Write Matrix as NPY file. The file is created or truncated.
+    pub fn write(self) -> Result<(), MatrixLoadError>
+    where
+        T: utils::BinaryElement,
+    {
+        let mut file = BufWriter::new(File::create(&self.file_path)?);
+        self.matrix.write_npy(
+            &mut file,
+            self.big_endian,
+            self.fortran_order,
+        )?;
+        file.flush()?;
+        Ok(())
+    }
+}
+
+/// Matrix loader for LIBSVM (SVMlight) formatted file.
+///
+/// See also: [`Matrix::from_libsvm`].
+///
+/// [`Matrix::from_libsvm`]: ../struct.Matrix.html#method.from_libsvm
+#[derive(Debug)]
+pub struct MatrixLoaderForLibsvm {
+    file_path: PathBuf,
+    zero_based: bool,
+    n_features: Option<usize>,
+}
+
+impl MatrixLoaderForLibsvm {
+    /// Set to true if the feature indices start from zero. By default, it
+    /// is set to false and the feature indices start from one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// let (x, y) = Matrix::from_libsvm("tests/data/dataset_zero_based.svm")
+    ///     .zero_based(true)
+    ///     .load()
+    ///     .unwrap();
+    /// assert_eq!(x, matrix![0.5, 0.0; 0.0, 2.0]);
+    /// assert_eq!(y, vector![1.0, -1.0]);
+    /// ```
+    pub fn zero_based(self, yes: bool) -> MatrixLoaderForLibsvm {
+        MatrixLoaderForLibsvm {
+            zero_based: yes,
+            ..self
+        }
+    }
+
+    /// Set the number of features, which is the number of columns of the
+    /// feature matrix. By default, it is the largest feature index found in
+    /// the file. Loading will returns an error if a feature index is out of
+    /// bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// let (x, _y) = Matrix::from_libsvm("tests/data/dataset.svm")
+    ///     .n_features(5)
+    ///     .load()
+    ///     .unwrap();
+    /// assert_eq!(x.shape(), [3, 5]);
+    /// ```
+    pub fn n_features(self, n: usize) -> MatrixLoaderForLibsvm {
+        MatrixLoaderForLibsvm {
+            n_features: Some(n),
+            ..self
+        }
+    }
+
+    /// Load the feature matrix and the label vector. The features that are
+    /// not present in a line are set to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// let (x, y) = Matrix::from_libsvm("tests/data/dataset.svm")
+    ///     .load()
+    ///     .unwrap();
+    /// assert_eq!(x, matrix![0.5, 0.0, 1.0; 0.0, 2.0, 0.0; 0.0, 0.0, 0.0]);
+    /// assert_eq!(y, vector![1.0, -1.0, 1.0]);
+    /// ```
+    pub fn load(self) -> Result<(Matrix<f64>, Vector<f64>), MatrixLoadError> {
+        let file = BufReader::new(File::open(&self.file_path)?);
+        let format = utils::NumberFormat::default();
+        let mut labels = Vec::new();
+        let mut entries = Vec::new();
+        let mut ncols = 0;
+        // Line and column of the largest feature index
+        let mut widest = (None, 0);
+        for (i, line) in file.lines().enumerate() {
+            let line = line?;
+            let number = Some(i as u64 + 1);
+            let invalid = |kind, message, column: usize| {
+                MatrixLoadError::new(kind, message).at(
+                    number,
+                    None,
+                    Some(column),
+                    None,
+                )
+            };
+
+            // Remove the comment
+            let line = match line.find('#') {
+                Some(pos) => &line[..pos],
+                None => &line[..],
+            };
+            let mut fields = line.split_whitespace();
+            let label = match fields.next() {
+                Some(label) => label,
+                None => continue,
+            };
+            let label =
+                f64::parse_element(label, &format).ok_or_else(|| {
+                    invalid(
+                        MatrixLoadErrorKind::InvalidElement,
+                        format!("{:?} is not valid label", label),
+                        1,
+                    )
+                })?;
+            let row = labels.len();
+            labels.push(label);
+
+            for (k, field) in fields.enumerate() {
+                let column = k + 2;
+                let mut pair = field.splitn(2, ':');
+                let (index, value) = match (pair.next(), pair.next()) {
+                    (Some(index), Some(value)) => (index, value),
+                    _ => {
+                        return Err(invalid(
+                            MatrixLoadErrorKind::InvalidFormat,
+                            format!("expected index:value, got {:?}", field),
+                            column,
+                        ));
+                    }
+                };
+                // Query ID of ranking datasets
+                if index == "qid" {
+                    continue;
+                }
+                let col = index
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|index| {
+                        if self.zero_based {
+                            Some(index)
+                        } else {
+                            index.checked_sub(1)
+                        }
+                    })
+                    .filter(|col| match self.n_features {
+                        Some(n) => *col < n,
+                        None => true,
+                    })
+                    .ok_or_else(|| {
+                        invalid(
+                            MatrixLoadErrorKind::InvalidColumn,
+                            format!("invalid feature index {:?}", index),
+                            column,
+                        )
+                    })?;
+                let value =
+                    f64::parse_element(value, &format).ok_or_else(|| {
+                        invalid(
+                            MatrixLoadErrorKind::InvalidElement,
+                            format!("{:?} is not valid f64", value),
+                            column,
+                        )
+                    })?;
+                let width = col.checked_add(1).ok_or_else(|| {
+                    invalid(
+                        MatrixLoadErrorKind::InvalidColumn,
+                        format!("feature index {:?} is too large", index),
+                        column,
+                    )
+                })?;
+                if width > ncols {
+                    ncols = width;
+                    widest = (number, column);
+                }
+                entries.push((row, col, value));
+            }
+        }
+        if labels.is_empty() {
+            return Err(MatrixLoadError::new(
+                MatrixLoadErrorKind::Empty,
+                String::from("Cannot load empty file"),
+            ));
+        }
+
+        let nrows = labels.len();
+        let ncols = self.n_features.unwrap_or(ncols);
+        let mut elements = Vec::new();
+        let len = nrows
+            .checked_mul(ncols)
+            .filter(|len| elements.try_reserve_exact(*len).is_ok())
+            .ok_or_else(|| {
+                let (line, column) = widest;
+                MatrixLoadError::new(
+                    MatrixLoadErrorKind::InvalidShape,
+                    format!("{}x{} matrix is too large", nrows, ncols),
+                )
+                .at(line, None, Some(column), None)
+            })?;
+        elements.resize(len, 0.0);
+        for (row, col, value) in entries {
+            elements[row * ncols + col] = value;
+        }
+        let features = Matrix {
+            nrows,
+            ncols,
+            vec: Vector::from(elements),
+        };
+        Ok((features, Vector::from(labels)))
+    }
+}
+
+// Header of Netpbm image
+struct NetpbmHeader {
+    magic: [u8; 2],
+    width: usize,
+    height: usize,
+    max_value: u16,
+}
+
+// Reader of Netpbm image tokens, the comments that start with `#` are
+// skipped.
+struct NetpbmReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> NetpbmReader<'a> {
+    fn invalid(message: String) -> MatrixLoadError {
+        MatrixLoadError::new(MatrixLoadErrorKind::InvalidFormat, message)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(byte) = self.bytes.get(self.pos) {
+            if *byte == b'#' {
+                while self.pos < self.bytes.len()
+                    && self.bytes[self.pos] != b'\n'
+                {
+                    self.pos += 1;
+                }
+            } else if byte.is_ascii_whitespace() {
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+    }
+
+    // Read unsigned decimal integer
+    fn number(&mut self, name: &str) -> Result<usize, MatrixLoadError> {
+        self.skip_whitespace();
+        let start = self.pos;
+        while self.pos < self.bytes.len()
+            && self.bytes[self.pos].is_ascii_digit()
+        {
+            self.pos += 1;
+        }
+        std::str::from_utf8(&self.bytes[start..self.pos])
+            .ok()
+            .and_then(|number| number.parse().ok())
+            .ok_or_else(|| {
+                NetpbmReader::invalid(format!(
+                    "invalid {} at byte {}",
+                    name, start
+                ))
+            })
+    }
+
+    fn header(&mut self) -> Result<NetpbmHeader, MatrixLoadError> {
+        let magic = match self.bytes.get(..2) {
+            Some(magic) => [magic[0], magic[1]],
+            None => {
+                return Err(MatrixLoadError::new(
+                    MatrixLoadErrorKind::Empty,
+                    String::from("Cannot load empty file"),
+                ));
+            }
+        };
+        self.pos = 2;
+        let width = self.number("width")?;
+        let height = self.number("height")?;
+        let max_value = self.number("maximum value")?;
+        if max_value == 0 || max_value > 65535 {
+            return Err(NetpbmReader::invalid(format!(
+                "maximum value {} is out of range 1..=65535",
+                max_value
+            )));
+        }
+        Ok(NetpbmHeader {
+            magic,
+            width,
+            height,
+            max_value: max_value as u16,
+        })
+    }
+
+    // Read `len` samples, the binary samples are separated from the header
+    // by a single whitespace.
+    fn samples(
+        &mut self,
+        len: usize,
+        binary: bool,
+        max_value: u16,
+    ) -> Result<Vec<u16>, MatrixLoadError> {
+        let out_of_range = |i: usize| {
+            MatrixLoadError::new(
+                MatrixLoadErrorKind::InvalidElement,
+                format!(
+                    "sample {} is larger than the maximum value {}",
+                    i + 1,
+                    max_value
+                ),
+            )
+        };
+        let mut samples = Vec::new();
+        if binary {
+            self.pos += 1;
+            let size = if max_value > 255 { 2 } else { 1 };
+            let bytes = len
+                .checked_mul(size)
+                .and_then(|n| n.checked_add(self.pos))
+                .and_then(|end| self.bytes.get(self.pos..end))
+                .ok_or_else(|| {
+                    NetpbmReader::invalid(String::from(
+                        "unexpected end of file",
+                    ))
+                })?;
+            for (i, sample) in bytes.chunks(size).enumerate() {
+                let sample = match sample {
+                    [high, low] => u16::from_be_bytes([*high, *low]),
+                    _ => u16::from(sample[0]),
+                };
+                if sample > max_value {
+                    return Err(out_of_range(i));
+                }
+                samples.push(sample);
+            }
+        } else {
+            for i in 0..len {
+                let sample = self.number("sample")?;
+                if sample > max_value as usize {
+                    return Err(out_of_range(i));
+                }
+                samples.push(sample as u16);
+            }
+        }
+        Ok(samples)
+    }
+}
+
+// Load Netpbm image with the given number of channels, the channels are
+// interleaved in the file.
+fn read_netpbm<T, P>(
+    file_path: P,
+    channels: usize,
+) -> Result<Vec<Matrix<T>>, MatrixLoadError>
+where
+    T: Num + Copy + FromPrimitive + utils::TypeName,
+    P: AsRef<Path>,
+{
+    let bytes = std::fs::read(file_path)?;
+    let mut reader = NetpbmReader {
+        bytes: &bytes,
+        pos: 0,
+    };
+    let header = reader.header()?;
+    let (ascii, binary, name) = match channels {
+        1 => (b"P2", b"P5", "PGM"),
+        _ => (b"P3", b"P6", "PPM"),
+    };
+    let is_binary = if header.magic == *binary {
+        true
+    } else if header.magic == *ascii {
+        false
+    } else {
+        return Err(NetpbmReader::invalid(format!(
+            "expected {} image, got magic number {:?}",
+            name,
+            String::from_utf8_lossy(&header.magic)
+        )));
+    };
+    if T::from_u16(header.max_value).is_none() {
+        return Err(MatrixLoadError::new(
+            MatrixLoadErrorKind::InvalidDataType,
+            format!(
+                "cannot load samples up to {} as {}",
+                header.max_value,
+                T::type_name()
+            ),
+        ));
+    }
+    let (nrows, ncols) = (header.height, header.width);
+    let len = nrows
+        .checked_mul(ncols)
+        .and_then(|n| n.checked_mul(channels))
+        .ok_or_else(|| {
+            MatrixLoadError::new(
+                MatrixLoadErrorKind::InvalidShape,
+                format!("{}x{} image is too large", ncols, nrows),
+            )
+        })?;
+    let samples = reader.samples(len, is_binary, header.max_value)?;
+
+    // Samples are not larger than the maximum value, so they fit in `T`
+    let matrices = (0..channels)
+        .map(|c| {
+            let elements: Vec<T> = samples
+                .iter()
+                .skip(c)
+                .step_by(channels)
+                .map(|sample| T::from_u16(*sample).unwrap_or_else(T::zero))
+                .collect();
+            Matrix {
+                nrows,
+                ncols,
+                vec: Vector::from(elements),
+            }
+        })
+        .collect();
+    Ok(matrices)
+}
+
+/// Matrix writer for Netpbm (PGM and PPM) formatted image.
+///
+/// See also: [`Matrix::to_pgm`] and [`Matrix::to_ppm`].
+///
+/// [`Matrix::to_pgm`]: ../struct.Matrix.html#method.to_pgm
+/// [`Matrix::to_ppm`]: ../struct.Matrix.html#method.to_ppm
+pub struct MatrixWriterForNetpbm<'a, T>
+where
+    T: Num + Copy,
+{
+    channels: Vec<&'a Matrix<T>>,
+    file_path: PathBuf,
+    ascii: bool,
+    max_value: Option<u16>,
+}
+
+impl<'a, T> MatrixWriterForNetpbm<'a, T>
+where
+    T: Num + Copy,
+{
+    /// Set to true to write the samples as decimal numbers (P2 or P3). By
+    /// default, it is set to false and the samples are written as bytes
+    /// (P5 or P6).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// # let path = std::env::temp_dir().join("crabsformer_doc_ascii.pgm");
+    /// let image: Matrix<u8> = matrix![0, 128; 255, 64];
+    /// image.to_pgm(&path).ascii(true).write().unwrap();
+    /// assert_eq!(
+    ///     std::fs::read_to_string(&path).unwrap(),
+    ///     "P2\n2 2\n255\n0 128\n255 64\n"
+    /// );
+    /// ```
+    pub fn ascii(self, yes: bool) -> MatrixWriterForNetpbm<'a, T> {
+        MatrixWriterForNetpbm { ascii: yes, ..self }
+    }
+
+    /// Set the maximum sample value, it should be between 1 and 65535. By
+    /// default, it is 255 for 8-bit numeric types and 65535 otherwise,
+    /// including `f32` and `f64`. Writing will returns an error if an
+    /// element is larger than the maximum value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// # let path = std::env::temp_dir().join("crabsformer_doc_12bit.pgm");
+    /// let image: Matrix<u16> = matrix![0, 4095; 2048, 1];
+    /// image.to_pgm(&path).max_value(4095).write().unwrap();
+    /// let loaded: Matrix<u16> = Matrix::from_pgm(&path).unwrap();
+    /// assert_eq!(loaded, image);
+    /// ```
+    pub fn max_value(self, max_value: u16) -> MatrixWriterForNetpbm<'a, T> {
+        MatrixWriterForNetpbm {
+            max_value: Some(max_value),
+            ..self
+        }
+    }
+
+    /// Write the image. The file is created or truncated. It returns an
+    /// error if an element is not an integer within `0..=max_value`, so
+    /// floating point images should be scaled and rounded first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// # let path = std::env::temp_dir().join("crabsformer_doc_float.pgm");
+    /// let image: Matrix<f64> = matrix![0.0, 0.7; 1.0, 0.25];
+    /// assert!(image.to_pgm(&path).write().is_err());
+    /// let scaled =
+    ///     Matrix::from_fn([2, 2], |i, j| (image.at(i, j) * 255.0).round());
+    /// scaled.to_pgm(&path).max_value(255).write().unwrap();
+    /// let loaded: Matrix<u8> = Matrix::from_pgm(&path).unwrap();
+    /// assert_eq!(loaded, matrix![0, 179; 255, 64]);
+    /// ```
+    pub fn write(self) -> Result<(), MatrixLoadError>
+    where
+        T: ToPrimitive,
+    {
+        let [nrows, ncols] = self.channels[0].shape();
+        if self.channels.iter().any(|c| c.shape() != [nrows, ncols]) {
+            return Err(MatrixLoadError::new(
+                MatrixLoadErrorKind::InvalidShape,
+                String::from("channels should have the same shape"),
+            ));
+        }
+        let max_value = match self.max_value {
+            Some(0) => {
+                return Err(MatrixLoadError::new(
+                    MatrixLoadErrorKind::InvalidElement,
+                    String::from("maximum value should not be zero"),
+                ));
+            }
+            Some(max_value) => max_value,
+            None if mem::size_of::<T>() == 1 => 255,
+            None => u16::MAX,
+        };
+
+        // Interleave the channels
+        let mut samples =
+            Vec::with_capacity(nrows * ncols * self.channels.len());
+        for i in 0..nrows * ncols {
+            for channel in &self.channels {
+                let value = channel.vec[i];
+                // Floating point elements are not truncated
+                let sample = match value.to_f64() {
+                    Some(x) if x.fract() == 0.0 => value.to_u16(),
+                    _ => None,
+                };
+                match sample {
+                    Some(sample) if sample <= max_value => {
+                        samples.push(sample)
+                    }
+                    _ => {
+                        return Err(MatrixLoadError::new(
+                            MatrixLoadErrorKind::InvalidElement,
+                            format!(
+                                "element at row {}, column {} should be an \
+                                 integer within 0..={}",
+                                i / ncols.max(1) + 1,
+                                i % ncols.max(1) + 1,
+                                max_value
+                            ),
+                        ));
+                    }
+                }
+            }
+        }
+
+        let magic = match (self.channels.len(), self.ascii) {
+            (1, true) => "P2",
+            (1, false) => "P5",
+            (_, true) => "P3",
+            (_, false) => "P6",
+        };
+        let mut file = BufWriter::new(File::create(&self.file_path)?);
+        write!(file, "{}\n{} {}\n{}\n", magic, ncols, nrows, max_value)?;
+        if self.ascii {
+            // One image row per line, long rows are wrapped because lines
+            // should not be longer than 70 characters.
+            let width = ncols * self.channels.len();
+            for row in samples.chunks(width.max(1)) {
+                let mut line = String::new();
+                for sample in row {
+                    let sample = sample.to_string();
+                    if !line.is_empty() && line.len() + sample.len() >= 70 {
+                        writeln!(file, "{}", line)?;
+                        line.clear();
+                    }
+                    if !line.is_empty() {
+                        line.push(' ');
+                    }
+                    line.push_str(&sample);
+                }
+                writeln!(file, "{}", line)?;
+            }
+        } else {
+            let mut bytes = Vec::with_capacity(samples.len() * 2);
+            for sample in samples {
+                if max_value > 255 {
+                    bytes.extend_from_slice(&sample.to_be_bytes());
+                } else {
+                    bytes.push(sample as u8);
+                }
+            }
+            file.write_all(&bytes)?;
+        }
+        file.flush()?;
+        Ok(())
+    }
+}
+
+/// Matrix writer for WAVE audio file.
+///
+/// See also: [`Matrix::to_wav`].
+///
+/// [`Matrix::to_wav`]: ../struct.Matrix.html#method.to_wav
+pub struct MatrixWriterForWav<'a, T>
+where
+    T: Num + Copy,
+{
+    matrix: &'a Matrix<T>,
+    file_path: PathBuf,
+    sample_rate: u32,
+    bit_depth: Option<u16>,
+    float: Option<bool>,
+}
+
+impl<'a, T> MatrixWriterForWav<'a, T>
+where
+    T: Num + Copy,
+{
+    /// Set the number of bits per sample, it should be 8, 16, 24 or 32 for
+    /// integer samples and 32 or 64 for floating point samples. By default,
+    /// it is the size of the numeric type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// # let path = std::env::temp_dir().join("crabsformer_doc_8bit.wav");
+    /// let w: Matrix<i16> = matrix![0, 256, -256];
+    /// w.to_wav(&path, 8000).bit_depth(8).write().unwrap();
+    /// assert_eq!(std::fs::metadata(&path).unwrap().len(), 48);
+    /// ```
+    pub fn bit_depth(self, bits: u16) -> MatrixWriterForWav<'a, T> {
+        MatrixWriterForWav {
+            bit_depth: Some(bits),
+            ..self
+        }
+    }
+
+    /// Set to true to write floating point samples and false to write
+    /// integer samples. By default, it is true for `f32` and `f64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// # let path = std::env::temp_dir().join("crabsformer_doc_pcm.wav");
+    /// let w: Matrix<f32> = matrix![0.0, 0.5, -0.5];
+    /// w.to_wav(&path, 8000).float(false).bit_depth(16).write().unwrap();
+    /// let (v, _): (Matrix<i16>, u32) = Matrix::from_wav(&path).unwrap();
+    /// assert_eq!(v, matrix![0, 16384, -16384]);
+    /// ```
+    pub fn float(self, yes: bool) -> MatrixWriterForWav<'a, T> {
+        MatrixWriterForWav {
+            float: Some(yes),
+            ..self
+        }
+    }
+
+    /// Write the matrix as WAVE file, one channel per row. The file is
+    /// created or truncated. It returns an error if the bit depth is not
+    /// supported.
+    pub fn write(self) -> Result<(), MatrixLoadError>
+    where
+        T: WavSample,
+    {
+        let data = self.matrix.vec.as_slice();
+        let channels: Vec<&[T]> = match self.matrix.ncols {
+            0 => vec![&[]; self.matrix.nrows],
+            ncols => data.chunks(ncols).collect(),
+        };
+        let bits = self.bit_depth.unwrap_or(T::BITS);
+        let float = self.float.unwrap_or(T::FLOAT);
+        // Validate before creating the file, so it is left untouched
+        wav::check_wav(&channels, bits, float)?;
+        let mut file = BufWriter::new(File::create(&self.file_path)?);
+        wav::write_wav(&mut file, &channels, self.sample_rate, bits, float)?;
+        file.flush()?;
+        Ok(())
+    }
+}
+
+/// Storage format of Matrix Market file.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MatrixMarketFormat {
+    /// Dense format, the elements are stored in column-major order.
+    Array,
+    /// Sparse format, only the non-zero elements are stored together with
+    /// their row and column indices.
+    Coordinate,
+}
+
+/// Symmetry of the matrix stored in Matrix Market file.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MatrixMarketSymmetry {
+    /// All elements are stored.
+    General,
+    /// The element at `(i, j)` is equal to the element at `(j, i)`, only the
+    /// lower triangle and the diagonal are stored.
+    Symmetric,
+    /// The element at `(i, j)` is equal to the negated element at `(j, i)`,
+    /// only the lower triangle is stored and the diagonal is zero.
+    SkewSymmetric,
+}
+
+impl MatrixMarketSymmetry {
+    // First row of the column `j` that is stored
+    fn first_row(self, j: usize) -> usize {
+        match self {
+            MatrixMarketSymmetry::General => 0,
+            MatrixMarketSymmetry::Symmetric => j,
+            MatrixMarketSymmetry::SkewSymmetric => j + 1,
+        }
+    }
+}
+
+// Read Matrix Market file. The comment lines and the blank lines are
+// skipped, the duplicate entries of coordinate format are summed.
+fn read_matrix_market<T, R>(reader: R) -> Result<Matrix<T>, MatrixLoadError>
+where
+    T: Num + Copy + utils::TypeName + utils::ParseElement,
+    R: io::BufRead,
+{
+    let invalid =
+        |line: usize, message: String| {
+            MatrixLoadError::new(MatrixLoadErrorKind::InvalidFormat, message)
+                .at(Some(line as u64), None, None, None)
+        };
+    let mut lines = reader.lines().enumerate().map(|(i, line)| (i + 1, line));
+
+    // Header line
+    let banner = match lines.next() {
+        Some((_, line)) => line?.to_lowercase(),
+        None => {
+            return Err(MatrixLoadError::new(
+                MatrixLoadErrorKind::Empty,
+                String::from("Cannot load empty file"),
+            ));
+        }
+    };
+    let tokens: Vec<&str> = banner.split_whitespace().collect();
+    if tokens.len() != 5
+        || tokens[0] != "%%matrixmarket"
+        || tokens[1] != "matrix"
+    {
+        return Err(invalid(
+            1,
+            String::from(
+                "expected header \"%%MatrixMarket matrix <format> <field> \
+                 <symmetry>\"",
+            ),
+        ));
+    }
+    let format = match tokens[2] {
+        "array" => MatrixMarketFormat::Array,
+        "coordinate" => MatrixMarketFormat::Coordinate,
+        format => {
+            return Err(invalid(
+                1,
+                format!("unsupported format {:?}", format),
+            ));
+        }
+    };
+    let pattern = match tokens[3] {
+        "real" | "double" | "integer" => false,
+        "pattern" if format == MatrixMarketFormat::Coordinate => true,
+        field => {
+            return Err(MatrixLoadError::new(
+                MatrixLoadErrorKind::InvalidDataType,
+                format!("unsupported field {:?}", field),
+            )
+            .at(Some(1), None, None, None));
+        }
+    };
+    let symmetry = match tokens[4] {
+        "general" => MatrixMarketSymmetry::General,
+        "symmetric" | "hermitian" => MatrixMarketSymmetry::Symmetric,
+        "skew-symmetric" if !pattern => MatrixMarketSymmetry::SkewSymmetric,
+        symmetry => {
+            return Err(invalid(
+                1,
+                format!("unsupported symmetry {:?}", symmetry),
+            ));
+        }
+    };
+    let mut lines = lines.filter(|(_, line)| match line {
+        Ok(line) => {
+            let line = line.trim();
+            !line.is_empty() && !line.starts_with('%')
+        }
+        Err(_) => true,
+    });
+
+    // Size line
+    let (mut last, line) = match lines.next() {
+        Some((number, line)) => (number, line?),
+        None => return Err(invalid(1, String::from("missing size line"))),
+    };
+    let sizes = line
+        .split_whitespace()
+        .map(|size| size.parse())
+        .collect::<Result<Vec<usize>, _>>()
+        .ok()
+        .filter(|sizes| match format {
+            MatrixMarketFormat::Array => sizes.len() == 2,
+            MatrixMarketFormat::Coordinate => sizes.len() == 3,
+        })
+        .ok_or_else(|| {
+            invalid(last, format!("invalid size line {:?}", line))
+        })?;
+    let (nrows, ncols) = (sizes[0], sizes[1]);
+    if symmetry != MatrixMarketSymmetry::General && nrows != ncols {
+        return Err(MatrixLoadError::new(
+            MatrixLoadErrorKind::InvalidShape,
+            format!(
+                "symmetric matrix should be square, got {}x{}",
+                nrows, ncols
+            ),
+        )
+        .at(Some(last as u64), None, None, None));
+    }
+    // Do not trust the size line to allocate the elements, the allocation
+    // may fail
+    let size_line = last;
+    let allocate = || -> Result<Vec<T>, MatrixLoadError> {
+        let mut elements = Vec::new();
+        let len = nrows
+            .checked_mul(ncols)
+            .filter(|len| elements.try_reserve_exact(*len).is_ok())
+            .ok_or_else(|| {
+                MatrixLoadError::new(
+                    MatrixLoadErrorKind::InvalidShape,
+                    format!("{}x{} matrix is too large", nrows, ncols),
+                )
+                .at(Some(size_line as u64), None, None, None)
+            })?;
+        elements.resize(len, T::zero());
+        Ok(elements)
+    };
+
+    // Parse the element at the given line and column, the element of
+    // skew-symmetric matrix is negated by flipping its sign
+    let parse = |value: &str, negate: bool, line: usize, column: usize| {
+        let format = utils::NumberFormat::default();
+        let invalid_element = |value: &str| {
+            MatrixLoadError::new(
+                MatrixLoadErrorKind::InvalidElement,
+                format!("{:?} is not valid {}", value, T::type_name()),
+            )
+            .at(Some(line as u64), None, Some(column), None)
+        };
+        let element = T::parse_element(value, &format)
+            .ok_or_else(|| invalid_element(value))?;
+        if !negate || element == T::zero() {
+            return Ok(element);
+        }
+        let negated = match value.trim().trim_start_matches('+') {
+            value if value.starts_with('-') => value[1..].to_string(),
+            value => format!("-{}", value),
+        };
+        T::parse_element(&negated, &format)
+            .ok_or_else(|| invalid_element(&negated))
+    };
+    let skew = symmetry == MatrixMarketSymmetry::SkewSymmetric;
+
+    let elements = match format {
+        MatrixMarketFormat::Array => {
+            let mut elements = allocate()?;
+            let mut positions = (0..ncols).flat_map(|j| {
+                (symmetry.first_row(j)..nrows).map(move |i| (i, j))
+            });
+            for (number, line) in lines {
+                let line = line?;
+                last = number;
+                for (k, value) in line.split_whitespace().enumerate() {
+                    let (i, j) = positions.next().ok_or_else(|| {
+                        invalid(number, String::from("too many elements"))
+                    })?;
+                    elements[i * ncols + j] =
+                        parse(value, false, number, k + 1)?;
+                    if i != j && symmetry != MatrixMarketSymmetry::General {
+                        elements[j * ncols + i] =
+                            parse(value, skew, number, k + 1)?;
+                    }
+                }
+            }
+            if positions.next().is_some() {
+                return Err(invalid(last, String::from("too few elements")));
+            }
+            elements
+        }
+        MatrixMarketFormat::Coordinate => {
+            let nnz = sizes[2];
+            let nfields = if pattern { 2 } else { 3 };
+            let mut count = 0;
+            let mut entries = Vec::new();
+            for (number, line) in lines {
+                let line = line?;
+                last = number;
+                let fields: Vec<&str> = line.split_whitespace().collect();
+                if fields.len() != nfields {
+                    return Err(invalid(
+                        number,
+                        format!(
+                            "expected {} fields, got {}",
+                            nfields,
+                            fields.len()
+                        ),
+                    ));
+                }
+                count += 1;
+                if count > nnz {
+                    return Err(invalid(
+                        number,
+                        format!("expected {} entries, got more", nnz),
+                    ));
+                }
+                let index = |k: usize, len: usize| match fields[k]
+                    .parse::<usize>()
+                {
+                    Ok(index) if index >= 1 && index <= len => Ok(index - 1),
+                    _ => Err(MatrixLoadError::new(
+                        MatrixLoadErrorKind::InvalidFormat,
+                        format!("index {:?} is out of bounds", fields[k]),
+                    )
+                    .at(
+                        Some(number as u64),
+                        None,
+                        Some(k + 1),
+                        None,
+                    )),
+                };
+                let (i, j) = (index(0, nrows)?, index(1, ncols)?);
+                if i == j && skew {
+                    return Err(invalid(
+                        number,
+                        String::from(
+                            "diagonal of skew-symmetric matrix should not \
+                             be stored",
+                        ),
+                    ));
+                }
+                let value = if pattern {
+                    T::one()
+                } else {
+                    parse(fields[2], false, number, 3)?
+                };
+                entries.push((i, j, value));
+                if i != j && symmetry != MatrixMarketSymmetry::General {
+                    let value = if pattern {
+                        T::one()
+                    } else {
+                        parse(fields[2], skew, number, 3)?
+                    };
+                    entries.push((j, i, value));
+                }
+            }
+            if count != nnz {
+                return Err(invalid(
+                    last,
+                    format!("expected {} entries, got {}", nnz, count),
+                ));
+            }
+            let mut elements = allocate()?;
+            for (i, j, value) in entries {
+                elements[i * ncols + j] = elements[i * ncols + j] + value;
+            }
+            elements
+        }
+    };
+    Ok(Matrix {
+        nrows,
+        ncols,
+        vec: Vector::from(elements),
+    })
+}
+
+/// Matrix writer for Matrix Market formatted file.
+///
+/// See also: [`Matrix::to_mtx`].
+///
+/// [`Matrix::to_mtx`]: ../struct.Matrix.html#method.to_mtx
+pub struct MatrixWriterForMtx<'a, T>
+where
+    T: Num + Copy,
+{
+    matrix: &'a Matrix<T>,
+    file_path: PathBuf,
+    format: MatrixMarketFormat,
+    symmetry: MatrixMarketSymmetry,
+    precision: Option<usize>,
+}
+
+impl<'a, T> MatrixWriterForMtx<'a, T>
+where
+    T: Num + Copy,
+{
+    /// Set the storage format. By default, it is set to
+    /// [`MatrixMarketFormat::Array`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// # let path = std::env::temp_dir().join("crabsformer_doc_coordinate.mtx");
+    /// let w = matrix![1.5, 0.0; 0.0, 2.0];
+    /// w.to_mtx(&path)
+    ///     .format(MatrixMarketFormat::Coordinate)
+    ///     .write()
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     std::fs::read_to_string(&path).unwrap(),
+    ///     "%%MatrixMarket matrix coordinate real general\n2 2 2\n1 1 1.5\n2 2 2\n"
+    /// );
+    /// ```
+    ///
+    /// [`MatrixMarketFormat::Array`]: enum.MatrixMarketFormat.html#variant.Array
+    pub fn format(
+        self,
+        format: MatrixMarketFormat,
+    ) -> MatrixWriterForMtx<'a, T> {
+        MatrixWriterForMtx { format, ..self }
+    }
+
+    /// Set the symmetry, only the lower triangle of symmetric matrix is
+    /// written. By default, it is set to [`MatrixMarketSymmetry::General`].
+    /// Writing will returns an error if the matrix does not have the given
+    /// symmetry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// # let path = std::env::temp_dir().join("crabsformer_doc_symmetric.mtx");
+    /// let w = matrix![1, 2; 2, 3];
+    /// w.to_mtx(&path)
+    ///     .symmetry(MatrixMarketSymmetry::Symmetric)
+    ///     .write()
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     std::fs::read_to_string(&path).unwrap(),
+    ///     "%%MatrixMarket matrix array integer symmetric\n2 2\n1\n2\n3\n"
+    /// );
+    /// ```
+    ///
+    /// [`MatrixMarketSymmetry::General`]: enum.MatrixMarketSymmetry.html#variant.General
+    pub fn symmetry(
+        self,
+        symmetry: MatrixMarketSymmetry,
+    ) -> MatrixWriterForMtx<'a, T> {
+        MatrixWriterForMtx { symmetry, ..self }
+    }
+
+    /// Set the number of digits after the decimal point. By default, the
+    /// elements are written using the shortest representation that can be
+    /// loaded back without loss.
+    pub fn precision(self, precision: usize) -> MatrixWriterForMtx<'a, T> {
+        MatrixWriterForMtx {
+            precision: Some(precision),
+            ..self
+        }
+    }
+
+    /// Write Matrix as Matrix Market file. The file is created or
+    /// truncated.
+    pub fn write(self) -> Result<(), MatrixLoadError>
+    where
+        T: fmt::Display + fmt::LowerExp + utils::TypeName,
+    {
+        let (nrows, ncols) = (self.matrix.nrows, self.matrix.ncols);
+        let data = self.matrix.vec.as_slice();
+        let at = |i: usize, j: usize| data[i * ncols + j];
+        let symmetry = self.symmetry;
+        let symmetry_name = match symmetry {
+            MatrixMarketSymmetry::General => "general",
+            MatrixMarketSymmetry::Symmetric => "symmetric",
+            MatrixMarketSymmetry::SkewSymmetric => "skew-symmetric",
+        };
+        if symmetry == MatrixMarketSymmetry::SkewSymmetric
+            && T::type_name().starts_with('u')
+        {
+            return Err(MatrixLoadError::new(
+                MatrixLoadErrorKind::InvalidDataType,
+                format!("{} matrix cannot be skew-symmetric", T::type_name()),
+            ));
+        }
+        // Compare the text of the elements, negating the element may
+        // overflow
+        let negated = |a: T, b: T| {
+            if a == T::zero() || b == T::zero() {
+                return a == T::zero() && b == T::zero();
+            }
+            let (a, b) = (a.to_string(), b.to_string());
+            match a.strip_prefix('-') {
+                Some(a) => a == b,
+                None => b.strip_prefix('-') == Some(&a[..]),
+            }
+        };
+        if symmetry != MatrixMarketSymmetry::General {
+            if nrows != ncols {
+                return Err(MatrixLoadError::new(
+                    MatrixLoadErrorKind::InvalidShape,
+                    format!(
+                        "symmetric matrix should be square, got {}x{}",
+                        nrows, ncols
+                    ),
+                ));
+            }
+            for i in 0..nrows {
+                for j in 0..=i {
+                    let symmetric = match symmetry {
+                        MatrixMarketSymmetry::SkewSymmetric => {
+                            negated(at(i, j), at(j, i))
+                        }
+                        _ => at(i, j) == at(j, i),
+                    };
+                    if !symmetric {
+                        return Err(MatrixLoadError::new(
+                            MatrixLoadErrorKind::InvalidElement,
+                            format!(
+                                "matrix is not {} at row {}, column {}",
+                                symmetry_name,
+                                i + 1,
+                                j + 1
+                            ),
+                        ));
+                    }
+                }
+            }
+        }
+
+        let format = match self.format {
+            MatrixMarketFormat::Array => "array",
+            MatrixMarketFormat::Coordinate => "coordinate",
+        };
+        let field = if T::type_name().starts_with('f') {
+            "real"
+        } else {
+            "integer"
+        };
+        let precision = self.precision;
+        let positions = (0..ncols).flat_map(|j| {
+            (symmetry.first_row(j)..nrows).map(move |i| (i, j))
+        });
+
+        let mut file = BufWriter::new(File::create(&self.file_path)?);
+        writeln!(
+            file,
+            "%%MatrixMarket matrix {} {} {}",
+            format, field, symmetry_name
+        )?;
+        match self.format {
+            MatrixMarketFormat::Array => {
+                writeln!(file, "{} {}", nrows, ncols)?;
+                for (i, j) in positions {
+                    let value =
+                        utils::format_element(at(i, j), precision, false);
+                    writeln!(file, "{}", value)?;
+                }
+            }
+            MatrixMarketFormat::Coordinate => {
+                let entries: Vec<(usize, usize)> = positions
+                    .filter(|&(i, j)| at(i, j) != T::zero())
+                    .collect();
+                writeln!(file, "{} {} {}", nrows, ncols, entries.len())?;
+                for (i, j) in entries {
+                    let value =
+                        utils::format_element(at(i, j), precision, false);
+                    writeln!(file, "{} {} {}", i + 1, j + 1, value)?;
+                }
+            }
+        }
+        file.flush()?;
+        Ok(())
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Num + Copy,
+{
+    /// Load Matrix from CSV file. You need to explicitly annotate the numeric type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// let dataset: Matrix<f32> = Matrix::from_csv("tests/data/weight.csv").load().unwrap();
+    /// ```
+    ///
+    pub fn from_csv<P>(file_path: P) -> MatrixLoaderForCSV<'static, T>
+    where
+        P: AsRef<Path>,
+    {
+        let path = file_path.as_ref().to_path_buf();
+        Matrix::csv_loader(utils::CSVSource::Path(path))
+    }
+
+    /// Load Matrix from CSV formatted data of any [`io::Read`], such as the
+    /// standard input. You need to explicitly annotate the numeric type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// let data = "a,b\n1,2\n3,4\n";
+    /// let w: Matrix<i32> = Matrix::from_csv_reader(data.as_bytes())
+    ///     .has_headers(true)
+    ///     .load()
+    ///     .unwrap();
+    /// assert_eq!(w, matrix![1, 2; 3, 4]);
+    /// ```
+    ///
+    /// [`io::Read`]: https://doc.rust-lang.org/std/io/trait.Read.html
+    pub fn from_csv_reader<'a, R>(reader: R) -> MatrixLoaderForCSV<'a, T>
+    where
+        R: io::Read + 'a,
+    {
+        Matrix::csv_loader(utils::CSVSource::Reader(Box::new(reader)))
+    }
+
+    /// Load Matrix from CSV formatted string. You need to explicitly annotate
+    /// the numeric type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// let w: Matrix<f64> = Matrix::from_csv_str("1.5;2\n3;4.5")
+    ///     .delimiter(b';')
+    ///     .load()
+    ///     .unwrap();
+    /// assert_eq!(w, matrix![1.5, 2.0; 3.0, 4.5]);
+    /// ```
+    pub fn from_csv_str(data: &str) -> MatrixLoaderForCSV<'_, T> {
+        Matrix::from_csv_reader(data.as_bytes())
+    }
+
+    fn csv_loader<'a>(
+        source: utils::CSVSource<'a>,
+    ) -> MatrixLoaderForCSV<'a, T> {
+        MatrixLoaderForCSV {
+            source,
+            has_headers: false,
+            delimiter: b',',
+            comment: None,
+            skip_rows: 0,
+            max_rows: None,
+            usecols: Columns::All,
+            flexible: false,
+            padding: T::zero(),
+            quoting: true,
+            quote: b'"',
+            double_quote: true,
+            escape: None,
+            missing_values: vec![String::new()],
+            missing: Missing::Error,
+            lenient: false,
+            format: utils::NumberFormat::default(),
+            parser: None,
+        }
+    }
+
+    /// Write Matrix to CSV file. The file is created or truncated when
+    /// [`write`] is called.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// let w = matrix![1.0, 2.0; 3.0, 4.0];
+    /// # let dir = std::env::temp_dir();
+    /// # let path = dir.join("crabsformer_doc_matrix_to_csv.csv");
+    /// w.to_csv(&path).has_headers(true).write().unwrap();
+    /// let v: Matrix<f64> = Matrix::from_csv(&path)
+    ///     .has_headers(true)
+    ///     .load()
+    ///     .unwrap();
+    /// assert_eq!(v, w);
+    /// ```
+    ///
+    /// [`write`]: loaders/struct.MatrixWriterForCSV.html#method.write
+    pub fn to_csv<P>(&self, file_path: P) -> MatrixWriterForCSV<'_, T>
+    where
+        P: AsRef<Path>,
+    {
+        let path = file_path.as_ref().to_path_buf();
+        self.csv_writer(utils::CSVDestination::Path(path))
+    }
+
+    /// Write Matrix as CSV to any [`io::Write`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// let w = matrix![1, 2; 3, 4];
+    /// let mut buf = Vec::new();
+    /// w.to_csv_writer(&mut buf).write().unwrap();
+    /// assert_eq!(String::from_utf8(buf).unwrap(), "1,2\n3,4\n");
+    /// ```
+    ///
+    /// [`io::Write`]: https://doc.rust-lang.org/std/io/trait.Write.html
+    pub fn to_csv_writer<'a, W>(
+        &'a self,
+        writer: W,
+    ) -> MatrixWriterForCSV<'a, T>
+    where
+        W: io::Write + 'a,
+    {
+        self.csv_writer(utils::CSVDestination::Writer(Box::new(writer)))
+    }
+
+    fn csv_writer<'a>(
+        &'a self,
+        destination: utils::CSVDestination<'a>,
+    ) -> MatrixWriterForCSV<'a, T> {
+        MatrixWriterForCSV {
+            matrix: self,
+            destination,
+            has_headers: false,
+            headers: None,
+            delimiter: b',',
+            precision: None,
+            scientific: false,
+        }
+    }
+
+    /// Load Matrix from NPY file written by `numpy.save`. The array should
+    /// be two-dimensional and its element type should match the numeric
+    /// type. Arrays in Fortran order are converted to row-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// let w: Matrix<i32> = Matrix::from_npy("tests/data/matrix.npy").unwrap();
+    /// assert_eq!(w, matrix![1, 2, 3; 4, 5, 6]);
+    /// ```
+    pub fn from_npy<P>(file_path: P) -> Result<Matrix<T>, MatrixLoadError>
+    where
+        P: AsRef<Path>,
+        T: utils::BinaryElement,
+    {
+        let file = File::open(file_path)?;
+        Matrix::read_npy(&mut BufReader::new(file))
+    }
+
+    /// Write Matrix to NPY file, the file can be loaded using `numpy.load`.
+    /// The file is created or truncated when [`write`] is called.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// # let path = std::env::temp_dir().join("crabsformer_doc_matrix.npy");
+    /// let w = matrix![1, 2; 3, 4];
+    /// w.to_npy(&path).write().unwrap();
+    /// ```
+    ///
+    /// [`write`]: loaders/struct.MatrixWriterForNpy.html#method.write
+    pub fn to_npy<P>(&self, file_path: P) -> MatrixWriterForNpy<'_, T>
+    where
+        P: AsRef<Path>,
+    {
+        MatrixWriterForNpy {
+            matrix: self,
+            file_path: file_path.as_ref().to_path_buf(),
+            big_endian: false,
+            fortran_order: false,
+        }
+    }
+
+    /// Save Matrix in the [native binary format]. The file is created or
+    /// truncated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// # let path = std::env::temp_dir().join("crabsformer_doc_matrix_save.bin");
+    /// let w = matrix![1, 2; 3, 4];
+    /// w.save(&path).unwrap();
+    /// ```
+    ///
+    /// [native binary format]: ../binary/index.html
+    pub fn save<P>(&self, file_path: P) -> Result<(), MatrixLoadError>
+    where
+        P: AsRef<Path>,
+        T: utils::BinaryElement,
+    {
+        let mut file = BufWriter::new(File::create(file_path)?);
+        let shape = [self.nrows, self.ncols];
+        binary::write_array(&mut file, &shape, self.vec.as_slice())?;
+        file.flush()?;
+        Ok(())
+    }
+
+    /// Load Matrix saved in the [native binary format]. It returns an error
+    /// if the element type does not match the numeric type, if the file
+    /// contains a numeric vector or if the file is corrupted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// # let path = std::env::temp_dir().join("crabsformer_doc_matrix_load.bin");
+    /// matrix![1, 2; 3, 4].save(&path).unwrap();
+    /// let w: Matrix<i32> = Matrix::load(&path).unwrap();
+    /// assert_eq!(w, matrix![1, 2; 3, 4]);
+    ///
+    /// let result: Result<Matrix<i64>, MatrixLoadError> = Matrix::load(&path);
+    /// assert!(result.is_err());
+    /// ```
+    ///
+    /// [native binary format]: ../binary/index.html
+    pub fn load<P>(file_path: P) -> Result<Matrix<T>, MatrixLoadError>
+    where
+        P: AsRef<Path>,
+        T: utils::BinaryElement,
+    {
+        let file = BufReader::new(File::open(file_path)?);
+        let (shape, data) = binary::read_array(file)?;
+        if shape.len() != 2 {
+            return Err(MatrixLoadError::new(
+                MatrixLoadErrorKind::InvalidShape,
+                format!(
+                    "expected two-dimensional array, got shape {:?}",
+                    shape
+                ),
+            ));
+        }
+        Ok(Matrix {
+            nrows: shape[0],
+            ncols: shape[1],
+            vec: Vector::from(data),
+        })
+    }
+
+    pub(crate) fn read_npy<R>(
+        reader: &mut R,
+    ) -> Result<Matrix<T>, MatrixLoadError>
+    where
+        R: io::Read,
+        T: utils::BinaryElement,
+    {
+        let (shape, data) = npy::read_array(reader)?;
+        if shape.len() != 2 {
+            return Err(MatrixLoadError::new(
+                MatrixLoadErrorKind::InvalidShape,
+                format!(
+                    "expected two-dimensional array, got shape {:?}",
+                    shape
+                ),
+            ));
+        }
+        Ok(Matrix {
+            nrows: shape[0],
+            ncols: shape[1],
+            vec: Vector::from(data),
+        })
+    }
+
+    pub(crate) fn write_npy<W>(
+        &self,
+        writer: &mut W,
+        big_endian: bool,
+        fortran_order: bool,
+    ) -> io::Result<()>
+    where
+        W: io::Write,
+        T: utils::BinaryElement,
+    {
+        let shape = [self.nrows, self.ncols];
+        let data = self.vec.as_slice();
+        npy::write_array(writer, &shape, data, big_endian, fortran_order)
+    }
+
+    /// Load Matrix from Matrix Market file. Both the `array` and the
+    /// `coordinate` formats are supported, with `real`, `integer` or
+    /// `pattern` field and `general`, `symmetric` or `skew-symmetric`
+    /// symmetry. The elements of `pattern` file are set to one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// let w: Matrix<f64> =
+    ///     Matrix::from_mtx("tests/data/matrix_symmetric.mtx").unwrap();
+    /// assert_eq!(w, matrix![1.0, 0.0, 2.5; 0.0, 3.0, 0.0; 2.5, 0.0, 4.0]);
+    /// ```
+    pub fn from_mtx<P>(file_path: P) -> Result<Matrix<T>, MatrixLoadError>
+    where
+        P: AsRef<Path>,
+        T: utils::TypeName + utils::ParseElement,
+    {
+        let file = File::open(file_path)?;
+        read_matrix_market(BufReader::new(file))
+    }
+
+    /// Write Matrix to Matrix Market file. The file is created or truncated
+    /// when [`write`] is called.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// # let path = std::env::temp_dir().join("crabsformer_doc_matrix.mtx");
+    /// let w = matrix![1, 2; 3, 4];
+    /// w.to_mtx(&path).write().unwrap();
+    /// assert_eq!(
+    ///     std::fs::read_to_string(&path).unwrap(),
+    ///     "%%MatrixMarket matrix array integer general\n2 2\n1\n3\n2\n4\n"
+    /// );
+    /// ```
+    ///
+    /// [`write`]: loaders/struct.MatrixWriterForMtx.html#method.write
+    pub fn to_mtx<P>(&self, file_path: P) -> MatrixWriterForMtx<'_, T>
+    where
+        P: AsRef<Path>,
+    {
+        MatrixWriterForMtx {
+            matrix: self,
+            file_path: file_path.as_ref().to_path_buf(),
+            format: MatrixMarketFormat::Array,
+            symmetry: MatrixMarketSymmetry::General,
+            precision: None,
+        }
+    }
+
+    /// Load grayscale image from PGM file, both the binary (P5) and the
+    /// ASCII (P2) formats are supported. The image rows are the matrix
+    /// rows. It will returns an error if the maximum sample value of the
+    /// image does not fit in the numeric type, for example 16-bit image as
+    /// `Matrix<u8>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// let image: Matrix<u8> = Matrix::from_pgm("tests/data/image.pgm").unwrap();
+    /// assert_eq!(image, matrix![0, 64, 128; 192, 255, 32]);
+    /// ```
+    pub fn from_pgm<P>(file_path: P) -> Result<Matrix<T>, MatrixLoadError>
+    where
+        P: AsRef<Path>,
+        T: FromPrimitive + utils::TypeName,
+    {
+        let mut channels = read_netpbm(file_path, 1)?;
+        Ok(channels.remove(0))
+    }
+
+    /// Load color image from PPM file as red, green and blue matrices, both
+    /// the binary (P6) and the ASCII (P3) formats are supported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// let [red, green, blue]: [Matrix<u8>; 3] =
+    ///     Matrix::from_ppm("tests/data/image.ppm").unwrap();
+    /// assert_eq!(red, matrix![255, 0; 0, 255]);
+    /// assert_eq!(green, matrix![0, 255; 0, 255]);
+    /// assert_eq!(blue, matrix![0, 0; 255, 255]);
+    /// ```
+    pub fn from_ppm<P>(
+        file_path: P,
+    ) -> Result<[Matrix<T>; 3], MatrixLoadError>
+    where
+        P: AsRef<Path>,
+        T: FromPrimitive + utils::TypeName,
+    {
+        let mut channels = read_netpbm(file_path, 3)?;
+        let blue = channels.remove(2);
+        let green = channels.remove(1);
+        let red = channels.remove(0);
+        Ok([red, green, blue])
+    }
+
+    /// Write Matrix as grayscale PGM image. The file is created or
+    /// truncated when [`write`] is called.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// # let path = std::env::temp_dir().join("crabsformer_doc_image.pgm");
+    /// let image: Matrix<u8> = matrix![0, 128; 255, 64];
+    /// image.to_pgm(&path).write().unwrap();
+    /// ```
+    ///
+    /// [`write`]: loaders/struct.MatrixWriterForNetpbm.html#method.write
+    pub fn to_pgm<P>(&self, file_path: P) -> MatrixWriterForNetpbm<'_, T>
+    where
+        P: AsRef<Path>,
+    {
+        MatrixWriterForNetpbm {
+            channels: vec![self],
+            file_path: file_path.as_ref().to_path_buf(),
+            ascii: false,
+            max_value: None,
+        }
+    }
+
+    /// Write red, green and blue matrices as color PPM image. The matrices
+    /// should have the same shape. The file is created or truncated when
+    /// [`write`] is called.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// # let path = std::env::temp_dir().join("crabsformer_doc_image.ppm");
+    /// let red: Matrix<u8> = matrix![255, 0];
+    /// let green: Matrix<u8> = matrix![0, 255];
+    /// let blue: Matrix<u8> = matrix![0, 0];
+    /// Matrix::to_ppm([&red, &green, &blue], &path).write().unwrap();
+    /// ```
+    ///
+    /// [`write`]: loaders/struct.MatrixWriterForNetpbm.html#method.write
+    pub fn to_ppm<'a, P>(
+        channels: [&'a Matrix<T>; 3],
+        file_path: P,
+    ) -> MatrixWriterForNetpbm<'a, T>
+    where
+        P: AsRef<Path>,
+    {
+        MatrixWriterForNetpbm {
+            channels: channels.to_vec(),
+            file_path: file_path.as_ref().to_path_buf(),
+            ascii: false,
+            max_value: None,
+        }
+    }
+
+    /// Load PCM audio from WAVE file as a matrix of channels × samples,
+    /// returns the matrix and the sample rate. The samples are converted to
+    /// the numeric type, see [`WavSample`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// let (w, sample_rate): (Matrix<i16>, u32) =
+    ///     Matrix::from_wav("tests/data/audio.wav").unwrap();
+    /// assert_eq!(sample_rate, 8000);
+    /// assert_eq!(w, matrix![0, 1000, -1000, 32767; 0, -1000, 1000, -32768]);
+    /// ```
+    ///
+    /// [`WavSample`]: ../wav/trait.WavSample.html
+    pub fn from_wav<P>(
+        file_path: P,
+    ) -> Result<(Matrix<T>, u32), MatrixLoadError>
+    where
+        P: AsRef<Path>,
+        T: WavSample,
+    {
+        let file = BufReader::new(File::open(file_path)?);
+        let (sample_rate, channels) = wav::read_wav(file)?;
+        let nrows = channels.len();
+        let ncols = channels[0].len();
+        let data: Vec<T> = channels.into_iter().flatten().collect();
+        let matrix = Matrix {
+            nrows,
+            ncols,
+            vec: Vector::from(data),
+        };
+        Ok((matrix, sample_rate))
+    }
+
+    /// Write Matrix as WAVE file with the given sample rate, each row is a
+    /// channel. The file is created or truncated when [`write`] is called.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// # let path = std::env::temp_dir().join("crabsformer_doc_matrix.wav");
+    /// let w: Matrix<i16> = matrix![0, 1000, -1000; 0, -1000, 1000];
+    /// w.to_wav(&path, 44100).write().unwrap();
+    /// ```
+    ///
+    /// [`write`]: loaders/struct.MatrixWriterForWav.html#method.write
+    pub fn to_wav<P>(
+        &self,
+        file_path: P,
+        sample_rate: u32,
+    ) -> MatrixWriterForWav<'_, T>
+    where
+        P: AsRef<Path>,
+    {
+        MatrixWriterForWav {
+            matrix: self,
+            file_path: file_path.as_ref().to_path_buf(),
+            sample_rate,
+            bit_depth: None,
+            float: None,
+        }
+    }
+}
+
+impl Matrix<f64> {
+    /// Load the feature matrix and the label vector from LIBSVM (SVMlight)
+    /// formatted file. Each line contains a label followed by the non-zero
+    /// features as `index:value` pairs, for example `1 3:0.5 7:1.25`.
+    /// The comments that start with `#` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// let (x, y) = Matrix::from_libsvm("tests/data/dataset.svm")
+    ///     .load()
+    ///     .unwrap();
+    /// assert_eq!(x.shape(), [3, 3]);
+    /// assert_eq!(y.len(), 3);
+    /// ```
+    pub fn from_libsvm<P>(file_path: P) -> MatrixLoaderForLibsvm
+    where
+        P: AsRef<Path>,
+    {
+        MatrixLoaderForLibsvm {
+            file_path: file_path.as_ref().to_path_buf(),
+            zero_based: false,
+            n_features: None,
         }
     }
 }
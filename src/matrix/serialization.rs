@@ -0,0 +1,82 @@
+// Copyright (c) 2019, Bayu Aldi Yansyah <bayualdiyansyah@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Serialization of matrix, enabled by the `serde` feature.
+//!
+//! Matrix is serialized as its shape and its elements in row-major order,
+//! for example `{"shape":[2,2],"data":[1,2,3,4]}` in JSON. The length of the
+//! data is validated against the shape when the matrix is deserialized.
+
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+use num::Num;
+use serde::de;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(Serialize)]
+#[serde(rename = "Matrix")]
+struct MatrixRef<'a, T> {
+    shape: [usize; 2],
+    data: &'a [T],
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "Matrix")]
+struct MatrixData<T> {
+    shape: [usize; 2],
+    data: Vec<T>,
+}
+
+impl<T> Serialize for Matrix<T>
+where
+    T: Num + Copy + Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        MatrixRef {
+            shape: self.shape(),
+            data: self.vec.as_slice(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Matrix<T>
+where
+    T: Num + Copy + Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Matrix<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let MatrixData { shape, data } =
+            MatrixData::deserialize(deserializer)?;
+        let [nrows, ncols] = shape;
+        if nrows.checked_mul(ncols) != Some(data.len()) {
+            return Err(de::Error::custom(format!(
+                "expected {} elements for shape {:?}, got {}",
+                nrows.saturating_mul(ncols),
+                shape,
+                data.len()
+            )));
+        }
+        Ok(Matrix {
+            nrows,
+            ncols,
+            vec: Vector::from(data),
+        })
+    }
+}
@@ -0,0 +1,950 @@
+// Copyright (c) 2019, Bayu Aldi Yansyah <bayualdiyansyah@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! NumPy `.npy` and `.npz` file formats.
+//!
+//! A numeric vector or a matrix can be exchanged with NumPy without loss
+//! using [`Vector::from_npy`], [`Matrix::from_npy`], [`Vector::to_npy`] and
+//! [`Matrix::to_npy`]. Files of format version 1.0, 2.0 and 3.0, in little
+//! or big endian and in C or Fortran order can be loaded. The element type
+//! of the file should match the numeric type, for example `'<f8'` is loaded
+//! as `f64` and `'<i4'` as `i32`.
+//!
+//! Archives of named arrays, as written by `numpy.savez`, can be loaded
+//! using [`NpzReader`] and written using [`NpzWriter`]. Only uncompressed
+//! archives are supported.
+//!
+//! **Note that**: NumPy does not support 128-bit integers, so `i128` and
+//! `u128` elements can only be loaded back by this crate.
+//!
+//! # Examples
+//! ```
+//! # use crabsformer::prelude::*;
+//! # let path = std::env::temp_dir().join("crabsformer_doc_npy.npy");
+//! let w = matrix![1.0, 2.0; 3.0, 4.0];
+//! w.to_npy(&path).write().unwrap();
+//! let v: Matrix<f64> = Matrix::from_npy(&path).unwrap();
+//! assert_eq!(v, w);
+//! ```
+//!
+//! [`Vector::from_npy`]: ../vector/struct.Vector.html#method.from_npy
+//! [`Matrix::from_npy`]: ../matrix/struct.Matrix.html#method.from_npy
+//! [`Vector::to_npy`]: ../vector/struct.Vector.html#method.to_npy
+//! [`Matrix::to_npy`]: ../matrix/struct.Matrix.html#method.to_npy
+//! [`NpzReader`]: struct.NpzReader.html
+//! [`NpzWriter`]: struct.NpzWriter.html
+
+use crate::matrix::errors::MatrixLoadError;
+use crate::matrix::Matrix;
+use crate::utils::{self, BinaryElement};
+use crate::vector::errors::{VectorLoadError, VectorLoadErrorKind};
+use crate::vector::Vector;
+use num::Num;
+use std::borrow::Cow;
+use std::fs::File;
+use std::io::{self, BufWriter, Read, Seek, SeekFrom, Write};
+use std::mem;
+use std::path::Path;
+
+const MAGIC: &[u8] = b"\x93NUMPY";
+
+// Number of elements converted to bytes at once
+const BLOCK_SIZE: usize = 8192;
+
+// ZIP record signatures
+const LOCAL_HEADER: u64 = 0x0403_4b50;
+const CENTRAL_HEADER: u64 = 0x0201_4b50;
+const END_OF_CENTRAL_DIRECTORY: u64 = 0x0605_4b50;
+const ZIP64_END_OF_CENTRAL_DIRECTORY: u64 = 0x0606_4b50;
+const ZIP64_LOCATOR: u64 = 0x0706_4b50;
+
+// Header of NPY file
+struct Header {
+    kind: char,
+    size: usize,
+    big_endian: bool,
+    fortran_order: bool,
+    shape: Vec<usize>,
+}
+
+// Value of the header dictionary
+enum Value {
+    Str(String),
+    Bool(bool),
+    Tuple(Vec<usize>),
+}
+
+fn invalid_format(message: String) -> VectorLoadError {
+    VectorLoadError::new(VectorLoadErrorKind::InvalidFormat, message)
+}
+
+// Same as `read_exact` but reports the end of file as invalid format
+fn read_exact<R>(
+    reader: &mut R,
+    buf: &mut [u8],
+) -> Result<(), VectorLoadError>
+where
+    R: io::Read,
+{
+    reader.read_exact(buf).map_err(|error| match error.kind() {
+        io::ErrorKind::UnexpectedEof => {
+            invalid_format(String::from("unexpected end of file"))
+        }
+        _ => VectorLoadError::from(error),
+    })
+}
+
+// Read NPY array from `reader`, returns its shape and its elements in C
+// order.
+pub(crate) fn read_array<T, R>(
+    reader: &mut R,
+) -> Result<(Vec<usize>, Vec<T>), VectorLoadError>
+where
+    T: BinaryElement,
+    R: io::Read,
+{
+    let header = read_header(reader)?;
+    let size = mem::size_of::<T>();
+    if header.kind != T::KIND || header.size != size {
+        return Err(VectorLoadError::new(
+            VectorLoadErrorKind::InvalidDataType,
+            format!(
+                "cannot load '{}{}' elements as {}",
+                header.kind,
+                header.size,
+                T::type_name()
+            ),
+        ));
+    }
+    let nbytes = header
+        .shape
+        .iter()
+        .try_fold(size, |n, len| n.checked_mul(*len))
+        .ok_or_else(|| {
+            VectorLoadError::new(
+                VectorLoadErrorKind::InvalidShape,
+                format!("shape {:?} is too large", header.shape),
+            )
+        })?;
+
+    // Do not trust the shape to allocate the buffer
+    let mut bytes = Vec::new();
+    reader.take(nbytes as u64).read_to_end(&mut bytes)?;
+    if bytes.len() != nbytes {
+        return Err(invalid_format(format!(
+            "expected {} bytes of data, got {}",
+            nbytes,
+            bytes.len()
+        )));
+    }
+    let data: Vec<T> = bytes
+        .chunks(size)
+        .map(|chunk| T::from_bytes(chunk, header.big_endian))
+        .collect();
+    if header.fortran_order {
+        let shape: Vec<usize> = header.shape.iter().rev().cloned().collect();
+        Ok((header.shape, reverse_axes(&data, &shape)))
+    } else {
+        Ok((header.shape, data))
+    }
+}
+
+fn read_header<R>(reader: &mut R) -> Result<Header, VectorLoadError>
+where
+    R: io::Read,
+{
+    let mut prefix = [0; 8];
+    read_exact(reader, &mut prefix)?;
+    if &prefix[..6] != MAGIC {
+        return Err(invalid_format(String::from("invalid magic string")));
+    }
+    let len = match prefix[6] {
+        1 => {
+            let mut len = [0; 2];
+            read_exact(reader, &mut len)?;
+            u16::from_le_bytes(len) as usize
+        }
+        2 | 3 => {
+            let mut len = [0; 4];
+            read_exact(reader, &mut len)?;
+            u32::from_le_bytes(len) as usize
+        }
+        major => {
+            return Err(invalid_format(format!(
+                "unsupported format version {}.{}",
+                major, prefix[7]
+            )));
+        }
+    };
+    let mut header = Vec::new();
+    reader.take(len as u64).read_to_end(&mut header)?;
+    if header.len() != len {
+        return Err(invalid_format(String::from("unexpected end of file")));
+    }
+    match String::from_utf8(header) {
+        Ok(header) => parse_header(&header),
+        Err(_) => Err(invalid_format(String::from("header is not UTF-8"))),
+    }
+}
+
+fn parse_header(text: &str) -> Result<Header, VectorLoadError> {
+    let mut parser = HeaderParser { text, pos: 0 };
+    let entries = parser.parse_dict()?;
+    parser.skip_whitespace();
+    if parser.pos != text.len() {
+        return Err(parser.error());
+    }
+    let get = |key: &str| {
+        entries
+            .iter()
+            .find(|(name, _)| name == key)
+            .map(|(_, value)| value)
+            .ok_or_else(|| invalid_format(format!("missing key {:?}", key)))
+    };
+
+    let descr = match get("descr")? {
+        Value::Str(descr) => descr,
+        _ => return Err(invalid_format(String::from("invalid descr"))),
+    };
+    let fortran_order = match get("fortran_order")? {
+        Value::Bool(yes) => *yes,
+        _ => {
+            return Err(invalid_format(String::from("invalid fortran_order")))
+        }
+    };
+    let shape = match get("shape")? {
+        Value::Tuple(shape) => shape.clone(),
+        _ => return Err(invalid_format(String::from("invalid shape"))),
+    };
+
+    // The data type is the byte order followed by the kind and the size
+    let unsupported = || {
+        VectorLoadError::new(
+            VectorLoadErrorKind::InvalidDataType,
+            format!("unsupported data type {:?}", descr),
+        )
+    };
+    let mut chars = descr.chars();
+    let big_endian = match chars.next() {
+        Some('>') => true,
+        Some('<') | Some('|') => false,
+        Some('=') => cfg!(target_endian = "big"),
+        _ => return Err(unsupported()),
+    };
+    let kind = match chars.next() {
+        Some(kind) if kind == 'i' || kind == 'u' || kind == 'f' => kind,
+        _ => return Err(unsupported()),
+    };
+    let size = chars.as_str().parse().map_err(|_| unsupported())?;
+    Ok(Header {
+        kind,
+        size,
+        big_endian,
+        fortran_order,
+        shape,
+    })
+}
+
+// Parser of the header, a Python dictionary literal such as
+// `{'descr': '<f8', 'fortran_order': False, 'shape': (2, 3), }`
+struct HeaderParser<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> HeaderParser<'a> {
+    fn error(&self) -> VectorLoadError {
+        invalid_format(format!(
+            "invalid header {:?} at position {}",
+            self.text.trim_end(),
+            self.pos
+        ))
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.text[self.pos..].chars().next()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek() {
+            if !c.is_whitespace() {
+                break;
+            }
+            self.pos += c.len_utf8();
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), VectorLoadError> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(c) if c == expected => {
+                self.pos += c.len_utf8();
+                Ok(())
+            }
+            _ => Err(self.error()),
+        }
+    }
+
+    // Consume the separator of the items, returns true if it is the end of
+    // the items.
+    fn separator(&mut self, end: char) -> Result<bool, VectorLoadError> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(',') => {
+                self.pos += 1;
+                Ok(false)
+            }
+            Some(c) if c == end => Ok(true),
+            _ => Err(self.error()),
+        }
+    }
+
+    fn parse_dict(
+        &mut self,
+    ) -> Result<Vec<(String, Value)>, VectorLoadError> {
+        let mut entries = Vec::new();
+        self.expect('{')?;
+        loop {
+            self.skip_whitespace();
+            if self.peek() == Some('}') {
+                break;
+            }
+            let key = self.parse_string()?;
+            self.expect(':')?;
+            let value = self.parse_value()?;
+            entries.push((key, value));
+            if self.separator('}')? {
+                break;
+            }
+        }
+        self.expect('}')?;
+        Ok(entries)
+    }
+
+    fn parse_value(&mut self) -> Result<Value, VectorLoadError> {
+        self.skip_whitespace();
+        let rest = &self.text[self.pos..];
+        if rest.starts_with("True") {
+            self.pos += 4;
+            Ok(Value::Bool(true))
+        } else if rest.starts_with("False") {
+            self.pos += 5;
+            Ok(Value::Bool(false))
+        } else if rest.starts_with('(') {
+            Ok(Value::Tuple(self.parse_tuple()?))
+        } else {
+            Ok(Value::Str(self.parse_string()?))
+        }
+    }
+
+    fn parse_string(&mut self) -> Result<String, VectorLoadError> {
+        self.skip_whitespace();
+        let quote = match self.peek() {
+            Some(c) if c == '\'' || c == '"' => c,
+            _ => return Err(self.error()),
+        };
+        let start = self.pos + 1;
+        match self.text[start..].find(quote) {
+            Some(len) => {
+                self.pos = start + len + 1;
+                Ok(self.text[start..start + len].to_string())
+            }
+            None => Err(self.error()),
+        }
+    }
+
+    fn parse_tuple(&mut self) -> Result<Vec<usize>, VectorLoadError> {
+        let mut items = Vec::new();
+        self.expect('(')?;
+        loop {
+            self.skip_whitespace();
+            if self.peek() == Some(')') {
+                break;
+            }
+            let rest = &self.text[self.pos..];
+            let len = rest
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len());
+            let item = rest[..len].parse().map_err(|_| self.error())?;
+            items.push(item);
+            self.pos += len;
+            // Python 2 long integer suffix
+            if self.peek() == Some('L') {
+                self.pos += 1;
+            }
+            if self.separator(')')? {
+                break;
+            }
+        }
+        self.expect(')')?;
+        Ok(items)
+    }
+}
+
+// Write `data`, the elements of array with the given shape in C order, as
+// NPY array.
+pub(crate) fn write_array<T, W>(
+    writer: &mut W,
+    shape: &[usize],
+    data: &[T],
+    big_endian: bool,
+    fortran_order: bool,
+) -> io::Result<()>
+where
+    T: BinaryElement,
+    W: io::Write,
+{
+    let size = mem::size_of::<T>();
+    let order = if size == 1 {
+        '|'
+    } else if big_endian {
+        '>'
+    } else {
+        '<'
+    };
+    let dims: Vec<String> = shape.iter().map(|len| len.to_string()).collect();
+    let shape_text = match dims.len() {
+        1 => format!("({},)", dims[0]),
+        _ => format!("({})", dims.join(", ")),
+    };
+    let dict = format!(
+        "{{'descr': '{}{}{}', 'fortran_order': {}, 'shape': {}, }}",
+        order,
+        T::KIND,
+        size,
+        if fortran_order { "True" } else { "False" },
+        shape_text
+    );
+
+    // Pad the header with spaces so the data is aligned to 64 bytes, the
+    // format version 2.0 is only used if the header is too long
+    let padded = |prefix: usize| {
+        let len = prefix + dict.len() + 1;
+        dict.len() + 1 + (64 - len % 64) % 64
+    };
+    let mut prefix = MAGIC.to_vec();
+    let len = padded(10);
+    if len <= u16::MAX as usize {
+        prefix.extend_from_slice(&[1, 0]);
+        prefix.extend_from_slice(&(len as u16).to_le_bytes());
+    } else {
+        let len = padded(12);
+        if len > u32::MAX as usize {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "NPY header is too long",
+            ));
+        }
+        prefix.extend_from_slice(&[2, 0]);
+        prefix.extend_from_slice(&(len as u32).to_le_bytes());
+    }
+    let len = padded(prefix.len());
+    writer.write_all(&prefix)?;
+    writer.write_all(dict.as_bytes())?;
+    writer.write_all(&vec![b' '; len - dict.len() - 1])?;
+    writer.write_all(b"\n")?;
+
+    let data = if fortran_order {
+        Cow::Owned(reverse_axes(data, shape))
+    } else {
+        Cow::Borrowed(data)
+    };
+    let mut buf = Vec::with_capacity(BLOCK_SIZE * size);
+    for block in data.chunks(BLOCK_SIZE) {
+        buf.clear();
+        for element in block {
+            element.write_bytes(&mut buf, big_endian);
+        }
+        writer.write_all(&buf)?;
+    }
+    Ok(())
+}
+
+// Reverse the order of the axes of the array with the given shape and
+// elements in C order. It converts C order to Fortran order and, given the
+// reversed shape, Fortran order to C order.
+fn reverse_axes<T>(data: &[T], shape: &[usize]) -> Vec<T>
+where
+    T: Copy,
+{
+    let ndim = shape.len();
+    let mut strides = vec![1; ndim];
+    for k in (1..ndim).rev() {
+        strides[k - 1] = strides[k] * shape[k];
+    }
+    let mut index = vec![0; ndim];
+    let mut reversed = Vec::with_capacity(data.len());
+    for _ in 0..data.len() {
+        let offset: usize =
+            index.iter().zip(strides.iter()).map(|(i, s)| i * s).sum();
+        reversed.push(data[offset]);
+        // The first axis is the last axis of the reversed array
+        for (i, len) in index.iter_mut().zip(shape.iter()) {
+            *i += 1;
+            if *i < *len {
+                break;
+            }
+            *i = 0;
+        }
+    }
+    reversed
+}
+
+// Little-endian unsigned integer of `size` bytes at `pos`
+fn field(
+    bytes: &[u8],
+    pos: usize,
+    size: usize,
+) -> Result<u64, VectorLoadError> {
+    match bytes.get(pos..pos + size) {
+        Some(field) => Ok(field
+            .iter()
+            .rev()
+            .fold(0, |value, byte| (value << 8) | u64::from(*byte))),
+        None => {
+            Err(invalid_format(String::from("unexpected end of archive")))
+        }
+    }
+}
+
+fn put(buf: &mut Vec<u8>, value: u64, size: usize) {
+    buf.extend_from_slice(&value.to_le_bytes()[..size]);
+}
+
+// Array stored in NPZ archive
+#[derive(Debug, Clone)]
+struct Entry {
+    name: String,
+    method: u64,
+    crc: u64,
+    size: u64,
+    offset: u64,
+}
+
+/// Reader for NPZ archive of named arrays.
+///
+/// The arrays are read from the file when they are loaded, so only the
+/// loaded arrays are kept in memory.
+///
+/// # Examples
+/// ```
+/// # use crabsformer::prelude::*;
+/// # let path = std::env::temp_dir().join("crabsformer_doc_reader.npz");
+/// NpzWriter::new()
+///     .vector("x", &vector![1, 2, 3])
+///     .matrix("w", &matrix![1.0, 2.0; 3.0, 4.0])
+///     .write(&path)
+///     .unwrap();
+///
+/// let mut archive = NpzReader::open(&path).unwrap();
+/// assert_eq!(archive.names(), vec!["x", "w"]);
+/// let x: Vector<i32> = archive.vector("x").unwrap();
+/// let w: Matrix<f64> = archive.matrix("w").unwrap();
+/// assert_eq!(x, vector![1, 2, 3]);
+/// assert_eq!(w, matrix![1.0, 2.0; 3.0, 4.0]);
+/// ```
+#[derive(Debug)]
+pub struct NpzReader {
+    file: File,
+    entries: Vec<Entry>,
+}
+
+impl NpzReader {
+    /// Open NPZ archive and read the names of its arrays.
+    pub fn open<P>(file_path: P) -> Result<NpzReader, VectorLoadError>
+    where
+        P: AsRef<Path>,
+    {
+        let mut file = File::open(file_path)?;
+        let entries = read_central_directory(&mut file)?;
+        Ok(NpzReader { file, entries })
+    }
+
+    /// Returns the names of the arrays in the archive, without the `.npy`
+    /// extension.
+    pub fn names(&self) -> Vec<&str> {
+        self.entries
+            .iter()
+            .map(|entry| entry.name.trim_end_matches(".npy"))
+            .collect()
+    }
+
+    /// Load the array with the given name as numeric vector. The array
+    /// should be one-dimensional.
+    pub fn vector<T>(
+        &mut self,
+        name: &str,
+    ) -> Result<Vector<T>, VectorLoadError>
+    where
+        T: Num + BinaryElement,
+    {
+        let data = self.read_entry(name)?;
+        Vector::read_npy(&mut &data[..])
+    }
+
+    /// Load the array with the given name as matrix. The array should be
+    /// two-dimensional.
+    pub fn matrix<T>(
+        &mut self,
+        name: &str,
+    ) -> Result<Matrix<T>, MatrixLoadError>
+    where
+        T: Num + BinaryElement,
+    {
+        let data = self.read_entry(name)?;
+        Matrix::read_npy(&mut &data[..])
+    }
+
+    fn read_entry(&mut self, name: &str) -> Result<Vec<u8>, VectorLoadError> {
+        let file_name = format!("{}.npy", name);
+        let entry = self
+            .entries
+            .iter()
+            .find(|entry| entry.name == file_name || entry.name == name)
+            .cloned()
+            .ok_or_else(|| {
+                VectorLoadError::new(
+                    VectorLoadErrorKind::InvalidName,
+                    format!("array {:?} is not found", name),
+                )
+            })?;
+        if entry.method != 0 {
+            return Err(invalid_format(format!(
+                "array {:?} is compressed, only uncompressed archives are \
+                 supported",
+                name
+            )));
+        }
+
+        // The local header may have different extra field than the central
+        // directory
+        let mut header = [0; 30];
+        self.file.seek(SeekFrom::Start(entry.offset))?;
+        read_exact(&mut self.file, &mut header)?;
+        if field(&header, 0, 4)? != LOCAL_HEADER {
+            return Err(invalid_format(String::from("invalid local header")));
+        }
+        let skip = field(&header, 26, 2)? + field(&header, 28, 2)?;
+        self.file.seek(SeekFrom::Current(skip as i64))?;
+
+        let mut data = Vec::new();
+        (&mut self.file).take(entry.size).read_to_end(&mut data)?;
+        if data.len() as u64 != entry.size {
+            return Err(invalid_format(String::from(
+                "unexpected end of archive",
+            )));
+        }
+        if u64::from(utils::crc32(&data)) != entry.crc {
+            return Err(invalid_format(format!(
+                "checksum of array {:?} does not match",
+                name
+            )));
+        }
+        Ok(data)
+    }
+}
+
+fn read_central_directory(
+    file: &mut File,
+) -> Result<Vec<Entry>, VectorLoadError> {
+    // The end of central directory record is at the end of the file,
+    // followed by a comment of at most 65535 bytes
+    let len = file.seek(SeekFrom::End(0))?;
+    let tail_len = len.min(22 + 65535);
+    file.seek(SeekFrom::Start(len - tail_len))?;
+    let mut tail = vec![0; tail_len as usize];
+    read_exact(file, &mut tail)?;
+    let pos = (0..tail.len().saturating_sub(21))
+        .rev()
+        .find(|&i| field(&tail, i, 4).ok() == Some(END_OF_CENTRAL_DIRECTORY))
+        .ok_or_else(|| invalid_format(String::from("not a ZIP archive")))?;
+    let record = &tail[pos..];
+    let mut count = field(record, 10, 2)?;
+    let mut size = field(record, 12, 4)?;
+    let mut offset = field(record, 16, 4)?;
+    if count == 0xffff || size == 0xffff_ffff || offset == 0xffff_ffff {
+        // Read ZIP64 end of central directory record
+        let pos = (len - tail_len + pos as u64)
+            .checked_sub(20)
+            .ok_or_else(|| invalid_format(String::from("invalid archive")))?;
+        let mut locator = [0; 20];
+        file.seek(SeekFrom::Start(pos))?;
+        read_exact(file, &mut locator)?;
+        if field(&locator, 0, 4)? != ZIP64_LOCATOR {
+            return Err(invalid_format(String::from(
+                "invalid ZIP64 locator",
+            )));
+        }
+        let mut record = [0; 56];
+        file.seek(SeekFrom::Start(field(&locator, 8, 8)?))?;
+        read_exact(file, &mut record)?;
+        if field(&record, 0, 4)? != ZIP64_END_OF_CENTRAL_DIRECTORY {
+            return Err(invalid_format(String::from("invalid ZIP64 record")));
+        }
+        count = field(&record, 32, 8)?;
+        size = field(&record, 40, 8)?;
+        offset = field(&record, 48, 8)?;
+    }
+    if offset.saturating_add(size) > len {
+        return Err(invalid_format(String::from(
+            "invalid central directory",
+        )));
+    }
+
+    let mut directory = Vec::new();
+    file.seek(SeekFrom::Start(offset))?;
+    file.take(size).read_to_end(&mut directory)?;
+    let mut entries = Vec::new();
+    let mut pos = 0;
+    for _ in 0..count {
+        if field(&directory, pos, 4)? != CENTRAL_HEADER {
+            return Err(invalid_format(String::from(
+                "invalid central header",
+            )));
+        }
+        let name_len = field(&directory, pos + 28, 2)? as usize;
+        let extra_len = field(&directory, pos + 30, 2)? as usize;
+        let comment_len = field(&directory, pos + 32, 2)? as usize;
+        let name = directory.get(pos + 46..pos + 46 + name_len).ok_or_else(
+            || invalid_format(String::from("unexpected end of archive")),
+        )?;
+        let mut entry = Entry {
+            name: String::from_utf8_lossy(name).into_owned(),
+            method: field(&directory, pos + 10, 2)?,
+            crc: field(&directory, pos + 16, 4)?,
+            size: field(&directory, pos + 24, 4)?,
+            offset: field(&directory, pos + 42, 4)?,
+        };
+        let compressed_size = field(&directory, pos + 20, 4)?;
+
+        // The values that do not fit are stored in the ZIP64 extra field
+        let extra_pos = pos + 46 + name_len;
+        let mut i = extra_pos;
+        while i + 4 <= extra_pos + extra_len {
+            let id = field(&directory, i, 2)?;
+            let len = field(&directory, i + 2, 2)? as usize;
+            if id == 1 {
+                let mut j = i + 4;
+                if entry.size == 0xffff_ffff {
+                    entry.size = field(&directory, j, 8)?;
+                    j += 8;
+                }
+                if compressed_size == 0xffff_ffff {
+                    j += 8;
+                }
+                if entry.offset == 0xffff_ffff {
+                    entry.offset = field(&directory, j, 8)?;
+                }
+            }
+            i += 4 + len;
+        }
+        entries.push(entry);
+        pos = extra_pos + extra_len + comment_len;
+    }
+    Ok(entries)
+}
+
+/// Writer for NPZ archive of named arrays, the archive can be loaded using
+/// `numpy.load`. The arrays are stored uncompressed, in little endian and C
+/// order.
+///
+/// # Examples
+/// ```
+/// # use crabsformer::prelude::*;
+/// # let path = std::env::temp_dir().join("crabsformer_doc_writer.npz");
+/// NpzWriter::new()
+///     .vector("y", &vector![0.0, 1.0])
+///     .matrix("x", &matrix![1, 2; 3, 4])
+///     .write(&path)
+///     .unwrap();
+/// ```
+#[derive(Debug, Default)]
+pub struct NpzWriter {
+    arrays: Vec<(String, Vec<u8>)>,
+}
+
+impl NpzWriter {
+    /// Create a new empty archive.
+    pub fn new() -> NpzWriter {
+        NpzWriter::default()
+    }
+
+    /// Add numeric vector with the given name. If an array with the same
+    /// name is already added, it is replaced.
+    pub fn vector<T>(self, name: &str, vector: &Vector<T>) -> NpzWriter
+    where
+        T: Num + BinaryElement,
+    {
+        let mut data = Vec::new();
+        // Writing to memory does not fail
+        let _ = vector.write_npy(&mut data, false);
+        self.add(name, data)
+    }
+
+    /// Add matrix with the given name. If an array with the same name is
+    /// already added, it is replaced.
+    pub fn matrix<T>(self, name: &str, matrix: &Matrix<T>) -> NpzWriter
+    where
+        T: Num + BinaryElement,
+    {
+        let mut data = Vec::new();
+        // Writing to memory does not fail
+        let _ = matrix.write_npy(&mut data, false, false);
+        self.add(name, data)
+    }
+
+    fn add(mut self, name: &str, data: Vec<u8>) -> NpzWriter {
+        let name = format!("{}.npy", name);
+        self.arrays.retain(|(array, _)| *array != name);
+        self.arrays.push((name, data));
+        self
+    }
+
+    /// Write the archive to file. The file is created or truncated.
+    pub fn write<P>(self, file_path: P) -> Result<(), VectorLoadError>
+    where
+        P: AsRef<Path>,
+    {
+        let too_large = || {
+            invalid_format(String::from(
+                "archives larger than 4 GiB are not supported",
+            ))
+        };
+        if self.arrays.len() > 0xffff {
+            return Err(invalid_format(String::from(
+                "archives with more than 65535 arrays are not supported",
+            )));
+        }
+
+        let mut file = BufWriter::new(File::create(file_path)?);
+        let mut directory = Vec::new();
+        let mut offset = 0;
+        for (name, data) in &self.arrays {
+            let crc = u64::from(utils::crc32(data));
+            let size = data.len() as u64;
+            if size >= 0xffff_ffff || offset >= 0xffff_ffff {
+                return Err(too_large());
+            }
+
+            // Version 2.0, no flags, stored, 1980-01-01 00:00
+            let mut header = Vec::new();
+            put(&mut header, LOCAL_HEADER, 4);
+            for value in &[20, 0, 0, 0, 0x21] {
+                put(&mut header, *value, 2);
+            }
+            put(&mut header, crc, 4);
+            put(&mut header, size, 4);
+            put(&mut header, size, 4);
+            put(&mut header, name.len() as u64, 2);
+            put(&mut header, 0, 2);
+            header.extend_from_slice(name.as_bytes());
+            file.write_all(&header)?;
+            file.write_all(data)?;
+
+            put(&mut directory, CENTRAL_HEADER, 4);
+            for value in &[20, 20, 0, 0, 0, 0x21] {
+                put(&mut directory, *value, 2);
+            }
+            put(&mut directory, crc, 4);
+            put(&mut directory, size, 4);
+            put(&mut directory, size, 4);
+            put(&mut directory, name.len() as u64, 2);
+            for _ in 0..4 {
+                put(&mut directory, 0, 2);
+            }
+            put(&mut directory, 0, 4);
+            put(&mut directory, offset, 4);
+            directory.extend_from_slice(name.as_bytes());
+            offset += header.len() as u64 + size;
+        }
+        if offset >= 0xffff_ffff {
+            return Err(too_large());
+        }
+
+        let mut record = Vec::new();
+        put(&mut record, END_OF_CENTRAL_DIRECTORY, 4);
+        put(&mut record, 0, 2);
+        put(&mut record, 0, 2);
+        put(&mut record, self.arrays.len() as u64, 2);
+        put(&mut record, self.arrays.len() as u64, 2);
+        put(&mut record, directory.len() as u64, 4);
+        put(&mut record, offset, 4);
+        put(&mut record, 0, 2);
+        file.write_all(&directory)?;
+        file.write_all(&record)?;
+        file.flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_header() {
+        let header = parse_header(
+            "{'descr': '>i2', 'fortran_order': True, 'shape': (2, 3), }   \n",
+        )
+        .unwrap();
+        assert_eq!(header.kind, 'i');
+        assert_eq!(header.size, 2);
+        assert!(header.big_endian);
+        assert!(header.fortran_order);
+        assert_eq!(header.shape, vec![2, 3]);
+
+        // Keys in any order, double quotes and Python 2 long integers
+        let header = parse_header(
+            "{\"shape\": (3L,), \"fortran_order\": False, \"descr\": \"|u1\"}",
+        )
+        .unwrap();
+        assert_eq!(header.shape, vec![3]);
+        assert_eq!(header.size, 1);
+
+        // Scalar
+        let header = parse_header(
+            "{'descr': '<f8', 'fortran_order': False, 'shape': ()}",
+        )
+        .unwrap();
+        assert!(header.shape.is_empty());
+
+        assert!(parse_header("{'descr': '<f8', 'shape': (1,)}").is_err());
+        assert!(parse_header(
+            "{'descr': '<c16', 'fortran_order': False, 'shape': (1,)}"
+        )
+        .is_err());
+        assert!(parse_header(
+            "{'descr': '<f8', 'fortran_order': False, 'shape': (1,)} x"
+        )
+        .is_err());
+        assert!(parse_header("{'descr': [('a', '<f8')], 'fortran_order': False, 'shape': (1,)}").is_err());
+        assert!(parse_header(
+            "{'descr': '<f8', 'fortran_order': False, 'shape': (-1,)}"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_reverse_axes() {
+        let data = vec![1, 2, 3, 4, 5, 6];
+        assert_eq!(reverse_axes(&data, &[2, 3]), vec![1, 4, 2, 5, 3, 6]);
+        assert_eq!(reverse_axes(&data, &[3, 2]), vec![1, 3, 5, 2, 4, 6]);
+        assert_eq!(reverse_axes(&data, &[6]), data);
+        let data: Vec<i32> = (0..24).collect();
+        let reversed = reverse_axes(&data, &[2, 3, 4]);
+        assert_eq!(reversed[1], 12);
+        assert_eq!(reversed[2], 4);
+        assert_eq!(reverse_axes(&reversed, &[4, 3, 2]), data);
+    }
+}
@@ -33,6 +33,9 @@ pub use crate::matrix::loaders::*;
 pub use crate::matrix::operations::*;
 pub use crate::matrix::slicing::*;
 pub use crate::matrix::*;
+pub use crate::npy::*;
+pub use crate::printing::*;
+pub use crate::random::*;
 pub use crate::vector::builders::*;
 pub use crate::vector::errors::*;
 pub use crate::vector::indexing::*;
@@ -41,4 +44,5 @@ pub use crate::vector::loaders::*;
 pub use crate::vector::operations::*;
 pub use crate::vector::slicing::*;
 pub use crate::vector::*;
+pub use crate::wav::*;
 pub use crate::*;
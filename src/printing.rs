@@ -0,0 +1,265 @@
+// Copyright (c) 2019, Bayu Aldi Yansyah <bayualdiyansyah@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Printing numeric vectors and matrices.
+//!
+//! Numeric vectors and matrices implement [`fmt::Display`], the elements are
+//! right-aligned to the same width:
+//!
+//! ```
+//! # use crabsformer::prelude::*;
+//! let w = matrix![1, -20, 3; 400, 5, 6];
+//! assert_eq!(format!("{}", w), "[[  1 -20   3]\n [400   5   6]]");
+//! ```
+//!
+//! The output is controlled by [`PrintOptions`], either globally using
+//! [`set_print_options`] or per call using `display`, for example
+//! [`Vector::display`]. The precision of the formatter, as in `{:.2}`,
+//! overrides the precision of the options.
+//!
+//! ```
+//! # use crabsformer::prelude::*;
+//! let x = vector![1.0, 2.5, 30.25];
+//! assert_eq!(format!("{:.1}", x), "[ 1.0  2.5 30.2]");
+//!
+//! let options = PrintOptions {
+//!     scientific: true,
+//!     precision: Some(2),
+//!     ..PrintOptions::default()
+//! };
+//! assert_eq!(
+//!     format!("{}", x.display(options)),
+//!     "[1.00e0 2.50e0 3.02e1]"
+//! );
+//! ```
+//!
+//! Large numeric vectors and matrices are summarized, only the first and
+//! the last elements of each dimension are printed:
+//!
+//! ```
+//! # use crabsformer::prelude::*;
+//! let x: Vector<i32> = Vector::range(0, 2000, 1).unwrap();
+//! assert_eq!(format!("{}", x), "[   0    1    2 ... 1997 1998 1999]");
+//! ```
+//!
+//! [`fmt::Display`]: https://doc.rust-lang.org/std/fmt/trait.Display.html
+//! [`PrintOptions`]: struct.PrintOptions.html
+//! [`set_print_options`]: fn.set_print_options.html
+//! [`Vector::display`]: ../vector/struct.Vector.html#method.display
+
+use crate::utils;
+use num::Num;
+use std::fmt;
+use std::sync::{PoisonError, RwLock};
+
+/// Options to print numeric vectors and matrices.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PrintOptions {
+    /// Number of digits after the decimal point. By default, it is `None`
+    /// and floating point numbers are printed with as many digits as
+    /// needed to represent them exactly.
+    pub precision: Option<usize>,
+    /// Print the elements in scientific notation, for example `1.5e3`. By
+    /// default, it is false.
+    pub scientific: bool,
+    /// Total number of elements that triggers summarization. By default,
+    /// it is 1000.
+    pub threshold: usize,
+    /// Number of elements printed at the beginning and at the end of each
+    /// dimension when summarized. By default, it is 3.
+    pub edge_items: usize,
+}
+
+impl Default for PrintOptions {
+    fn default() -> PrintOptions {
+        PrintOptions {
+            precision: None,
+            scientific: false,
+            threshold: 1000,
+            edge_items: 3,
+        }
+    }
+}
+
+// Same as `PrintOptions::default()`, which cannot be used in a static
+static PRINT_OPTIONS: RwLock<PrintOptions> = RwLock::new(PrintOptions {
+    precision: None,
+    scientific: false,
+    threshold: 1000,
+    edge_items: 3,
+});
+
+/// Set the global print options, they are used by all threads when numeric
+/// vectors and matrices are formatted using `{}`.
+///
+/// # Examples
+/// ```
+/// # use crabsformer::prelude::*;
+/// set_print_options(PrintOptions {
+///     precision: Some(3),
+///     ..PrintOptions::default()
+/// });
+/// assert_eq!(format!("{}", vector![0.5, 1.0]), "[0.500 1.000]");
+/// set_print_options(PrintOptions::default());
+/// ```
+pub fn set_print_options(options: PrintOptions) {
+    // The options are plain data, so they are still valid after a panic
+    let mut global = PRINT_OPTIONS
+        .write()
+        .unwrap_or_else(PoisonError::into_inner);
+    *global = options;
+}
+
+/// Returns the global print options.
+///
+/// # Examples
+/// ```
+/// # use crabsformer::prelude::*;
+/// assert_eq!(print_options(), PrintOptions::default());
+/// ```
+pub fn print_options() -> PrintOptions {
+    *PRINT_OPTIONS.read().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Numeric vector formatted with the given print options.
+///
+/// See also: [`Vector::display`].
+///
+/// [`Vector::display`]: ../vector/struct.Vector.html#method.display
+pub struct VectorDisplay<'a, T>
+where
+    T: Num + Copy,
+{
+    pub(crate) data: &'a [T],
+    pub(crate) options: PrintOptions,
+}
+
+impl<'a, T> fmt::Display for VectorDisplay<'a, T>
+where
+    T: Num + Copy + fmt::Display + fmt::LowerExp,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let shape = [1, self.data.len()];
+        format_array(f, self.data, shape, false, &self.options)
+    }
+}
+
+/// Matrix formatted with the given print options.
+///
+/// See also: [`Matrix::display`].
+///
+/// [`Matrix::display`]: ../matrix/struct.Matrix.html#method.display
+pub struct MatrixDisplay<'a, T>
+where
+    T: Num + Copy,
+{
+    pub(crate) data: &'a [T],
+    pub(crate) shape: [usize; 2],
+    pub(crate) options: PrintOptions,
+}
+
+impl<'a, T> fmt::Display for MatrixDisplay<'a, T>
+where
+    T: Num + Copy + fmt::Display + fmt::LowerExp,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        format_array(f, self.data, self.shape, true, &self.options)
+    }
+}
+
+// Indices of the printed elements of a dimension of length `len`, `None`
+// marks the skipped elements.
+fn printed_indices(
+    len: usize,
+    summarize: bool,
+    edge_items: usize,
+) -> Vec<Option<usize>> {
+    if summarize && len > 2 * edge_items {
+        let head = (0..edge_items).map(Some);
+        let tail = (len - edge_items..len).map(Some);
+        head.chain(Some(None)).chain(tail).collect()
+    } else {
+        (0..len).map(Some).collect()
+    }
+}
+
+// Format the elements of array with the given shape in row-major order.
+// The array is printed as nested lists if `two_dim` is true.
+pub(crate) fn format_array<T>(
+    f: &mut fmt::Formatter,
+    data: &[T],
+    shape: [usize; 2],
+    two_dim: bool,
+    options: &PrintOptions,
+) -> fmt::Result
+where
+    T: Copy + fmt::Display + fmt::LowerExp,
+{
+    let [nrows, ncols] = shape;
+    let precision = f.precision().or(options.precision);
+    let summarize = data.len() > options.threshold;
+    let rows = printed_indices(nrows, summarize, options.edge_items);
+    let cols = printed_indices(ncols, summarize, options.edge_items);
+
+    // Format the printed elements first to align them
+    let formatted: Vec<Option<Vec<Option<String>>>> = rows
+        .iter()
+        .map(|i| {
+            i.map(|i| {
+                cols.iter()
+                    .map(|j| {
+                        j.map(|j| {
+                            let value = data[i * ncols + j];
+                            utils::format_element(
+                                value,
+                                precision,
+                                options.scientific,
+                            )
+                        })
+                    })
+                    .collect()
+            })
+        })
+        .collect();
+    let width = formatted
+        .iter()
+        .flatten()
+        .flatten()
+        .flatten()
+        .map(|s| s.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    let lines: Vec<String> = formatted
+        .iter()
+        .map(|row| match row {
+            Some(row) => {
+                let elements: Vec<String> = row
+                    .iter()
+                    .map(|element| match element {
+                        Some(element) => format!("{:>1$}", element, width),
+                        None => String::from("..."),
+                    })
+                    .collect();
+                format!("[{}]", elements.join(" "))
+            }
+            None => String::from("..."),
+        })
+        .collect();
+    if two_dim {
+        write!(f, "[{}]", lines.join("\n "))
+    } else {
+        write!(f, "{}", lines.join(""))
+    }
+}
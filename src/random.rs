@@ -0,0 +1,531 @@
+// Copyright (c) 2019, Bayu Aldi Yansyah <bayualdiyansyah@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Pseudo-random number generators.
+//!
+//! All random builders ([`RandomVectorBuilder`], [`RandomMatrixBuilder`]
+//! and [`QuasiRandomMatrixBuilder`]) draw their samples from one of the
+//! generators listed in [`Generator`]:
+//!
+//! - [`Xoshiro256StarStar`], the xoshiro256\*\* generator by David
+//!   Blackman and Sebastiano Vigna. It is the default generator.
+//! - [`Xoshiro256PlusPlus`], the xoshiro256++ generator by the same
+//!   authors. It shares the state, the period and the jump functions of
+//!   xoshiro256\*\*, but uses a different output function.
+//!
+//! The generators are implemented in this crate, so their raw output
+//! (`next_u32`, `next_u64` and `fill_bytes`) is the same on every platform.
+//!
+//! The distributions, `Uniform`, shuffling and choice used by the builders
+//! are provided by the `rand` crate. This crate depends on exactly version
+//! 0.6.5 of `rand`, so a `cargo update` can not change the samples drawn
+//! from a seeded builder. Any future upgrade of `rand` that changes the
+//! samples will be released as a breaking change of this crate.
+//!
+//! The generator state can be saved as a [`RandomState`] and restored
+//! later, and independent sub-streams can be created using
+//! [`Xoshiro256StarStar::jump`], which advances the state by 2<sup>128</sup>
+//! steps.
+//!
+//! # Examples
+//! ```
+//! # use crabsformer::prelude::*;
+//! let mut rvb = RandomVectorBuilder::new();
+//! rvb.seed(42);
+//! let state = rvb.state();
+//! let x = rvb.uniform(5, 0.0, 1.0).unwrap();
+//!
+//! // Restore the saved state to replay the same samples
+//! rvb.set_state(&state);
+//! let y = rvb.uniform(5, 0.0, 1.0).unwrap();
+//! assert_eq!(x, y);
+//! ```
+//!
+//! [`RandomVectorBuilder`]: ../vector/builders/struct.RandomVectorBuilder.html
+//! [`RandomMatrixBuilder`]: ../matrix/builders/struct.RandomMatrixBuilder.html
+//! [`QuasiRandomMatrixBuilder`]: ../matrix/builders/struct.QuasiRandomMatrixBuilder.html
+//! [`Generator`]: enum.Generator.html
+//! [`Xoshiro256StarStar`]: struct.Xoshiro256StarStar.html
+//! [`Xoshiro256PlusPlus`]: struct.Xoshiro256PlusPlus.html
+//! [`Xoshiro256StarStar::jump`]: struct.Xoshiro256StarStar.html#method.jump
+//! [`RandomState`]: struct.RandomState.html
+
+use crate::vector::errors::{VectorBuilderError, VectorBuilderErrorKind};
+use rand::{Error, FromEntropy, RngCore, SeedableRng};
+
+// Polynomial to advance the state by 2^128 steps
+const JUMP: [u64; 4] = [
+    0x180e_c6d3_3cfd_0aba,
+    0xd5a6_1266_f0c9_392c,
+    0xa958_2618_e03f_c9aa,
+    0x39ab_dc45_29b1_661c,
+];
+
+// Polynomial to advance the state by 2^192 steps
+const LONG_JUMP: [u64; 4] = [
+    0x76e1_5d3e_fefd_cbbf,
+    0xc500_4e44_1c52_2fb3,
+    0x7771_0069_854e_e241,
+    0x3910_9bb0_2acb_e635,
+];
+
+/// Snapshot of the pseudo-random number generator state.
+///
+/// The state can be converted to 32 bytes (four little-endian `u64`
+/// words) using [`RandomState::to_bytes`] and converted back using
+/// [`RandomState::from_bytes`], so it can be saved and restored across
+/// runs and platforms.
+///
+/// [`RandomState::to_bytes`]: #method.to_bytes
+/// [`RandomState::from_bytes`]: #method.from_bytes
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RandomState {
+    s: [u64; 4],
+}
+
+impl RandomState {
+    /// Convert the state to 32 bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rvb = RandomVectorBuilder::new();
+    /// rvb.seed(42);
+    /// let bytes = rvb.state().to_bytes();
+    /// assert_eq!(bytes.len(), 32);
+    /// ```
+    pub fn to_bytes(&self) -> [u8; 32] {
+        let mut bytes = [0; 32];
+        for (chunk, word) in bytes.chunks_mut(8).zip(self.s.iter()) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Create a state from 32 bytes produced by [`RandomState::to_bytes`].
+    ///
+    /// **Note that**: It will returns an error if the length of `bytes`
+    /// is not 32 or if all bytes are zero, because xoshiro256\*\* can not
+    /// recover from the all-zero state.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rvb = RandomVectorBuilder::new();
+    /// rvb.seed(42);
+    /// let state = rvb.state();
+    /// let restored = RandomState::from_bytes(&state.to_bytes()).unwrap();
+    /// assert_eq!(state, restored);
+    /// ```
+    ///
+    /// [`RandomState::to_bytes`]: #method.to_bytes
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, VectorBuilderError> {
+        if bytes.len() != 32 {
+            return Err(VectorBuilderError::new(
+                VectorBuilderErrorKind::InvalidState,
+                format!("expected 32 bytes, got {}", bytes.len()),
+            ));
+        }
+        if bytes.iter().all(|b| *b == 0) {
+            return Err(VectorBuilderError::new(
+                VectorBuilderErrorKind::InvalidState,
+                "state should not be all zeros".to_string(),
+            ));
+        }
+        let mut s = [0; 4];
+        for (word, chunk) in s.iter_mut().zip(bytes.chunks(8)) {
+            let mut buf = [0; 8];
+            buf.copy_from_slice(chunk);
+            *word = u64::from_le_bytes(buf);
+        }
+        Ok(RandomState { s })
+    }
+}
+
+/// Pseudo-random number generators that can be used by the random
+/// builders.
+///
+/// # Examples
+/// ```
+/// # use crabsformer::prelude::*;
+/// let mut rvb = RandomVectorBuilder::with_generator(Generator::Xoshiro256PlusPlus);
+/// rvb.seed(42);
+/// assert_eq!(rvb.generator(), Generator::Xoshiro256PlusPlus);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Generator {
+    /// The [`Xoshiro256StarStar`] generator. It is the default generator.
+    ///
+    /// [`Xoshiro256StarStar`]: struct.Xoshiro256StarStar.html
+    #[default]
+    Xoshiro256StarStar,
+    /// The [`Xoshiro256PlusPlus`] generator.
+    ///
+    /// [`Xoshiro256PlusPlus`]: struct.Xoshiro256PlusPlus.html
+    Xoshiro256PlusPlus,
+}
+
+/// The xoshiro256\*\* pseudo-random number generator.
+///
+/// It has 256 bits of state, a period of 2<sup>256</sup> - 1 and passes
+/// all known statistical tests. It is not cryptographically secure.
+///
+/// Seeding from `u64` using [`SeedableRng::seed_from_u64`] expands the
+/// seed with SplitMix64, as recommended by the authors.
+///
+/// # Examples
+/// ```
+/// # use crabsformer::prelude::*;
+/// use rand::{RngCore, SeedableRng};
+/// let mut rng = Xoshiro256StarStar::seed_from_u64(0);
+/// assert_eq!(rng.next_u64(), 11091344671253066420);
+/// ```
+///
+/// [`SeedableRng::seed_from_u64`]: https://docs.rs/rand/0.6/rand/trait.SeedableRng.html#method.seed_from_u64
+#[derive(Debug, Clone, PartialEq)]
+pub struct Xoshiro256StarStar {
+    s: [u64; 4],
+}
+
+impl Xoshiro256StarStar {
+    /// Create a new generator from the saved state.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// use rand::SeedableRng;
+    /// let rng = Xoshiro256StarStar::seed_from_u64(12);
+    /// let copy = Xoshiro256StarStar::from_state(&rng.state());
+    /// assert_eq!(rng, copy);
+    /// ```
+    pub fn from_state(state: &RandomState) -> Self {
+        Xoshiro256StarStar { s: state.s }
+    }
+
+    /// Returns the current state of the generator.
+    pub fn state(&self) -> RandomState {
+        RandomState { s: self.s }
+    }
+
+    /// Advance the generator by 2<sup>128</sup> steps.
+    ///
+    /// It can be used to generate 2<sup>128</sup> non-overlapping
+    /// sub-streams for parallel computations.
+    pub fn jump(&mut self) {
+        jump(&mut self.s, &JUMP);
+    }
+
+    /// Advance the generator by 2<sup>192</sup> steps.
+    ///
+    /// It can be used to generate 2<sup>64</sup> starting points, from each
+    /// of which [`jump`] will generate 2<sup>64</sup> non-overlapping
+    /// sub-streams.
+    ///
+    /// [`jump`]: #method.jump
+    pub fn long_jump(&mut self) {
+        jump(&mut self.s, &LONG_JUMP);
+    }
+}
+
+impl RngCore for Xoshiro256StarStar {
+    fn next_u32(&mut self) -> u32 {
+        // Use the high bits, they have better quality
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let result = self.s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        step(&mut self.s);
+        result
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        fill_bytes(self, dest);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl SeedableRng for Xoshiro256StarStar {
+    type Seed = [u8; 32];
+
+    fn from_seed(seed: [u8; 32]) -> Self {
+        // The all-zero state is a fixed point of the generator
+        match RandomState::from_bytes(&seed) {
+            Ok(state) => Xoshiro256StarStar::from_state(&state),
+            Err(_err) => Xoshiro256StarStar::seed_from_u64(0),
+        }
+    }
+
+    fn seed_from_u64(state: u64) -> Self {
+        Xoshiro256StarStar {
+            s: split_mix(state),
+        }
+    }
+}
+
+/// The xoshiro256++ pseudo-random number generator.
+///
+/// It shares the state, the period and the jump functions of
+/// [`Xoshiro256StarStar`], so a [`RandomState`] can be used by both
+/// generators, but it uses a different output function. It passes all
+/// known statistical tests. It is not cryptographically secure.
+///
+/// Seeding from `u64` using [`SeedableRng::seed_from_u64`] expands the
+/// seed with SplitMix64, as recommended by the authors.
+///
+/// # Examples
+/// ```
+/// # use crabsformer::prelude::*;
+/// use rand::{RngCore, SeedableRng};
+/// let mut rng = Xoshiro256PlusPlus::seed_from_u64(0);
+/// assert_eq!(rng.next_u64(), 5987356902031041503);
+/// ```
+///
+/// [`Xoshiro256StarStar`]: struct.Xoshiro256StarStar.html
+/// [`RandomState`]: struct.RandomState.html
+/// [`SeedableRng::seed_from_u64`]: https://docs.rs/rand/0.6/rand/trait.SeedableRng.html#method.seed_from_u64
+#[derive(Debug, Clone, PartialEq)]
+pub struct Xoshiro256PlusPlus {
+    s: [u64; 4],
+}
+
+impl Xoshiro256PlusPlus {
+    /// Create a new generator from the saved state.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// use rand::SeedableRng;
+    /// let rng = Xoshiro256PlusPlus::seed_from_u64(12);
+    /// let copy = Xoshiro256PlusPlus::from_state(&rng.state());
+    /// assert_eq!(rng, copy);
+    /// ```
+    pub fn from_state(state: &RandomState) -> Self {
+        Xoshiro256PlusPlus { s: state.s }
+    }
+
+    /// Returns the current state of the generator.
+    pub fn state(&self) -> RandomState {
+        RandomState { s: self.s }
+    }
+
+    /// Advance the generator by 2<sup>128</sup> steps.
+    ///
+    /// It can be used to generate 2<sup>128</sup> non-overlapping
+    /// sub-streams for parallel computations.
+    pub fn jump(&mut self) {
+        jump(&mut self.s, &JUMP);
+    }
+
+    /// Advance the generator by 2<sup>192</sup> steps.
+    ///
+    /// It can be used to generate 2<sup>64</sup> starting points, from each
+    /// of which [`jump`] will generate 2<sup>64</sup> non-overlapping
+    /// sub-streams.
+    ///
+    /// [`jump`]: #method.jump
+    pub fn long_jump(&mut self) {
+        jump(&mut self.s, &LONG_JUMP);
+    }
+}
+
+impl RngCore for Xoshiro256PlusPlus {
+    fn next_u32(&mut self) -> u32 {
+        // Use the high bits, they have better quality
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let result = self.s[0]
+            .wrapping_add(self.s[3])
+            .rotate_left(23)
+            .wrapping_add(self.s[0]);
+        step(&mut self.s);
+        result
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        fill_bytes(self, dest);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl SeedableRng for Xoshiro256PlusPlus {
+    type Seed = [u8; 32];
+
+    fn from_seed(seed: [u8; 32]) -> Self {
+        // The all-zero state is a fixed point of the generator
+        match RandomState::from_bytes(&seed) {
+            Ok(state) => Xoshiro256PlusPlus::from_state(&state),
+            Err(_err) => Xoshiro256PlusPlus::seed_from_u64(0),
+        }
+    }
+
+    fn seed_from_u64(state: u64) -> Self {
+        Xoshiro256PlusPlus {
+            s: split_mix(state),
+        }
+    }
+}
+
+// Generator of the random builders, it dispatches to the selected
+// generator.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum BuilderRng {
+    StarStar(Xoshiro256StarStar),
+    PlusPlus(Xoshiro256PlusPlus),
+}
+
+impl BuilderRng {
+    pub(crate) fn from_entropy(generator: Generator) -> Self {
+        match generator {
+            Generator::Xoshiro256StarStar => {
+                BuilderRng::StarStar(Xoshiro256StarStar::from_entropy())
+            }
+            Generator::Xoshiro256PlusPlus => {
+                BuilderRng::PlusPlus(Xoshiro256PlusPlus::from_entropy())
+            }
+        }
+    }
+
+    pub(crate) fn seed_from_u64(generator: Generator, value: u64) -> Self {
+        BuilderRng::from_state(
+            generator,
+            &RandomState {
+                s: split_mix(value),
+            },
+        )
+    }
+
+    pub(crate) fn from_state(
+        generator: Generator,
+        state: &RandomState,
+    ) -> Self {
+        match generator {
+            Generator::Xoshiro256StarStar => {
+                BuilderRng::StarStar(Xoshiro256StarStar::from_state(state))
+            }
+            Generator::Xoshiro256PlusPlus => {
+                BuilderRng::PlusPlus(Xoshiro256PlusPlus::from_state(state))
+            }
+        }
+    }
+
+    pub(crate) fn generator(&self) -> Generator {
+        match self {
+            BuilderRng::StarStar(_) => Generator::Xoshiro256StarStar,
+            BuilderRng::PlusPlus(_) => Generator::Xoshiro256PlusPlus,
+        }
+    }
+
+    pub(crate) fn state(&self) -> RandomState {
+        match self {
+            BuilderRng::StarStar(rng) => rng.state(),
+            BuilderRng::PlusPlus(rng) => rng.state(),
+        }
+    }
+
+    pub(crate) fn jump(&mut self) {
+        match self {
+            BuilderRng::StarStar(rng) => rng.jump(),
+            BuilderRng::PlusPlus(rng) => rng.jump(),
+        }
+    }
+}
+
+impl RngCore for BuilderRng {
+    fn next_u32(&mut self) -> u32 {
+        match self {
+            BuilderRng::StarStar(rng) => rng.next_u32(),
+            BuilderRng::PlusPlus(rng) => rng.next_u32(),
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        match self {
+            BuilderRng::StarStar(rng) => rng.next_u64(),
+            BuilderRng::PlusPlus(rng) => rng.next_u64(),
+        }
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        match self {
+            BuilderRng::StarStar(rng) => rng.fill_bytes(dest),
+            BuilderRng::PlusPlus(rng) => rng.fill_bytes(dest),
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+// Advance the linear engine shared by xoshiro256** and xoshiro256++ by one
+// step.
+fn step(s: &mut [u64; 4]) {
+    let t = s[1] << 17;
+    s[2] ^= s[0];
+    s[3] ^= s[1];
+    s[1] ^= s[2];
+    s[0] ^= s[3];
+    s[2] ^= t;
+    s[3] = s[3].rotate_left(45);
+}
+
+// Advance the state by the number of steps encoded in `polynomial`.
+fn jump(state: &mut [u64; 4], polynomial: &[u64; 4]) {
+    let mut s = [0; 4];
+    for word in polynomial.iter() {
+        for bit in 0..64 {
+            if word & (1 << bit) != 0 {
+                for (acc, value) in s.iter_mut().zip(state.iter()) {
+                    *acc ^= value;
+                }
+            }
+            step(state);
+        }
+    }
+    *state = s;
+}
+
+// Expand the seed to a state using SplitMix64.
+fn split_mix(seed: u64) -> [u64; 4] {
+    let mut x = seed;
+    let mut s = [0; 4];
+    for word in s.iter_mut() {
+        x = x.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = x;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        *word = z ^ (z >> 31);
+    }
+    s
+}
+
+// Fill `dest` with the little endian bytes of the 64-bit outputs.
+fn fill_bytes<R: RngCore>(rng: &mut R, dest: &mut [u8]) {
+    for chunk in dest.chunks_mut(8) {
+        let bytes = rng.next_u64().to_le_bytes();
+        chunk.copy_from_slice(&bytes[..chunk.len()]);
+    }
+}
@@ -12,6 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::vector::errors::{VectorParseError, VectorParseErrorKind};
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::fmt;
+use std::fs::File;
+use std::io;
+use std::mem;
+use std::path::PathBuf;
+use std::rc::Rc;
+
 /// Trait to print the type name of the numeric type.
 pub trait TypeName {
     fn type_name() -> &'static str;
@@ -41,6 +52,621 @@ implement_type_name_for_type!(u128, "u128");
 implement_type_name_for_type!(f32, "f32");
 implement_type_name_for_type!(f64, "f64");
 
+/// Format of the numbers in a text file.
+///
+/// By default, numbers use `.` as the decimal separator and have no
+/// thousands separator.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NumberFormat {
+    /// Thousands separator, it is removed before parsing.
+    pub thousands: Option<char>,
+    /// Decimal separator.
+    pub decimal: char,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        NumberFormat {
+            thousands: None,
+            decimal: '.',
+        }
+    }
+}
+
+impl NumberFormat {
+    // Trim `value` and convert it to the default format.
+    fn normalize<'a>(&self, value: &'a str) -> Cow<'a, str> {
+        let value = value.trim();
+        if self.thousands.is_none() && self.decimal == '.' {
+            return Cow::Borrowed(value);
+        }
+        let normalized = value
+            .chars()
+            .filter(|c| Some(*c) != self.thousands)
+            .map(|c| if c == self.decimal { '.' } else { c })
+            .collect();
+        Cow::Owned(normalized)
+    }
+}
+
+/// Trait to parse the numeric type from text files.
+///
+/// The default implementations trim the value and remove the thousands
+/// separator before parsing. Floats accept the scientific notation, `inf`,
+/// `infinity` and `nan` (case-insensitive). Integers accept the `0x`, `0o`
+/// and `0b` prefixes for hexadecimal, octal and binary numbers.
+///
+/// # Examples
+/// ```
+/// # use crabsformer::prelude::*;
+/// use crabsformer::utils::{NumberFormat, ParseElement};
+///
+/// let format = NumberFormat::default();
+/// assert_eq!(f64::parse_element("-1.5E3", &format), Some(-1500.0));
+/// assert_eq!(i32::parse_element("-0x1F", &format), Some(-31));
+///
+/// let format = NumberFormat {
+///     thousands: Some('.'),
+///     decimal: ',',
+/// };
+/// assert_eq!(f64::parse_element("1.234,5", &format), Some(1234.5));
+/// ```
+pub trait ParseElement: Sized {
+    /// Parse `value` in the given `format`, returns `None` if it is not a
+    /// valid number.
+    fn parse_element(value: &str, format: &NumberFormat) -> Option<Self>;
+}
+
+macro_rules! implement_parse_element_for_float {
+    ($t: ty) => {
+        impl ParseElement for $t {
+            fn parse_element(
+                value: &str,
+                format: &NumberFormat,
+            ) -> Option<Self> {
+                format.normalize(value).parse().ok()
+            }
+        }
+    };
+}
+
+macro_rules! implement_parse_element_for_integer {
+    ($t: ty) => {
+        impl ParseElement for $t {
+            fn parse_element(
+                value: &str,
+                format: &NumberFormat,
+            ) -> Option<Self> {
+                let value = format.normalize(value);
+                let (sign, digits) = match value.chars().next() {
+                    Some('-') => ("-", &value[1..]),
+                    Some('+') => ("", &value[1..]),
+                    _ => ("", &value[..]),
+                };
+                let radix = match digits.get(..2) {
+                    Some("0x") | Some("0X") => 16,
+                    Some("0o") | Some("0O") => 8,
+                    Some("0b") | Some("0B") => 2,
+                    _ => return value.parse().ok(),
+                };
+                // Do not accept a second sign after the prefix
+                let digits = &digits[2..];
+                if digits.starts_with('-') || digits.starts_with('+') {
+                    return None;
+                }
+                let digits = format!("{}{}", sign, digits);
+                <$t>::from_str_radix(&digits, radix).ok()
+            }
+        }
+    };
+}
+
+implement_parse_element_for_integer!(usize);
+implement_parse_element_for_integer!(i8);
+implement_parse_element_for_integer!(i16);
+implement_parse_element_for_integer!(i32);
+implement_parse_element_for_integer!(i64);
+implement_parse_element_for_integer!(i128);
+implement_parse_element_for_integer!(u8);
+implement_parse_element_for_integer!(u16);
+implement_parse_element_for_integer!(u32);
+implement_parse_element_for_integer!(u64);
+implement_parse_element_for_integer!(u128);
+implement_parse_element_for_float!(f32);
+implement_parse_element_for_float!(f64);
+
+/// Trait to convert the numeric type from and to bytes, used by the
+/// binary file formats.
+///
+/// # Examples
+/// ```
+/// use crabsformer::utils::BinaryElement;
+///
+/// let mut buf = Vec::new();
+/// 258u16.write_bytes(&mut buf, false);
+/// assert_eq!(buf, vec![2, 1]);
+/// assert_eq!(u16::from_bytes(&buf, true), 513);
+/// assert_eq!(u16::KIND, 'u');
+/// ```
+pub trait BinaryElement: Copy + TypeName {
+    /// Kind of the numeric type, `'i'` for signed integers, `'u'` for
+    /// unsigned integers and `'f'` for floating point numbers. The size of
+    /// the type is given by [`mem::size_of`].
+    ///
+    /// [`mem::size_of`]: https://doc.rust-lang.org/std/mem/fn.size_of.html
+    const KIND: char;
+
+    /// Read the element from `bytes`, its length should be equal to the size
+    /// of the type.
+    fn from_bytes(bytes: &[u8], big_endian: bool) -> Self;
+
+    /// Append the bytes of the element to `buf`.
+    fn write_bytes(self, buf: &mut Vec<u8>, big_endian: bool);
+}
+
+macro_rules! implement_binary_element_for_type {
+    ($t: ty, $kind: expr) => {
+        impl BinaryElement for $t {
+            const KIND: char = $kind;
+
+            fn from_bytes(bytes: &[u8], big_endian: bool) -> Self {
+                let mut buf = [0; mem::size_of::<$t>()];
+                buf.copy_from_slice(bytes);
+                if big_endian {
+                    <$t>::from_be_bytes(buf)
+                } else {
+                    <$t>::from_le_bytes(buf)
+                }
+            }
+
+            fn write_bytes(self, buf: &mut Vec<u8>, big_endian: bool) {
+                if big_endian {
+                    buf.extend_from_slice(&self.to_be_bytes());
+                } else {
+                    buf.extend_from_slice(&self.to_le_bytes());
+                }
+            }
+        }
+    };
+}
+
+implement_binary_element_for_type!(usize, 'u');
+implement_binary_element_for_type!(i8, 'i');
+implement_binary_element_for_type!(i16, 'i');
+implement_binary_element_for_type!(i32, 'i');
+implement_binary_element_for_type!(i64, 'i');
+implement_binary_element_for_type!(i128, 'i');
+implement_binary_element_for_type!(u8, 'u');
+implement_binary_element_for_type!(u16, 'u');
+implement_binary_element_for_type!(u32, 'u');
+implement_binary_element_for_type!(u64, 'u');
+implement_binary_element_for_type!(u128, 'u');
+implement_binary_element_for_type!(f32, 'f');
+implement_binary_element_for_type!(f64, 'f');
+
+// Lookup table of the CRC-32 checksum, computed at compile time.
+const CRC32_TABLE: [u32; 256] = crc32_table();
+
+const fn crc32_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut c = i as u32;
+        let mut k = 0;
+        while k < 8 {
+            c = if c & 1 != 0 {
+                0xedb8_8320 ^ (c >> 1)
+            } else {
+                c >> 1
+            };
+            k += 1;
+        }
+        table[i] = c;
+        i += 1;
+    }
+    table
+}
+
+// CRC-32 checksum (IEEE 802.3) of `data`, as used by ZIP archives.
+pub(crate) fn crc32(data: &[u8]) -> u32 {
+    crc32_update(0, data)
+}
+
+// Update the CRC-32 checksum `crc` of the previous data with `data`.
+pub(crate) fn crc32_update(crc: u32, data: &[u8]) -> u32 {
+    let mut crc = !crc;
+    for byte in data {
+        let index = ((crc ^ u32::from(*byte)) & 0xff) as usize;
+        crc = CRC32_TABLE[index] ^ (crc >> 8);
+    }
+    !crc
+}
+
+// Source of the CSV loaders, either a file path or any `io::Read`.
+pub(crate) enum CSVSource<'a> {
+    Path(PathBuf),
+    Reader(Box<dyn io::Read + 'a>),
+}
+
+impl<'a> CSVSource<'a> {
+    // Open the file if needed and returns the underlying reader.
+    pub(crate) fn into_reader(self) -> io::Result<Box<dyn io::Read + 'a>> {
+        match self {
+            CSVSource::Path(path) => Ok(Box::new(File::open(path)?)),
+            CSVSource::Reader(reader) => Ok(reader),
+        }
+    }
+}
+
+impl<'a> fmt::Debug for CSVSource<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CSVSource::Path(path) => write!(f, "Path({:?})", path),
+            CSVSource::Reader(_reader) => write!(f, "Reader"),
+        }
+    }
+}
+
+// Reader that keeps the bytes read by the CSV reader until they are
+// consumed by the `LineCounter`.
+pub(crate) struct LineTrackingReader<R> {
+    inner: R,
+    buffer: Rc<RefCell<VecDeque<u8>>>,
+}
+
+impl<R> io::Read for LineTrackingReader<R>
+where
+    R: io::Read,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.buffer.borrow_mut().extend(&buf[..n]);
+        Ok(n)
+    }
+}
+
+// Computes the line numbers of the CSV records. The line numbers reported
+// by `csv` are not reliable for blank lines and CRLF terminators, and its
+// byte offsets point to the end of the previous record.
+pub(crate) struct LineCounter {
+    buffer: Rc<RefCell<VecDeque<u8>>>,
+    comment: Option<u8>,
+    offset: u64,
+    lines: u64,
+}
+
+impl LineCounter {
+    // Wrap `reader` to track the lines of the CSV records.
+    pub(crate) fn new<R>(
+        reader: R,
+        comment: Option<u8>,
+    ) -> (LineTrackingReader<R>, LineCounter) {
+        let buffer = Rc::new(RefCell::new(VecDeque::new()));
+        let reader = LineTrackingReader {
+            inner: reader,
+            buffer: Rc::clone(&buffer),
+        };
+        let counter = LineCounter {
+            buffer,
+            comment,
+            offset: 0,
+            lines: 0,
+        };
+        (reader, counter)
+    }
+
+    // Returns the 1-based line number of the record reported at byte
+    // offset `byte`. It should be called with increasing offsets.
+    pub(crate) fn line(&mut self, byte: u64) -> u64 {
+        let buffer = Rc::clone(&self.buffer);
+        let mut buffer = buffer.borrow_mut();
+        while self.offset < byte {
+            match buffer.pop_front() {
+                Some(b) => self.consume(b),
+                None => break,
+            }
+        }
+        // Skip the blank lines and comments before the record
+        loop {
+            match buffer.front() {
+                Some(b'\r') | Some(b'\n') => {
+                    let b = buffer.pop_front().unwrap();
+                    self.consume(b);
+                }
+                Some(b) if Some(*b) == self.comment => {
+                    while let Some(b) = buffer.pop_front() {
+                        self.consume(b);
+                        if b == b'\n' {
+                            break;
+                        }
+                    }
+                }
+                _ => break,
+            }
+        }
+        self.lines + 1
+    }
+
+    fn consume(&mut self, b: u8) {
+        self.offset += 1;
+        if b == b'\n' {
+            self.lines += 1;
+        }
+    }
+}
+
+// Destination of the CSV writers, either a file path or any `io::Write`.
+pub(crate) enum CSVDestination<'a> {
+    Path(PathBuf),
+    Writer(Box<dyn io::Write + 'a>),
+}
+
+impl<'a> CSVDestination<'a> {
+    // Create the file if needed and returns the underlying writer.
+    pub(crate) fn into_writer(self) -> io::Result<Box<dyn io::Write + 'a>> {
+        match self {
+            CSVDestination::Path(path) => Ok(Box::new(File::create(path)?)),
+            CSVDestination::Writer(writer) => Ok(writer),
+        }
+    }
+}
+
+// Format the element with the given precision, in scientific notation if
+// `scientific` is true.
+pub(crate) fn format_element<T>(
+    value: T,
+    precision: Option<usize>,
+    scientific: bool,
+) -> String
+where
+    T: fmt::Display + fmt::LowerExp,
+{
+    match (precision, scientific) {
+        (Some(precision), true) => format!("{:.*e}", precision, value),
+        (Some(precision), false) => format!("{:.*}", precision, value),
+        (None, true) => format!("{:e}", value),
+        (None, false) => format!("{}", value),
+    }
+}
+
+// Token of numeric vector and matrix literals
+#[derive(Clone, Copy, PartialEq)]
+enum Token<'a> {
+    Open,
+    Close,
+    Comma,
+    Semicolon,
+    Newline,
+    Number(&'a str),
+    End,
+}
+
+// Rows of elements parsed from numeric vector or matrix literal.
+pub(crate) struct ParsedRows<T> {
+    pub(crate) rows: Vec<Vec<T>>,
+    // Byte position of each row
+    pub(crate) positions: Vec<usize>,
+    // True if the rows are written as NumPy-style nested lists
+    pub(crate) nested: bool,
+}
+
+struct RowsParser<'a> {
+    tokens: Vec<(Token<'a>, usize)>,
+    pos: usize,
+}
+
+impl<'a> RowsParser<'a> {
+    fn new(text: &'a str) -> RowsParser<'a> {
+        let mut tokens = Vec::new();
+        let mut start = None;
+        for (i, c) in text.char_indices() {
+            let token = match c {
+                '[' => Some(Token::Open),
+                ']' => Some(Token::Close),
+                ',' => Some(Token::Comma),
+                ';' => Some(Token::Semicolon),
+                '\n' => Some(Token::Newline),
+                c if c.is_whitespace() => None,
+                _ => {
+                    start = start.or(Some(i));
+                    continue;
+                }
+            };
+            if let Some(start) = start.take() {
+                tokens.push((Token::Number(&text[start..i]), start));
+            }
+            if let Some(token) = token {
+                tokens.push((token, i));
+            }
+        }
+        if let Some(start) = start {
+            tokens.push((Token::Number(&text[start..]), start));
+        }
+        tokens.push((Token::End, text.len()));
+        RowsParser { tokens, pos: 0 }
+    }
+
+    fn peek(&self) -> (Token<'a>, usize) {
+        self.tokens[self.pos]
+    }
+
+    fn next(&mut self) -> (Token<'a>, usize) {
+        let token = self.tokens[self.pos];
+        if token.0 != Token::End {
+            self.pos += 1;
+        }
+        token
+    }
+
+    fn unexpected(&self) -> VectorParseError {
+        let (token, position) = self.peek();
+        let message = match token {
+            Token::Open => String::from("unexpected `[`"),
+            Token::Close => String::from("unexpected `]`"),
+            Token::Comma => String::from("unexpected `,`"),
+            Token::Semicolon => String::from("unexpected `;`"),
+            Token::Newline => String::from("unexpected new line"),
+            Token::Number(value) => format!("unexpected `{}`", value),
+            Token::End => String::from("unexpected end of string"),
+        };
+        VectorParseError::new(
+            VectorParseErrorKind::InvalidSyntax,
+            message,
+            position,
+        )
+    }
+
+    fn expect(
+        &mut self,
+        expected: Token<'a>,
+    ) -> Result<(), VectorParseError> {
+        if self.peek().0 != expected {
+            return Err(self.unexpected());
+        }
+        self.next();
+        Ok(())
+    }
+
+    // Parse elements separated by commas or whitespaces, until a token that
+    // is not a number or a comma. A trailing comma is allowed.
+    fn elements<T>(&mut self) -> Result<Vec<T>, VectorParseError>
+    where
+        T: ParseElement,
+    {
+        let format = NumberFormat::default();
+        let mut elements = Vec::new();
+        while let (Token::Number(value), position) = self.peek() {
+            let element =
+                T::parse_element(value, &format).ok_or_else(|| {
+                    VectorParseError::new(
+                        VectorParseErrorKind::InvalidElement,
+                        format!("`{}`", value),
+                        position,
+                    )
+                })?;
+            elements.push(element);
+            self.next();
+            if self.peek().0 == Token::Comma {
+                self.next();
+                if let (Token::Comma, _) = self.peek() {
+                    return Err(self.unexpected());
+                }
+            }
+        }
+        if let (Token::Comma, _) = self.peek() {
+            return Err(self.unexpected());
+        }
+        Ok(elements)
+    }
+
+    // Parse NumPy-style nested lists, for example `[[1, 2], [3, 4]]`
+    fn nested<T>(&mut self) -> Result<ParsedRows<T>, VectorParseError>
+    where
+        T: ParseElement,
+    {
+        // New lines are whitespaces in nested lists
+        self.tokens.retain(|(token, _)| *token != Token::Newline);
+        let mut parsed = ParsedRows {
+            rows: Vec::new(),
+            positions: Vec::new(),
+            nested: true,
+        };
+        self.expect(Token::Open)?;
+        loop {
+            let position = self.peek().1;
+            self.expect(Token::Open)?;
+            parsed.rows.push(self.elements()?);
+            parsed.positions.push(position);
+            self.expect(Token::Close)?;
+            if self.peek().0 == Token::Comma {
+                self.next();
+            }
+            if self.peek().0 == Token::Close {
+                self.next();
+                break;
+            }
+        }
+        self.expect(Token::End)?;
+        Ok(parsed)
+    }
+
+    // Parse MATLAB-style rows separated by semicolons or new lines, for
+    // example `1 2; 3 4` or `[1, 2; 3, 4]`
+    fn rows<T>(&mut self) -> Result<ParsedRows<T>, VectorParseError>
+    where
+        T: ParseElement,
+    {
+        let mut parsed = ParsedRows {
+            rows: Vec::new(),
+            positions: Vec::new(),
+            nested: false,
+        };
+        let bracketed = self.peek().0 == Token::Open;
+        if bracketed {
+            self.next();
+        }
+        loop {
+            let position = self.peek().1;
+            let row = self.elements()?;
+            // Empty rows are ignored
+            if !row.is_empty() {
+                parsed.rows.push(row);
+                parsed.positions.push(position);
+            }
+            match self.peek().0 {
+                Token::Semicolon | Token::Newline => {
+                    self.next();
+                }
+                _ => break,
+            }
+        }
+        if bracketed {
+            self.expect(Token::Close)?;
+        }
+        self.expect(Token::End)?;
+        Ok(parsed)
+    }
+}
+
+// Parse numeric vector or matrix literal in MATLAB-style or NumPy-style
+// syntax into rows of elements. The rows are not checked to have the same
+// length.
+pub(crate) fn parse_rows<T>(
+    text: &str,
+) -> Result<ParsedRows<T>, VectorParseError>
+where
+    T: ParseElement,
+{
+    let mut parser = RowsParser::new(text);
+    // Leading and trailing new lines are ignored
+    let is_newline = |(token, _): &(Token, usize)| *token == Token::Newline;
+    let start = parser.tokens.iter().take_while(|t| is_newline(t)).count();
+    parser.tokens.drain(..start);
+    while parser.tokens.len() > 1
+        && is_newline(&parser.tokens[parser.tokens.len() - 2])
+    {
+        let len = parser.tokens.len();
+        parser.tokens.remove(len - 2);
+    }
+    if parser.peek().0 == Token::End {
+        return Err(VectorParseError::new(
+            VectorParseErrorKind::Empty,
+            String::from("nothing to parse"),
+            0,
+        ));
+    }
+    let mut tokens = parser.tokens.iter().filter(|t| !is_newline(t));
+    let nested = matches!(
+        (tokens.next(), tokens.next()),
+        (Some((Token::Open, _)), Some((Token::Open, _)))
+    );
+    if nested {
+        parser.nested()
+    } else {
+        parser.rows()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -61,4 +687,85 @@ mod tests {
         assert_eq!(<f32>::type_name(), "f32");
         assert_eq!(<f64>::type_name(), "f64");
     }
+
+    #[test]
+    fn test_binary_element() {
+        let mut buf = Vec::new();
+        (-2i32).write_bytes(&mut buf, false);
+        1.5f64.write_bytes(&mut buf, true);
+        assert_eq!(buf[..4], [0xfe, 0xff, 0xff, 0xff]);
+        assert_eq!(buf[4..], [0x3f, 0xf8, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(i32::from_bytes(&buf[..4], false), -2);
+        assert_eq!(f64::from_bytes(&buf[4..], true), 1.5);
+        assert_eq!(u128::from_bytes(&[1; 16], true), u128::MAX / 255);
+        assert_eq!((f32::KIND, i8::KIND, usize::KIND), ('f', 'i', 'u'));
+    }
+
+    #[test]
+    fn test_crc32() {
+        assert_eq!(crc32(b""), 0);
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+        assert_eq!(crc32_update(crc32(b"1234"), b"56789"), 0xcbf4_3926);
+    }
+
+    #[test]
+    fn test_parse_float() {
+        let format = NumberFormat::default();
+        assert_eq!(f64::parse_element(" 1.5 ", &format), Some(1.5));
+        assert_eq!(f64::parse_element("-2.5e-3", &format), Some(-0.0025));
+        assert_eq!(f64::parse_element("1E+05", &format), Some(1e5));
+        assert_eq!(f64::parse_element(".5", &format), Some(0.5));
+        assert_eq!(f64::parse_element("5.", &format), Some(5.0));
+        assert_eq!(f32::parse_element("inf", &format), Some(f32::INFINITY));
+        assert_eq!(
+            f64::parse_element("-Infinity", &format),
+            Some(f64::NEG_INFINITY)
+        );
+        assert!(f64::parse_element("NaN", &format).unwrap().is_nan());
+        assert!(f64::parse_element("nan", &format).unwrap().is_nan());
+        assert_eq!(f64::parse_element("1e", &format), None);
+        assert_eq!(f64::parse_element("1,5", &format), None);
+        assert_eq!(f64::parse_element("", &format), None);
+
+        let format = NumberFormat {
+            thousands: Some('.'),
+            decimal: ',',
+        };
+        assert_eq!(
+            f64::parse_element("1.234.567,25", &format),
+            Some(1234567.25)
+        );
+        assert_eq!(f64::parse_element("-0,5", &format), Some(-0.5));
+
+        let format = NumberFormat {
+            thousands: Some(' '),
+            decimal: '.',
+        };
+        assert_eq!(f64::parse_element("1 000.5", &format), Some(1000.5));
+    }
+
+    #[test]
+    fn test_parse_integer() {
+        let format = NumberFormat::default();
+        assert_eq!(i32::parse_element(" 42 ", &format), Some(42));
+        assert_eq!(i32::parse_element("+42", &format), Some(42));
+        assert_eq!(i32::parse_element("-42", &format), Some(-42));
+        assert_eq!(i32::parse_element("0x1F", &format), Some(31));
+        assert_eq!(i32::parse_element("-0X1f", &format), Some(-31));
+        assert_eq!(u8::parse_element("0b1010", &format), Some(10));
+        assert_eq!(u16::parse_element("0o17", &format), Some(15));
+        assert_eq!(u8::parse_element("0xFF", &format), Some(255));
+        assert_eq!(u8::parse_element("0x100", &format), None);
+        assert_eq!(u8::parse_element("-0x1", &format), None);
+        assert_eq!(i32::parse_element("0x-1", &format), None);
+        assert_eq!(i32::parse_element("0x", &format), None);
+        assert_eq!(i32::parse_element("1.0", &format), None);
+        assert_eq!(i32::parse_element("1,000", &format), None);
+
+        let format = NumberFormat {
+            thousands: Some(','),
+            decimal: '.',
+        };
+        assert_eq!(i64::parse_element("-1,000,000", &format), Some(-1000000));
+    }
 }
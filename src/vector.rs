@@ -20,6 +20,7 @@
 //! [quickstart tutorial]: ../index.html#quickstart-tutorial
 //!
 
+use crate::printing::{self, PrintOptions, VectorDisplay};
 use num::Num;
 use std::fmt;
 
@@ -30,6 +31,8 @@ pub mod indexing;
 pub mod iterators;
 pub mod loaders;
 pub mod operations;
+#[cfg(feature = "serde")]
+mod serialization;
 pub mod slicing;
 
 /// Numeric vectors.
@@ -90,6 +93,30 @@ where
     pub fn len(&self) -> usize {
         self.data.len()
     }
+
+    /// Format the numeric vector with the given print options instead of
+    /// the [global print options].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let x = vector![1, 2, 3, 4, 5];
+    /// let options = PrintOptions {
+    ///     threshold: 4,
+    ///     edge_items: 1,
+    ///     ..PrintOptions::default()
+    /// };
+    /// assert_eq!(format!("{}", x.display(options)), "[1 ... 5]");
+    /// ```
+    ///
+    /// [global print options]: ../printing/fn.set_print_options.html
+    pub fn display(&self, options: PrintOptions) -> VectorDisplay<'_, T> {
+        VectorDisplay {
+            data: &self.data,
+            options,
+        }
+    }
 }
 
 // Numeric vector comparison
@@ -111,6 +138,16 @@ where
     }
 }
 
+impl<T> fmt::Display for Vector<T>
+where
+    T: Num + Copy + fmt::Display + fmt::LowerExp,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let options = printing::print_options();
+        fmt::Display::fmt(&self.display(options), f)
+    }
+}
+
 impl<T> fmt::Debug for Vector<T>
 where
     T: Num + Copy + fmt::Debug,
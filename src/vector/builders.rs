@@ -68,6 +68,21 @@
 //! assert_eq!(x, vector![1, 1, 1]);
 //! ```
 //!
+//! # Parsing Numeric Vector from String
+//! Numeric vector can be parsed from a string in MATLAB-style or
+//! NumPy-style syntax using [`str::parse`]. The elements are separated by
+//! commas or whitespaces and the brackets are optional:
+//!
+//! ```
+//! # use crabsformer::prelude::*;
+//! let x: Vector<i32> = "1 2 3".parse().unwrap();
+//! let y: Vector<f64> = "[1.0, 2.5]".parse().unwrap();
+//! assert_eq!(x, vector![1, 2, 3]);
+//! assert_eq!(y, vector![1.0, 2.5]);
+//! ```
+//!
+//! [`str::parse`]: https://doc.rust-lang.org/std/primitive.str.html#method.parse
+//!
 //! # Intrinsic Numeric Vector Creation
 //! Crabsformer has built-in functions for creating numeric vectors from
 //! scratch:
@@ -98,7 +113,7 @@
 //!
 //! ```
 //! # use crabsformer::prelude::*;
-//! let a = Vector::linspace(5, 1.0, 10.0);
+//! let a = Vector::linspace(5, 1.0, 10.0, true).unwrap();
 //! // vector![1.0, 3.25, 5.5, 7.75, 10.0]
 //! ```
 //! The advantage of this creation function is that one can guarantee the
@@ -111,39 +126,49 @@
 //!
 //! 1. **Ones and zeros**
 //!     - [`Vector::ones`]: Create a new numeric vector of given length and
-//!         type, filled with ones.
+//!       type, filled with ones.
 //!     - [`Vector::ones_like`]: Create a new numeric vector that have the same
-//!         length and type as given numeric vector, filled with ones.
+//!       length and type as given numeric vector, filled with ones.
 //!     - [`Vector::zeros`]: Create a new numeric vector of given length and
-//!         type, filled with zeros.
+//!       type, filled with zeros.
 //!     - [`Vector::zeros_like`]: Create a new numeric vector that have the same
-//!         length and type as given numeric vector, filled with zeros.
+//!       length and type as given numeric vector, filled with zeros.
 //!     - [`Vector::full`]: Create a new numeric vector of given length and
-//!         type, filled with specified value.
+//!       type, filled with specified value.
 //!     - [`Vector::full_like`]: Create a new numeric vector that have the same
-//!         length and type as given numeric vector, filled with specified
-//!         value.
+//!       length and type as given numeric vector, filled with specified
+//!       value.
 //!
 //! 2. **From existing data**
 //!     - `Vector::from`: Convert array, slice or [`Vec<T>`] to numeric
-//!         vector.
+//!       vector.
 //!     - [`Vector::copy`]: Create a numeric vector copy of the given numeric
-//!         vector.
+//!       vector.
 //!
 //! 3. **Numerical ranges**
 //!     - [`Vector::range`]: Create a new numeric vector of evenly spaced
-//!         values.
+//!       values.
 //!     - [`Vector::linspace`]: Create a new numeric vector of the given length
-//!          and populate it with linearly spaced values.
-//!     - `Vector::logspace` ([#20][issue-20]): Create a new numeric vector of
-//!         the given length and populate it with logarithmically spaced values.
-//!     - `Vector::geomspace` ([#21][issue-21]): Create a new numeric vector of
-//!         the given length and populate it with evenly spaced values on a log
-//!         scale (a geometric progression).
+//!       and populate it with linearly spaced values.
+//!     - [`Vector::logspace`]: Create a new numeric vector of the given
+//!       length and populate it with logarithmically spaced values.
+//!     - [`Vector::geomspace`]: Create a new numeric vector of the given
+//!       length and populate it with evenly spaced values on a log scale
+//!       (a geometric progression).
 //!
-//! - Simple random data
-//! - Permutations
-//! - Distributions
+//! 4. **Simple random data**
+//!     - [`RandomVectorBuilder::uniform`]: Create a new numeric vector of
+//!       given length and populate it with uniformly distributed samples.
+//!
+//! 5. **Permutations**
+//!     - [`RandomVectorBuilder`] provides `choice`, `shuffle` and
+//!       `permutation`.
+//!
+//! 6. **Distributions**
+//!     - [`RandomVectorBuilder`] provides `normal`, `log_normal`,
+//!       `exponential`, `gamma`, `beta`, `chi_squared`, `student_t`,
+//!       `binomial`, `poisson`, `geometric`, `bernoulli` and
+//!       `categorical` samplers.
 //!
 //! [`Vector::copy`]: ../struct.Vector.html#method.copy
 //! [`Vector::zeros`]: ../struct.Vector.html#method.zeros
@@ -154,19 +179,29 @@
 //! [`Vector::full_like`]: ../struct.Vector.html#method.full_like
 //! [`Vector::range`]: ../struct.Vector.html#method.range
 //! [`Vector::linspace`]: ../struct.Vector.html#method.linspace
-//! [issue-20]: https://github.com/pyk/Crabsformer/issues/20
-//! [issue-21]: https://github.com/pyk/Crabsformer/issues/21
+//! [`Vector::logspace`]: ../struct.Vector.html#method.logspace
+//! [`Vector::geomspace`]: ../struct.Vector.html#method.geomspace
+//! [`RandomVectorBuilder`]: struct.RandomVectorBuilder.html
+//! [`RandomVectorBuilder::uniform`]: struct.RandomVectorBuilder.html#method.uniform
 //!
 
-use crate::vector::errors::{VectorBuilderError, VectorBuilderErrorKind};
+use crate::random::{BuilderRng, Generator, RandomState};
+use crate::utils::{self, ParseElement};
+use crate::vector::errors::{
+    VectorBuilderError, VectorBuilderErrorKind, VectorParseError,
+    VectorParseErrorKind,
+};
 use crate::vector::Vector;
 use num::{Float, FromPrimitive, Num};
 use rand::distributions::uniform::SampleUniform;
-use rand::distributions::{Distribution, Normal, Uniform};
-use rand::{FromEntropy, SeedableRng};
-use rand::rngs::SmallRng;
+use rand::distributions::{
+    Bernoulli, Beta, Binomial, ChiSquared, Distribution, Exp, Gamma,
+    LogNormal, Normal, Open01, Poisson, StudentT, Uniform, WeightedIndex,
+};
+use rand::seq::{index, SliceRandom};
 use std::fmt;
 use std::ops;
+use std::str::FromStr;
 
 /// Creates a [numeric vector] containing the arguments.
 ///
@@ -263,6 +298,44 @@ where
     }
 }
 
+// Parse numeric vector from MATLAB-style or NumPy-style string
+impl<T> FromStr for Vector<T>
+where
+    T: Num + Copy + ParseElement,
+{
+    type Err = VectorParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parsed = utils::parse_rows(s)?;
+        if parsed.nested {
+            return Err(VectorParseError::new(
+                VectorParseErrorKind::InvalidShape,
+                String::from(
+                    "expected one-dimensional list, got nested lists",
+                ),
+                parsed.positions[0],
+            ));
+        }
+        let mut rows = parsed.rows;
+        if rows.len() == 1 {
+            return Ok(Vector::from(rows.remove(0)));
+        }
+        // Rows of one element are a column
+        if let Some(i) = rows.iter().position(|row| row.len() != 1) {
+            return Err(VectorParseError::new(
+                VectorParseErrorKind::InvalidShape,
+                format!(
+                    "row {} has {} elements, expected one element per row",
+                    i + 1,
+                    rows[i].len()
+                ),
+                parsed.positions[i],
+            ));
+        }
+        Ok(Vector::from(rows.into_iter().flatten().collect::<Vec<T>>()))
+    }
+}
+
 impl<T> Vector<T>
 where
     T: Num + Copy,
@@ -444,86 +517,201 @@ where
     }
 
     /// Create a new numeric vector of the given length `len` and populate it
-    /// with linearly spaced values within a given closed interval `[start,
-    /// stop]`.
+    /// with linearly spaced values within a given interval `[start, stop]`.
+    ///
+    /// If `endpoint` is `true`, `stop` is the last sample. Otherwise, it is
+    /// not included and values are generated within the half-open interval
+    /// `[start, stop)`.
+    ///
+    /// **Note that**: If `endpoint` is `true` and `len < 2` or if `endpoint`
+    /// is `false` and `len < 1` it will returns an error.
     ///
     /// # Examples
     /// ```
     /// # use crabsformer::prelude::*;
-    /// let a = Vector::linspace(5, 1.0, 10.0);
+    /// let a = Vector::linspace(5, 1.0, 10.0, true).unwrap();
+    /// assert_eq!(a, vector![1.0, 3.25, 5.5, 7.75, 10.0]);
+    ///
+    /// let b = Vector::linspace(4, 1.0, 3.0, false).unwrap();
+    /// assert_eq!(b, vector![1.0, 1.5, 2.0, 2.5]);
     /// ```
-    pub fn linspace(len: usize, start: T, stop: T) -> Vector<T>
+    pub fn linspace(
+        len: usize,
+        start: T,
+        stop: T,
+        endpoint: bool,
+    ) -> Result<Vector<T>, VectorBuilderError>
     where
         T: Float,
     {
-        // Convert len to float type
-        let divisor = T::from_usize(len).unwrap();
+        // Both start and stop should be present if endpoint is included
+        let min_len = if endpoint { 2 } else { 1 };
+        if len < min_len {
+            return Err(VectorBuilderError::new(
+                VectorBuilderErrorKind::InvalidLength,
+                format!("len={} should be at least {}", len, min_len),
+            ));
+        }
+
+        let divisor = if endpoint {
+            T::from_usize(len - 1).unwrap()
+        } else {
+            T::from_usize(len).unwrap()
+        };
+        let step = (stop - start) / divisor;
+
+        // Compute each element from start to avoid accumulating rounding
+        // errors of the step value
         let mut elements = Vec::with_capacity(len);
-        let mut current_step = start;
-        let step = (stop - start) / (divisor - T::from_f32(1.0).unwrap());
-        while current_step < stop {
-            elements.push(current_step);
-            current_step += step;
+        for i in 0..len {
+            elements.push(start + T::from_usize(i).unwrap() * step);
         }
 
-        // Include the `stop` value in the generated sequences
-        if elements.len() == len {
+        // Include the exact `stop` value in the generated sequences
+        if endpoint {
             elements[len - 1] = stop;
-        } else {
-            elements.push(stop);
         }
 
-        Vector { data: elements }
+        Ok(Vector::from(elements))
     }
 
     /// Create a new numeric vector of the given length `len` and populate it
-    /// with logarithmically spaced values within a given closed interval
-    /// `[10^a, 10^b]`.
+    /// with logarithmically spaced values within a given interval
+    /// `[base^start, base^stop]`.
+    ///
+    /// If `endpoint` is `true`, `base^stop` is the last sample. Otherwise, it
+    /// is not included.
     ///
     /// The `Vector::logspace` function is especially useful for creating
     /// frequency numeric vectors.
     ///
+    /// **Note that**: It will returns an error for the same `len` values as
+    /// [`Vector::linspace`].
+    ///
     /// # Examples
     ///
     /// ```
     /// # use crabsformer::prelude::*;
-    /// // TODO(pyk): Uncomment this if the function is already implemented
-    /// // let a = Vector::logspace(5, 2.0, 3.0);
+    /// let a = Vector::logspace(3, 0.0, 2.0, 10.0, true).unwrap();
+    /// assert_eq!(a, vector![1.0, 10.0, 100.0]);
+    ///
+    /// let b = Vector::logspace(4, 0.0, 4.0, 2.0, false).unwrap();
+    /// assert_eq!(b, vector![1.0, 2.0, 4.0, 8.0]);
     /// ```
-    pub fn logspace(_len: usize, _a: T, _b: T) -> Vector<T>
+    ///
+    /// [`Vector::linspace`]: #method.linspace
+    pub fn logspace(
+        len: usize,
+        start: T,
+        stop: T,
+        base: T,
+        endpoint: bool,
+    ) -> Result<Vector<T>, VectorBuilderError>
     where
         T: Float,
     {
-        unimplemented!();
+        let exponents = Vector::linspace(len, start, stop, endpoint)?;
+        let elements = exponents.data.iter().map(|x| base.powf(*x)).collect();
+        Ok(Vector { data: elements })
     }
 
     /// Create a new numeric vector of the given length `len` and populate it
-    /// with logarithmically spaced values within a given closed interval
-    /// `[start, end]`.
+    /// with logarithmically spaced values within a given interval
+    /// `[start, stop]`.
     ///
-    /// This is similar to `Vector::logspace`, but with endpoints specified
+    /// This is similar to [`Vector::logspace`], but with endpoints specified
     /// directly. Each output sample is a constant multiple of the previous.
+    /// If `endpoint` is `true`, `stop` is the last sample. Otherwise, it is
+    /// not included.
+    ///
+    /// Negative values are supported as long as `start` and `stop` have the
+    /// same sign; the sequence is generated from their absolute values and
+    /// the sign is restored afterwards.
+    ///
+    /// **Note that**:
+    /// 1. If `start` or `stop` is zero it will returns an error.
+    /// 2. If `start` and `stop` have different sign it will returns an error.
+    /// 3. It will returns an error for the same `len` values as
+    ///    [`Vector::linspace`].
     ///
     /// # Examples
     ///
     /// ```
     /// # use crabsformer::prelude::*;
-    /// // TODO(pyk): Uncomment this if the function is already implemented
-    /// // let a = Vector::geomspace(5, 100.0, 1000.0);
-    /// // similar to:
-    /// // let b = Vector::logspace(5, 2.0, 3.0);
+    /// let a: Vector<f64> = Vector::geomspace(3, 1.0, 100.0, true).unwrap();
+    /// assert_eq!(a[0], 1.0);
+    /// assert!((a[1] - 10.0).abs() < 1e-12);
+    /// assert_eq!(a[2], 100.0);
+    ///
+    /// let b = Vector::geomspace(2, -1.0, -100.0, true).unwrap();
+    /// assert_eq!(b, vector![-1.0, -100.0]);
     /// ```
-    pub fn geomspace(_len: usize, _start: T, _end: T) -> Vector<T>
+    ///
+    /// [`Vector::logspace`]: #method.logspace
+    /// [`Vector::linspace`]: #method.linspace
+    pub fn geomspace(
+        len: usize,
+        start: T,
+        stop: T,
+        endpoint: bool,
+    ) -> Result<Vector<T>, VectorBuilderError>
     where
-        T: Float,
+        T: Float + fmt::Display,
     {
-        unimplemented!();
+        let zero = T::zero();
+        // Geometric sequence cannot include zero
+        if start == zero || stop == zero {
+            return Err(VectorBuilderError::new(
+                VectorBuilderErrorKind::InvalidRange,
+                format!(
+                    "start={} and stop={} should not equal to zero",
+                    start, stop
+                ),
+            ));
+        }
+        // The sequence cannot cross zero either
+        if start.is_sign_negative() != stop.is_sign_negative() {
+            return Err(VectorBuilderError::new(
+                VectorBuilderErrorKind::InvalidRange,
+                format!(
+                    "start={} and stop={} should have the same sign",
+                    start, stop
+                ),
+            ));
+        }
+
+        // Generate the sequence on the absolute values and restore the
+        // sign later
+        let sign = start.signum();
+        let exponents = Vector::linspace(
+            len,
+            start.abs().ln(),
+            stop.abs().ln(),
+            endpoint,
+        )?;
+        let mut elements: Vec<T> =
+            exponents.data.iter().map(|x| sign * x.exp()).collect();
+
+        // Make sure the endpoints match the input exactly
+        elements[0] = start;
+        if endpoint {
+            elements[len - 1] = stop;
+        }
+
+        Ok(Vector::from(elements))
     }
 }
 
 /// Random numeric vectors builder.
+///
+/// The samples are drawn from one of the generators listed in
+/// [`Generator`], by default [`Xoshiro256StarStar`], so a seeded builder
+/// produces the same samples on every platform.
+///
+/// [`Generator`]: ../../random/enum.Generator.html
+/// [`Xoshiro256StarStar`]: ../../random/struct.Xoshiro256StarStar.html
 pub struct RandomVectorBuilder {
-    rng: SmallRng,
+    rng: BuilderRng,
 }
 
 impl RandomVectorBuilder {
@@ -535,10 +723,35 @@ impl RandomVectorBuilder {
     /// let rvb = RandomVectorBuilder::new();
     /// ```
     pub fn new() -> Self {
-        let rng = SmallRng::from_entropy();
+        RandomVectorBuilder::with_generator(Generator::default())
+    }
+
+    /// Create a new random numeric vector builder that draws the samples
+    /// from the given pseudo-random number generator.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let rvb = RandomVectorBuilder::with_generator(Generator::Xoshiro256PlusPlus);
+    /// ```
+    pub fn with_generator(generator: Generator) -> Self {
+        let rng = BuilderRng::from_entropy(generator);
         RandomVectorBuilder { rng }
     }
 
+    /// Returns the pseudo-random number generator of random numeric vector
+    /// builder.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let rvb = RandomVectorBuilder::new();
+    /// assert_eq!(rvb.generator(), Generator::Xoshiro256StarStar);
+    /// ```
+    pub fn generator(&self) -> Generator {
+        self.rng.generator()
+    }
+
     /// Set the seed of random numeric vector builder.
     ///
     /// # Examples
@@ -548,7 +761,79 @@ impl RandomVectorBuilder {
     /// rvb.seed(12);
     /// ```
     pub fn seed(&mut self, value: u64) {
-        self.rng = SmallRng::seed_from_u64(value);
+        self.rng = BuilderRng::seed_from_u64(self.rng.generator(), value);
+    }
+
+    /// Returns the current state of random numeric vector builder.
+    ///
+    /// The state can be restored later using [`set_state`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rvb = RandomVectorBuilder::new();
+    /// let state = rvb.state();
+    /// let x = rvb.uniform(5, 0.0, 1.0).unwrap();
+    /// rvb.set_state(&state);
+    /// let y = rvb.uniform(5, 0.0, 1.0).unwrap();
+    /// assert_eq!(x, y);
+    /// ```
+    ///
+    /// [`set_state`]: #method.set_state
+    pub fn state(&self) -> RandomState {
+        self.rng.state()
+    }
+
+    /// Restore the state of random numeric vector builder. The state does
+    /// not record the generator, so it should be restored to a builder
+    /// that uses the same generator.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rvb1 = RandomVectorBuilder::new();
+    /// let mut rvb2 = RandomVectorBuilder::new();
+    /// rvb2.set_state(&rvb1.state());
+    /// assert_eq!(rvb1.permutation(5), rvb2.permutation(5));
+    /// ```
+    pub fn set_state(&mut self, state: &RandomState) {
+        self.rng = BuilderRng::from_state(self.rng.generator(), state);
+    }
+
+    /// Advance the state of random numeric vector builder by
+    /// 2<sup>128</sup> steps.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rvb = RandomVectorBuilder::new();
+    /// rvb.seed(12);
+    /// rvb.jump();
+    /// ```
+    pub fn jump(&mut self) {
+        self.rng.jump();
+    }
+
+    /// Split off an independent random numeric vector builder.
+    ///
+    /// The returned builder continues from the current state, while this
+    /// builder jumps ahead by 2<sup>128</sup> steps, so their streams do not
+    /// overlap. It can be used to give each parallel task its own
+    /// reproducible stream.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rvb = RandomVectorBuilder::new();
+    /// rvb.seed(12);
+    /// let mut workers: Vec<RandomVectorBuilder> =
+    ///     (0..4).map(|_| rvb.split()).collect();
+    /// let v = workers[0].uniform(5, 0.0, 1.0).unwrap();
+    /// ```
+    pub fn split(&mut self) -> RandomVectorBuilder {
+        let rng = self.rng.clone();
+        self.rng.jump();
+        RandomVectorBuilder { rng }
     }
 
     /// Create a new numeric vector of the given length `len` and populate it
@@ -589,10 +874,26 @@ impl RandomVectorBuilder {
         Ok(Vector::from(elements))
     }
 
+    // Create a new numeric vector of the given length `len` and populate it
+    // with samples of `distribution` converted to the float type `T`.
+    fn sample_float<T, D>(&mut self, len: usize, distribution: D) -> Vector<T>
+    where
+        T: Float + FromPrimitive,
+        D: Distribution<f64>,
+    {
+        let mut elements = Vec::with_capacity(len);
+        for _ in 0..len {
+            let value = distribution.sample(&mut self.rng);
+            elements.push(T::from_f64(value).unwrap());
+        }
+        Vector::from(elements)
+    }
+
     /// Create a new numeric vector of the given length `len` and populate it
     /// with random samples from a normal distribution `N(mean, std_dev**2)`.
     ///
-    /// **Note that**: If `std_dev < 0` it will returns an error.
+    /// **Note that**: If `std_dev < 0` or either `mean` or `std_dev` is not
+    /// finite it will returns an error.
     ///
     /// # Examples
     /// ```
@@ -601,24 +902,557 @@ impl RandomVectorBuilder {
     /// // Gaussian mean=0.0 std_dev=1.0
     /// let v = rvb.normal(5, 0.0, 1.0).unwrap();
     /// ```
-    pub fn normal(
+    pub fn normal<T>(
+        &mut self,
+        len: usize,
+        mean: T,
+        std_dev: T,
+    ) -> Result<Vector<T>, VectorBuilderError>
+    where
+        T: Float + FromPrimitive + fmt::Display,
+    {
+        check_normal(mean, std_dev)?;
+        let normal_distribution =
+            Normal::new(mean.to_f64().unwrap(), std_dev.to_f64().unwrap());
+        Ok(self.sample_float(len, normal_distribution))
+    }
+
+    /// Create a new numeric vector of the given length `len` and populate it
+    /// with random samples from a log-normal distribution. The logarithm of
+    /// the samples is normally distributed with mean `mean` and standard
+    /// deviation `std_dev`.
+    ///
+    /// **Note that**: If `std_dev < 0` or either `mean` or `std_dev` is not
+    /// finite it will returns an error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rvb = RandomVectorBuilder::new();
+    /// let v = rvb.log_normal(5, 0.0, 1.0).unwrap();
+    /// ```
+    pub fn log_normal<T>(
+        &mut self,
+        len: usize,
+        mean: T,
+        std_dev: T,
+    ) -> Result<Vector<T>, VectorBuilderError>
+    where
+        T: Float + FromPrimitive + fmt::Display,
+    {
+        check_normal(mean, std_dev)?;
+        let log_normal_distribution =
+            LogNormal::new(mean.to_f64().unwrap(), std_dev.to_f64().unwrap());
+        Ok(self.sample_float(len, log_normal_distribution))
+    }
+
+    /// Create a new numeric vector of the given length `len` and populate it
+    /// with random samples from an exponential distribution with rate
+    /// `lambda`.
+    ///
+    /// **Note that**: If `lambda <= 0` it will returns an error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rvb = RandomVectorBuilder::new();
+    /// let v = rvb.exponential(5, 1.5).unwrap();
+    /// ```
+    pub fn exponential<T>(
         &mut self,
         len: usize,
-        mean: f64,
-        std_dev: f64,
-    ) -> Result<Vector<f64>, VectorBuilderError> {
-        if std_dev < 0.0 {
+        lambda: T,
+    ) -> Result<Vector<T>, VectorBuilderError>
+    where
+        T: Float + FromPrimitive + fmt::Display,
+    {
+        check_positive("lambda", lambda)?;
+        let exp_distribution = Exp::new(lambda.to_f64().unwrap());
+        Ok(self.sample_float(len, exp_distribution))
+    }
+
+    /// Create a new numeric vector of the given length `len` and populate it
+    /// with random samples from a gamma distribution with shape `k` and scale
+    /// `theta`.
+    ///
+    /// **Note that**: If `k <= 0` or `theta <= 0` it will returns an error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rvb = RandomVectorBuilder::new();
+    /// let v = rvb.gamma(5, 2.0, 1.0).unwrap();
+    /// ```
+    pub fn gamma<T>(
+        &mut self,
+        len: usize,
+        k: T,
+        theta: T,
+    ) -> Result<Vector<T>, VectorBuilderError>
+    where
+        T: Float + FromPrimitive + fmt::Display,
+    {
+        check_positive("k", k)?;
+        check_positive("theta", theta)?;
+        let gamma_distribution =
+            Gamma::new(k.to_f64().unwrap(), theta.to_f64().unwrap());
+        Ok(self.sample_float(len, gamma_distribution))
+    }
+
+    /// Create a new numeric vector of the given length `len` and populate it
+    /// with random samples from a beta distribution with shape parameters
+    /// `alpha` and `beta`. The samples are within the interval `[0, 1]`.
+    ///
+    /// **Note that**: If `alpha <= 0` or `beta <= 0` it will returns an
+    /// error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rvb = RandomVectorBuilder::new();
+    /// let v = rvb.beta(5, 2.0, 5.0).unwrap();
+    /// ```
+    pub fn beta<T>(
+        &mut self,
+        len: usize,
+        alpha: T,
+        beta: T,
+    ) -> Result<Vector<T>, VectorBuilderError>
+    where
+        T: Float + FromPrimitive + fmt::Display,
+    {
+        check_positive("alpha", alpha)?;
+        check_positive("beta", beta)?;
+        let beta_distribution =
+            Beta::new(alpha.to_f64().unwrap(), beta.to_f64().unwrap());
+        Ok(self.sample_float(len, beta_distribution))
+    }
+
+    /// Create a new numeric vector of the given length `len` and populate it
+    /// with random samples from a chi-squared distribution with `k` degrees
+    /// of freedom.
+    ///
+    /// **Note that**: If `k <= 0` it will returns an error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rvb = RandomVectorBuilder::new();
+    /// let v = rvb.chi_squared(5, 3.0).unwrap();
+    /// ```
+    pub fn chi_squared<T>(
+        &mut self,
+        len: usize,
+        k: T,
+    ) -> Result<Vector<T>, VectorBuilderError>
+    where
+        T: Float + FromPrimitive + fmt::Display,
+    {
+        check_positive("k", k)?;
+        let chi_squared_distribution = ChiSquared::new(k.to_f64().unwrap());
+        Ok(self.sample_float(len, chi_squared_distribution))
+    }
+
+    /// Create a new numeric vector of the given length `len` and populate it
+    /// with random samples from a Student's t distribution with `dof`
+    /// degrees of freedom.
+    ///
+    /// **Note that**: If `dof <= 0` it will returns an error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rvb = RandomVectorBuilder::new();
+    /// let v = rvb.student_t(5, 10.0).unwrap();
+    /// ```
+    pub fn student_t<T>(
+        &mut self,
+        len: usize,
+        dof: T,
+    ) -> Result<Vector<T>, VectorBuilderError>
+    where
+        T: Float + FromPrimitive + fmt::Display,
+    {
+        check_positive("dof", dof)?;
+        let student_t_distribution = StudentT::new(dof.to_f64().unwrap());
+        Ok(self.sample_float(len, student_t_distribution))
+    }
+
+    /// Create a new numeric vector of the given length `len` and populate it
+    /// with random samples from a binomial distribution. Each sample is the
+    /// number of successes in `n` trials with probability of success `p`.
+    ///
+    /// **Note that**: If `p < 0` or `p > 1` it will returns an error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rvb = RandomVectorBuilder::new();
+    /// let v = rvb.binomial(5, 10, 0.5).unwrap();
+    /// ```
+    pub fn binomial(
+        &mut self,
+        len: usize,
+        n: u64,
+        p: f64,
+    ) -> Result<Vector<u64>, VectorBuilderError> {
+        check_probability(p)?;
+        let mut elements = Vec::with_capacity(len);
+        let binomial_distribution = Binomial::new(n, p);
+        for _ in 0..len {
+            elements.push(binomial_distribution.sample(&mut self.rng));
+        }
+        Ok(Vector::from(elements))
+    }
+
+    /// Create a new numeric vector of the given length `len` and populate it
+    /// with random samples from a Poisson distribution with expected number
+    /// of events `lambda`.
+    ///
+    /// **Note that**: If `lambda <= 0` it will returns an error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rvb = RandomVectorBuilder::new();
+    /// let v = rvb.poisson(5, 4.0).unwrap();
+    /// ```
+    pub fn poisson(
+        &mut self,
+        len: usize,
+        lambda: f64,
+    ) -> Result<Vector<u64>, VectorBuilderError> {
+        check_positive("lambda", lambda)?;
+        let mut elements = Vec::with_capacity(len);
+        let poisson_distribution = Poisson::new(lambda);
+        for _ in 0..len {
+            elements.push(poisson_distribution.sample(&mut self.rng));
+        }
+        Ok(Vector::from(elements))
+    }
+
+    /// Create a new numeric vector of the given length `len` and populate it
+    /// with random samples from a geometric distribution. Each sample is the
+    /// number of trials until the first success, where each trial has
+    /// probability of success `p`. The samples are greater than or equal to
+    /// one.
+    ///
+    /// **Note that**: If `p <= 0` or `p > 1` it will returns an error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rvb = RandomVectorBuilder::new();
+    /// let v = rvb.geometric(5, 0.25).unwrap();
+    /// ```
+    pub fn geometric(
+        &mut self,
+        len: usize,
+        p: f64,
+    ) -> Result<Vector<u64>, VectorBuilderError> {
+        check_probability(p)?;
+        if p == 0.0 {
             return Err(VectorBuilderError::new(
-                VectorBuilderErrorKind::NegativeStandardDeviation,
-                format!("{}", std_dev),
+                VectorBuilderErrorKind::InvalidProbability,
+                "p=0 should be greater than zero".to_string(),
             ));
         }
         let mut elements = Vec::with_capacity(len);
-        let normal_distribution = Normal::new(mean, std_dev);
+        // Sample using the inverse of the cumulative distribution function,
+        // `ln_1p` keeps `ln(1 - p)` accurate when `p` is tiny
+        let log_q = (-p).ln_1p();
         for _ in 0..len {
-            elements.push(normal_distribution.sample(&mut self.rng));
+            if p == 1.0 {
+                elements.push(1);
+                continue;
+            }
+            let u: f64 = Open01.sample(&mut self.rng);
+            elements.push((u.ln() / log_q).floor() as u64 + 1);
         }
+        Ok(Vector::from(elements))
+    }
 
+    /// Create a new numeric vector of the given length `len` and populate it
+    /// with random samples from a Bernoulli distribution. Each element is
+    /// one with probability `p` and zero otherwise. You need to explicitly
+    /// annotate the numeric type.
+    ///
+    /// **Note that**: If `p < 0` or `p > 1` it will returns an error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rvb = RandomVectorBuilder::new();
+    /// let v: Vector<u8> = rvb.bernoulli(5, 0.3).unwrap();
+    /// ```
+    pub fn bernoulli<T>(
+        &mut self,
+        len: usize,
+        p: f64,
+    ) -> Result<Vector<T>, VectorBuilderError>
+    where
+        T: Num + Copy,
+    {
+        check_probability(p)?;
+        let mut elements = Vec::with_capacity(len);
+        let bernoulli_distribution = Bernoulli::new(p);
+        for _ in 0..len {
+            if bernoulli_distribution.sample(&mut self.rng) {
+                elements.push(T::one());
+            } else {
+                elements.push(T::zero());
+            }
+        }
+        Ok(Vector::from(elements))
+    }
+
+    /// Create a new numeric vector of the given length `len` and populate it
+    /// with random samples from a categorical distribution. Each sample is
+    /// an index `i` of `weights`, drawn with probability proportional to
+    /// `weights[i]`.
+    ///
+    /// **Note that**: If `weights` is empty, contains a negative value or
+    /// sums to zero it will returns an error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rvb = RandomVectorBuilder::new();
+    /// let weights = vector![0.2, 0.5, 0.3];
+    /// let v = rvb.categorical(5, &weights).unwrap();
+    /// ```
+    pub fn categorical(
+        &mut self,
+        len: usize,
+        weights: &Vector<f64>,
+    ) -> Result<Vector<usize>, VectorBuilderError> {
+        let weighted_distribution = weighted_index(weights)?;
+        let mut elements = Vec::with_capacity(len);
+        for _ in 0..len {
+            elements.push(weighted_distribution.sample(&mut self.rng));
+        }
         Ok(Vector::from(elements))
     }
+
+    /// Create a new numeric vector of the given length `len` by sampling
+    /// the elements of numeric vector `v`.
+    ///
+    /// If `replace` is `true`, the same element can be selected more than
+    /// once. If `weights` is given, each element `v[i]` is selected with
+    /// probability proportional to `weights[i]`; otherwise all elements
+    /// have the same probability.
+    ///
+    /// **Note that**:
+    /// 1. If `v` is empty and `len > 0` it will returns an error.
+    /// 2. If `replace` is `false` and `len > v.len()` it will returns an
+    ///    error.
+    /// 3. If `weights` length is not the same as `v` length, contains a
+    ///    negative value or does not have enough non-zero values it will
+    ///    returns an error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rvb = RandomVectorBuilder::new();
+    /// let x = vector![3, 1, 4, 1, 5];
+    ///
+    /// // Sample with replacement
+    /// let a = rvb.choice(&x, 10, true, None).unwrap();
+    ///
+    /// // Sample without replacement
+    /// let b = rvb.choice(&x, 3, false, None).unwrap();
+    ///
+    /// // Weighted sample
+    /// let weights = vector![0.1, 0.1, 0.6, 0.1, 0.1];
+    /// let c = rvb.choice(&x, 3, true, Some(&weights)).unwrap();
+    /// ```
+    pub fn choice<T>(
+        &mut self,
+        v: &Vector<T>,
+        len: usize,
+        replace: bool,
+        weights: Option<&Vector<f64>>,
+    ) -> Result<Vector<T>, VectorBuilderError>
+    where
+        T: Num + Copy,
+    {
+        if v.len() == 0 && len > 0 {
+            return Err(VectorBuilderError::new(
+                VectorBuilderErrorKind::InvalidLength,
+                "cannot take a sample from an empty numeric vector"
+                    .to_string(),
+            ));
+        }
+        if !replace && len > v.len() {
+            return Err(VectorBuilderError::new(
+                VectorBuilderErrorKind::InvalidLength,
+                format!(
+                    "len={} should not be greater than {} when sampling \
+                     without replacement",
+                    len,
+                    v.len()
+                ),
+            ));
+        }
+        if len == 0 {
+            return Ok(Vector::from(Vec::new()));
+        }
+
+        let indices = match weights {
+            None if replace => {
+                let uniform_distribution = Uniform::new(0, v.len());
+                (0..len)
+                    .map(|_| uniform_distribution.sample(&mut self.rng))
+                    .collect()
+            }
+            None => index::sample(&mut self.rng, v.len(), len).into_vec(),
+            Some(weights) => {
+                if weights.len() != v.len() {
+                    return Err(VectorBuilderError::new(
+                        VectorBuilderErrorKind::InvalidWeights,
+                        format!(
+                            "weights length {} should be equal to {}",
+                            weights.len(),
+                            v.len()
+                        ),
+                    ));
+                }
+                let mut remaining = weights.clone();
+                let mut weighted_distribution = weighted_index(&remaining)?;
+                let mut indices = Vec::with_capacity(len);
+                for _ in 0..len {
+                    let i = weighted_distribution.sample(&mut self.rng);
+                    indices.push(i);
+                    // Remove the selected element from the next draws
+                    if !replace && indices.len() < len {
+                        remaining[i] = 0.0;
+                        weighted_distribution = weighted_index(&remaining)?;
+                    }
+                }
+                indices
+            }
+        };
+
+        Ok(indices.iter().map(|i| v[*i]).collect())
+    }
+
+    /// Shuffle the elements of numeric vector `v` in place.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rvb = RandomVectorBuilder::new();
+    /// let mut x = vector![3, 1, 4, 1, 5];
+    /// rvb.shuffle(&mut x);
+    /// ```
+    pub fn shuffle<T>(&mut self, v: &mut Vector<T>)
+    where
+        T: Num + Copy,
+    {
+        v.data.shuffle(&mut self.rng);
+    }
+
+    /// Create a new numeric vector containing a random permutation of
+    /// `0..len`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rvb = RandomVectorBuilder::new();
+    /// let v = rvb.permutation(5); // e.g. vector![3, 0, 4, 1, 2]
+    /// ```
+    pub fn permutation(&mut self, len: usize) -> Vector<usize> {
+        let mut v = Vector::from((0..len).collect::<Vec<usize>>());
+        self.shuffle(&mut v);
+        v
+    }
+}
+
+// Returns an error if the distribution parameter `value` is not positive.
+fn check_positive<T>(name: &str, value: T) -> Result<(), VectorBuilderError>
+where
+    T: Float + fmt::Display,
+{
+    // NaN is not positive either
+    if value.is_nan() || value <= T::zero() {
+        return Err(VectorBuilderError::new(
+            VectorBuilderErrorKind::NonPositiveParameter,
+            format!("{}={} should be greater than zero", name, value),
+        ));
+    }
+    check_finite(name, value)
+}
+
+// Returns an error if `value` is NaN or infinite.
+fn check_finite<T>(name: &str, value: T) -> Result<(), VectorBuilderError>
+where
+    T: Float + fmt::Display,
+{
+    if !value.is_finite() {
+        return Err(VectorBuilderError::new(
+            VectorBuilderErrorKind::NonFiniteParameter,
+            format!("{}={} should be finite", name, value),
+        ));
+    }
+    Ok(())
+}
+
+// Returns an error if the normal distribution parameters are invalid.
+fn check_normal<T>(mean: T, std_dev: T) -> Result<(), VectorBuilderError>
+where
+    T: Float + fmt::Display,
+{
+    check_finite("mean", mean)?;
+    // NaN is not non-negative either
+    if std_dev.is_nan() || std_dev < T::zero() {
+        return Err(VectorBuilderError::new(
+            VectorBuilderErrorKind::NegativeStandardDeviation,
+            format!("{}", std_dev),
+        ));
+    }
+    check_finite("std_dev", std_dev)
+}
+
+// Returns an error if the probability `p` is not within `[0, 1]`.
+fn check_probability(p: f64) -> Result<(), VectorBuilderError> {
+    if !(0.0..=1.0).contains(&p) {
+        return Err(VectorBuilderError::new(
+            VectorBuilderErrorKind::InvalidProbability,
+            format!("p={} should be within [0, 1]", p),
+        ));
+    }
+    Ok(())
+}
+
+// Create a weighted index distribution from `weights`.
+fn weighted_index(
+    weights: &Vector<f64>,
+) -> Result<WeightedIndex<f64>, VectorBuilderError> {
+    if weights.len() == 0 {
+        return Err(VectorBuilderError::new(
+            VectorBuilderErrorKind::InvalidWeights,
+            "weights should not be empty".to_string(),
+        ));
+    }
+    if let Some(w) = weights.data.iter().find(|w| w.is_nan() || **w < 0.0) {
+        return Err(VectorBuilderError::new(
+            VectorBuilderErrorKind::InvalidWeights,
+            format!("weight={} should not be negative", w),
+        ));
+    }
+    // Infinite weights or an overflowing sum would make `WeightedIndex` panic
+    let sum = weights.data.iter().fold(0.0, |sum, w| sum + w);
+    if !sum.is_finite() {
+        return Err(VectorBuilderError::new(
+            VectorBuilderErrorKind::InvalidWeights,
+            format!("the sum of weights={} should be finite", sum),
+        ));
+    }
+    match WeightedIndex::new(&weights.data) {
+        Ok(distribution) => Ok(distribution),
+        Err(_err) => Err(VectorBuilderError::new(
+            VectorBuilderErrorKind::InvalidWeights,
+            "the sum of weights should be greater than zero".to_string(),
+        )),
+    }
 }
@@ -14,9 +14,12 @@
 
 //! Numeric vector errors.
 //!
-//! An error which can be returned when creating, operating, loading and
-//! indexing numeric vectors.
+//! An error which can be returned when creating, operating, loading,
+//! parsing and indexing numeric vectors.
+use csv;
+use std::convert;
 use std::fmt;
+use std::io;
 
 /// Enum to store the various types of errors that can cause creating a numeric
 /// vector to fail.
@@ -33,11 +36,51 @@ pub enum VectorBuilderErrorKind {
     /// new vector with invalid range, for example `low >= high`.
     InvalidRange,
 
+    /// Invalid length for numeric vectors input.
+    ///
+    /// Among other causes, this variant will be constructed when creating
+    /// new numeric vector linear space with not enough elements to include
+    /// its endpoints, for example `len < 2`.
+    InvalidLength,
+
     /// Standard deviation value should not be negative.
     ///
     /// Among other causes, this variant will be constructed when creating
     /// new random numeric vector using normal distribution with `std_dev < 0`.
     NegativeStandardDeviation,
+
+    /// Distribution parameter should be positive.
+    ///
+    /// Among other causes, this variant will be constructed when creating
+    /// new random numeric vector using exponential distribution with
+    /// `lambda <= 0`.
+    NonPositiveParameter,
+
+    /// Distribution parameter should be finite.
+    ///
+    /// Among other causes, this variant will be constructed when creating
+    /// new random numeric vector using normal distribution with NaN or
+    /// infinite `mean`.
+    NonFiniteParameter,
+
+    /// Probability should be within the closed interval `[0, 1]`.
+    ///
+    /// Among other causes, this variant will be constructed when creating
+    /// new random numeric vector using binomial distribution with `p > 1`.
+    InvalidProbability,
+
+    /// Invalid weights for categorical distribution.
+    ///
+    /// Among other causes, this variant will be constructed when creating
+    /// new random numeric vector using categorical distribution with
+    /// negative weights.
+    InvalidWeights,
+
+    /// Invalid pseudo-random number generator state.
+    ///
+    /// Among other causes, this variant will be constructed when restoring
+    /// random numeric vector builder state from bytes that are all zeros.
+    InvalidState,
 }
 
 /// An error which can be returned when creating new numeric vectors.
@@ -65,11 +108,34 @@ impl VectorBuilderError {
             VectorBuilderErrorKind::InvalidStepValue => {
                 format!("Vector builder invalid step value: {}", self.message)
             }
+            VectorBuilderErrorKind::InvalidLength => {
+                format!("Vector builder invalid length: {}", self.message)
+            }
             VectorBuilderErrorKind::NegativeStandardDeviation => format!(
                 "Random vector builder standard deviation should not \
                  be negative: {}",
                 self.message
             ),
+            VectorBuilderErrorKind::NonPositiveParameter => format!(
+                "Random vector builder invalid parameter: {}",
+                self.message
+            ),
+            VectorBuilderErrorKind::NonFiniteParameter => format!(
+                "Random vector builder parameter should be finite: {}",
+                self.message
+            ),
+            VectorBuilderErrorKind::InvalidProbability => format!(
+                "Random vector builder invalid probability: {}",
+                self.message
+            ),
+            VectorBuilderErrorKind::InvalidWeights => format!(
+                "Random vector builder invalid weights: {}",
+                self.message
+            ),
+            VectorBuilderErrorKind::InvalidState => format!(
+                "Random vector builder invalid state: {}",
+                self.message
+            ),
         }
     }
 }
@@ -85,3 +151,250 @@ impl fmt::Display for VectorBuilderError {
         write!(f, "{}", self.description())
     }
 }
+
+/// Enum to store the various types of errors that can cause loading a numeric
+/// vector to fail.
+pub enum VectorLoadErrorKind {
+    /// I/O Error
+    ///
+    /// Among other causes, this variant will be constructed when failed reading or
+    /// writing a file due to I/O problem.
+    IOError,
+    /// CSV Error
+    ///
+    /// Among other causes, this variant will be constructed when failed loading a CSV file.
+    CSVError,
+    /// File being loaded is empty.
+    ///
+    /// Among other causes, this variant will be constructed when loading an empty file.
+    Empty,
+    /// Contains an invalid element.
+    ///
+    /// Among other causes, this variant will be constructed when parsing a string that
+    /// contains non-numeric letter.
+    InvalidElement,
+    /// Selected column does not exist.
+    ///
+    /// Among other causes, this variant will be constructed when loading a CSV file
+    /// with a header name that is not found in the header row.
+    InvalidColumn,
+    /// File is not in the expected format.
+    ///
+    /// Among other causes, this variant will be constructed when loading a NPY file
+    /// with an invalid header.
+    InvalidFormat,
+    /// Element type of the file does not match the numeric type.
+    ///
+    /// Among other causes, this variant will be constructed when loading a NPY file
+    /// that contains `f64` elements as `Vector<i32>`.
+    InvalidDataType,
+    /// Shape of the array in the file is invalid.
+    ///
+    /// Among other causes, this variant will be constructed when loading a NPY file
+    /// that contains two-dimensional array as numeric vector.
+    InvalidShape,
+    /// Selected array does not exist.
+    ///
+    /// Among other causes, this variant will be constructed when loading an array
+    /// that is not found in a NPZ archive.
+    InvalidName,
+}
+
+/// An error which can be returned when loading numeric vector from a file.
+///
+/// # Potential causes
+/// Among other causes, `VectorLoadError` can be thrown because of loaded file is not
+/// exists.
+pub struct VectorLoadError {
+    pub(crate) kind: VectorLoadErrorKind,
+    pub(crate) message: String,
+}
+
+impl VectorLoadError {
+    /// Creates a new `VectorLoadError` from a known kind of error as well as an error
+    /// message.
+    pub fn new(
+        kind: VectorLoadErrorKind,
+        message: String,
+    ) -> VectorLoadError {
+        VectorLoadError { kind, message }
+    }
+
+    /// Outputs the detailed cause of loading file failing.
+    pub fn kind(&self) -> &VectorLoadErrorKind {
+        &self.kind
+    }
+
+    fn description(&self) -> String {
+        match self.kind {
+            VectorLoadErrorKind::IOError => format!(
+                "Cannot read or write Vector file due to: {}",
+                self.message
+            ),
+            VectorLoadErrorKind::CSVError => {
+                format!("Cannot load Vector, {}", self.message)
+            }
+            VectorLoadErrorKind::Empty => {
+                "Cannot load Vector from empty file".to_string()
+            }
+            VectorLoadErrorKind::InvalidElement => format!(
+                "Cannot load Vector, invalid element: {}",
+                self.message
+            ),
+            VectorLoadErrorKind::InvalidColumn => format!(
+                "Cannot load Vector, invalid column: {}",
+                self.message
+            ),
+            VectorLoadErrorKind::InvalidFormat => format!(
+                "Cannot load Vector, invalid format: {}",
+                self.message
+            ),
+            VectorLoadErrorKind::InvalidDataType => format!(
+                "Cannot load Vector, invalid data type: {}",
+                self.message
+            ),
+            VectorLoadErrorKind::InvalidShape => {
+                format!("Cannot load Vector, invalid shape: {}", self.message)
+            }
+            VectorLoadErrorKind::InvalidName => {
+                format!("Cannot load Vector, invalid name: {}", self.message)
+            }
+        }
+    }
+}
+
+/// Convert `io::Error` to `VectorLoadError`
+impl convert::From<io::Error> for VectorLoadError {
+    fn from(error: io::Error) -> Self {
+        VectorLoadError {
+            kind: VectorLoadErrorKind::IOError,
+            message: format!("{}", error),
+        }
+    }
+}
+
+/// Convert `csv::Error` to `VectorLoadError`
+impl convert::From<csv::Error> for VectorLoadError {
+    fn from(error: csv::Error) -> Self {
+        VectorLoadError {
+            kind: VectorLoadErrorKind::CSVError,
+            message: format!("{}", error),
+        }
+    }
+}
+
+impl fmt::Debug for VectorLoadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl fmt::Display for VectorLoadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+/// Enum to store the various types of errors that can cause parsing a numeric
+/// vector to fail.
+pub enum VectorParseErrorKind {
+    /// String being parsed is empty.
+    ///
+    /// Among other causes, this variant will be constructed when parsing a
+    /// string that only contains whitespaces.
+    Empty,
+    /// Contains an invalid element.
+    ///
+    /// Among other causes, this variant will be constructed when parsing a
+    /// string that contains non-numeric letter, for example `"[1, x]"`.
+    InvalidElement,
+    /// String is not in the expected syntax.
+    ///
+    /// Among other causes, this variant will be constructed when parsing a
+    /// string with unbalanced brackets, for example `"[1, 2"`.
+    InvalidSyntax,
+    /// Shape of the elements is invalid.
+    ///
+    /// Among other causes, this variant will be constructed when parsing a
+    /// string that contains a matrix as numeric vector.
+    InvalidShape,
+}
+
+/// An error which can be returned when parsing numeric vector from a string.
+///
+/// The 0-based byte [`position`] of the offending part of the string is
+/// available.
+///
+/// [`position`]: #method.position
+pub struct VectorParseError {
+    pub(crate) kind: VectorParseErrorKind,
+    pub(crate) message: String,
+    pub(crate) position: usize,
+}
+
+impl VectorParseError {
+    /// Creates a new `VectorParseError` from a known kind of error, an error
+    /// message and the byte position of the error.
+    pub fn new(
+        kind: VectorParseErrorKind,
+        message: String,
+        position: usize,
+    ) -> VectorParseError {
+        VectorParseError {
+            kind,
+            message,
+            position,
+        }
+    }
+
+    /// Outputs the detailed cause of parsing string failing.
+    pub fn kind(&self) -> &VectorParseErrorKind {
+        &self.kind
+    }
+
+    /// Returns the 0-based byte position in the string where the error
+    /// occurred.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let error = "[1, 2, x]".parse::<Vector<i32>>().unwrap_err();
+    /// assert_eq!(error.position(), 7);
+    /// ```
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    fn description(&self) -> String {
+        let description = match self.kind {
+            VectorParseErrorKind::Empty => {
+                "Cannot parse Vector from empty string".to_string()
+            }
+            VectorParseErrorKind::InvalidElement => format!(
+                "Cannot parse Vector, invalid element: {}",
+                self.message
+            ),
+            VectorParseErrorKind::InvalidSyntax => format!(
+                "Cannot parse Vector, invalid syntax: {}",
+                self.message
+            ),
+            VectorParseErrorKind::InvalidShape => format!(
+                "Cannot parse Vector, invalid shape: {}",
+                self.message
+            ),
+        };
+        format!("{} at position {}", description, self.position)
+    }
+}
+
+impl fmt::Debug for VectorParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl fmt::Display for VectorParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
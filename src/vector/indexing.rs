@@ -37,6 +37,11 @@ where
             )
         }
     }
+
+    // Elements of the numeric vector as a slice
+    pub(crate) fn as_slice(&self) -> &[T] {
+        &self.data
+    }
 }
 
 // Implement vector indexing
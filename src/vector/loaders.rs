@@ -14,6 +14,885 @@
 
 //! Loads numeric vector from a file.
 //!
-//! TODO(pyk): Add docs here
+//! A numeric vector can be loaded from a column of a CSV file using
+//! [`Vector::from_csv`] or from a text file that contains one element per
+//! line using [`Vector::from_txt`].
 //!
+//! ```
+//! # use crabsformer::prelude::*;
+//! let x: Vector<f32> = Vector::from_csv("tests/data/dataset.csv")
+//!     .column_name("feature2")
+//!     .load()
+//!     .unwrap();
+//! assert_eq!(x, vector![2.0, 4.0, 6.0]);
+//! ```
 //!
+//! Numeric vectors can also be written to a CSV file, one element per line,
+//! using [`Vector::to_csv`].
+//!
+//! Numeric vectors can be exchanged with NumPy using [`Vector::from_npy`]
+//! and [`Vector::to_npy`], see the [`npy`] module.
+//!
+//! Numeric vectors can be saved in the native binary format using
+//! [`Vector::save`] and loaded back using [`Vector::load`], see the
+//! [`binary`] module.
+//!
+//! [`Vector::from_csv`]: ../struct.Vector.html#method.from_csv
+//! [`Vector::from_txt`]: ../struct.Vector.html#method.from_txt
+//! [`Vector::to_csv`]: ../struct.Vector.html#method.to_csv
+//! [`Vector::from_npy`]: ../struct.Vector.html#method.from_npy
+//! [`Vector::to_npy`]: ../struct.Vector.html#method.to_npy
+//! [`npy`]: ../../npy/index.html
+//! [`Vector::save`]: ../struct.Vector.html#method.save
+//! [`Vector::load`]: ../struct.Vector.html#method.load
+//! [`binary`]: ../../binary/index.html
+
+use crate::binary;
+use crate::npy;
+use crate::utils;
+use crate::vector::errors::{VectorLoadError, VectorLoadErrorKind};
+use crate::vector::Vector;
+use crate::wav::{self, WavSample};
+use csv;
+use num::Num;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
+
+// Column of the CSV file to load
+#[derive(Debug)]
+enum Column {
+    Index(usize),
+    Name(String),
+}
+
+/// Numeric vector loader for CSV formatted file.
+///
+/// See also: [`Vector::from_csv`].
+///
+/// [`Vector::from_csv`]: ../struct.Vector.html#method.from_csv
+#[derive(Debug)]
+pub struct VectorLoaderForCSV<T, P>
+where
+    P: AsRef<Path>,
+{
+    file_path: P,
+    has_headers: bool,
+    column: Column,
+    delimiter: u8,
+    // We use this to make compiler happy
+    phantom: PhantomData<T>,
+}
+
+impl<T, P> VectorLoaderForCSV<T, P>
+where
+    P: AsRef<Path>,
+{
+    /// Set to true to treat the first row as a special header row. By
+    /// default, it is set to false.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let x: Vector<f32> = Vector::from_csv("tests/data/dataset.csv")
+    ///     .has_headers(true)
+    ///     .load()
+    ///     .unwrap();
+    /// assert_eq!(x, vector![1.0, 3.0, 5.0]);
+    /// ```
+    pub fn has_headers(self, yes: bool) -> VectorLoaderForCSV<T, P> {
+        VectorLoaderForCSV {
+            has_headers: yes,
+            ..self
+        }
+    }
+
+    /// Select the column to load by its zero-based index. By default, the
+    /// first column is loaded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let x: Vector<f32> = Vector::from_csv("tests/data/dataset.csv")
+    ///     .has_headers(true)
+    ///     .column(1)
+    ///     .load()
+    ///     .unwrap();
+    /// assert_eq!(x, vector![2.0, 4.0, 6.0]);
+    /// ```
+    pub fn column(self, index: usize) -> VectorLoaderForCSV<T, P> {
+        VectorLoaderForCSV {
+            column: Column::Index(index),
+            ..self
+        }
+    }
+
+    /// Select the column to load by its header name. It treats the first row
+    /// as the header row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let x: Vector<f32> = Vector::from_csv("tests/data/dataset.csv")
+    ///     .column_name("feature1")
+    ///     .load()
+    ///     .unwrap();
+    /// assert_eq!(x, vector![1.0, 3.0, 5.0]);
+    /// ```
+    pub fn column_name(self, name: &str) -> VectorLoaderForCSV<T, P> {
+        VectorLoaderForCSV {
+            has_headers: true,
+            column: Column::Name(name.to_string()),
+            ..self
+        }
+    }
+
+    /// Set the field delimiter. By default, it is set to `b','`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let x: Vector<i32> = Vector::from_csv("tests/data/vector_semicolon.csv")
+    ///     .delimiter(b';')
+    ///     .column(2)
+    ///     .load()
+    ///     .unwrap();
+    /// assert_eq!(x, vector![3, 6]);
+    /// ```
+    pub fn delimiter(self, delimiter: u8) -> VectorLoaderForCSV<T, P> {
+        VectorLoaderForCSV { delimiter, ..self }
+    }
+
+    /// Load numeric vector from CSV file. You need to explicitly annotate the
+    /// numeric type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let x: Vector<f32> =
+    ///     Vector::from_csv("tests/data/weight.csv").load().unwrap();
+    /// ```
+    pub fn load(self) -> Result<Vector<T>, VectorLoadError>
+    where
+        T: Num + Copy + utils::TypeName + utils::ParseElement,
+    {
+        // Open CSV file
+        let file = File::open(self.file_path)?;
+        let mut rdr = csv::ReaderBuilder::new()
+            .has_headers(self.has_headers)
+            .delimiter(self.delimiter)
+            .from_reader(file);
+
+        // Find the index of the selected column
+        let index = match self.column {
+            Column::Index(index) => index,
+            Column::Name(ref name) => {
+                match rdr.headers()?.iter().position(|h| h.trim() == name) {
+                    Some(index) => index,
+                    None => {
+                        return Err(VectorLoadError::new(
+                            VectorLoadErrorKind::InvalidColumn,
+                            format!("header {:?} is not found", name),
+                        ));
+                    }
+                }
+            }
+        };
+
+        let mut elements = Vec::new();
+        for result in rdr.records() {
+            let record = result?;
+            let value = match record.get(index) {
+                Some(value) => value,
+                None => {
+                    return Err(VectorLoadError::new(
+                        VectorLoadErrorKind::InvalidColumn,
+                        format!(
+                            "column {} is out of bounds, the record has {} \
+                             fields",
+                            index,
+                            record.len()
+                        ),
+                    ));
+                }
+            };
+            elements.push(parse_element(value)?);
+        }
+        if elements.is_empty() {
+            return Err(VectorLoadError::new(
+                VectorLoadErrorKind::Empty,
+                String::from("Cannot load empty file"),
+            ));
+        }
+        Ok(Vector::from(elements))
+    }
+}
+
+/// Numeric vector loader for text file that contains one element per line.
+///
+/// See also: [`Vector::from_txt`].
+///
+/// [`Vector::from_txt`]: ../struct.Vector.html#method.from_txt
+#[derive(Debug)]
+pub struct VectorLoaderForText<T, P>
+where
+    P: AsRef<Path>,
+{
+    file_path: P,
+    has_headers: bool,
+    // We use this to make compiler happy
+    phantom: PhantomData<T>,
+}
+
+impl<T, P> VectorLoaderForText<T, P>
+where
+    P: AsRef<Path>,
+{
+    /// Set to true to skip the first line. By default, it is set to false.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let x: Vector<f64> = Vector::from_txt("tests/data/vector_header.txt")
+    ///     .has_headers(true)
+    ///     .load()
+    ///     .unwrap();
+    /// assert_eq!(x, vector![0.5, 1.5]);
+    /// ```
+    pub fn has_headers(self, yes: bool) -> VectorLoaderForText<T, P> {
+        VectorLoaderForText {
+            has_headers: yes,
+            ..self
+        }
+    }
+
+    /// Load numeric vector from text file. Empty lines are ignored. You need
+    /// to explicitly annotate the numeric type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let x: Vector<i32> = Vector::from_txt("tests/data/vector.txt")
+    ///     .load()
+    ///     .unwrap();
+    /// assert_eq!(x, vector![1, -2, 3, 4]);
+    /// ```
+    pub fn load(self) -> Result<Vector<T>, VectorLoadError>
+    where
+        T: Num + Copy + utils::TypeName + utils::ParseElement,
+    {
+        let file = File::open(self.file_path)?;
+        let skip = if self.has_headers { 1 } else { 0 };
+        let mut elements = Vec::new();
+        for line in BufReader::new(file).lines().skip(skip) {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            elements.push(parse_element(&line)?);
+        }
+        if elements.is_empty() {
+            return Err(VectorLoadError::new(
+                VectorLoadErrorKind::Empty,
+                String::from("Cannot load empty file"),
+            ));
+        }
+        Ok(Vector::from(elements))
+    }
+}
+
+// Parse the element, it will returns an error if `value` is not a valid
+// number of type `T`.
+fn parse_element<T>(value: &str) -> Result<T, VectorLoadError>
+where
+    T: utils::TypeName + utils::ParseElement,
+{
+    let format = utils::NumberFormat::default();
+    match T::parse_element(value, &format) {
+        Some(element) => Ok(element),
+        None => Err(VectorLoadError::new(
+            VectorLoadErrorKind::InvalidElement,
+            format!("{:?} is not valid {}", value, T::type_name()),
+        )),
+    }
+}
+
+/// Numeric vector writer for CSV formatted file.
+///
+/// See also: [`Vector::to_csv`] and [`Vector::to_csv_writer`].
+///
+/// [`Vector::to_csv`]: ../struct.Vector.html#method.to_csv
+/// [`Vector::to_csv_writer`]: ../struct.Vector.html#method.to_csv_writer
+pub struct VectorWriterForCSV<'a, T>
+where
+    T: Num + Copy,
+{
+    vector: &'a Vector<T>,
+    destination: utils::CSVDestination<'a>,
+    has_headers: bool,
+    header: String,
+    precision: Option<usize>,
+    scientific: bool,
+}
+
+impl<'a, T> VectorWriterForCSV<'a, T>
+where
+    T: Num + Copy,
+{
+    /// Set to true to write a header row before the elements. By default, it
+    /// is set to false. The header name is `0` unless it is specified using
+    /// [`header`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let x = vector![1, 2, 3];
+    /// let mut buf = Vec::new();
+    /// x.to_csv_writer(&mut buf).has_headers(true).write().unwrap();
+    /// assert_eq!(String::from_utf8(buf).unwrap(), "0\n1\n2\n3\n");
+    /// ```
+    ///
+    /// [`header`]: #method.header
+    pub fn has_headers(self, yes: bool) -> VectorWriterForCSV<'a, T> {
+        VectorWriterForCSV {
+            has_headers: yes,
+            ..self
+        }
+    }
+
+    /// Set the header name and write it as the header row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let x = vector![1, 2, 3];
+    /// let mut buf = Vec::new();
+    /// x.to_csv_writer(&mut buf).header("x").write().unwrap();
+    /// assert_eq!(String::from_utf8(buf).unwrap(), "x\n1\n2\n3\n");
+    /// ```
+    pub fn header(self, name: &str) -> VectorWriterForCSV<'a, T> {
+        VectorWriterForCSV {
+            has_headers: true,
+            header: name.to_string(),
+            ..self
+        }
+    }
+
+    /// Set the number of digits after the decimal point. By default, the
+    /// elements are written using the shortest representation that can be
+    /// loaded back without loss.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let x = vector![0.5, 1.0 / 3.0];
+    /// let mut buf = Vec::new();
+    /// x.to_csv_writer(&mut buf).precision(3).write().unwrap();
+    /// assert_eq!(String::from_utf8(buf).unwrap(), "0.500\n0.333\n");
+    /// ```
+    pub fn precision(self, precision: usize) -> VectorWriterForCSV<'a, T> {
+        VectorWriterForCSV {
+            precision: Some(precision),
+            ..self
+        }
+    }
+
+    /// Set to true to write the elements in scientific notation. By default,
+    /// it is set to false.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let x = vector![1500.0, 0.25];
+    /// let mut buf = Vec::new();
+    /// x.to_csv_writer(&mut buf).scientific(true).write().unwrap();
+    /// assert_eq!(String::from_utf8(buf).unwrap(), "1.5e3\n2.5e-1\n");
+    /// ```
+    pub fn scientific(self, yes: bool) -> VectorWriterForCSV<'a, T> {
+        VectorWriterForCSV {
+            scientific: yes,
+            ..self
+        }
+    }
+
+    /// Write numeric vector as CSV, one element per line. If the destination
+    /// is a file path, the file is created or truncated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let x = vector![1, 2, 3];
+    /// let mut buf = Vec::new();
+    /// x.to_csv_writer(&mut buf).write().unwrap();
+    /// assert_eq!(String::from_utf8(buf).unwrap(), "1\n2\n3\n");
+    /// ```
+    pub fn write(self) -> Result<(), VectorLoadError>
+    where
+        T: fmt::Display + fmt::LowerExp,
+    {
+        let mut wtr = csv::WriterBuilder::new()
+            .from_writer(self.destination.into_writer()?);
+        if self.has_headers {
+            wtr.write_record([&self.header])?;
+        }
+        for value in self.vector.elements() {
+            let field =
+                utils::format_element(value, self.precision, self.scientific);
+            wtr.write_record(&[field])?;
+        }
+        wtr.flush()?;
+        Ok(())
+    }
+}
+
+/// Numeric vector writer for NPY formatted file.
+///
+/// See also: [`Vector::to_npy`].
+///
+/// [`Vector::to_npy`]: ../struct.Vector.html#method.to_npy
+pub struct VectorWriterForNpy<'a, T>
+where
+    T: Num + Copy,
+{
+    vector: &'a Vector<T>,
+    file_path: PathBuf,
+    big_endian: bool,
+}
+
+impl<'a, T> VectorWriterForNpy<'a, T>
+where
+    T: Num + Copy,
+{
+    /// Set to true to write the elements in big endian. By default, it is
+    /// set to false.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// # let path = std::env::temp_dir().join("crabsformer_doc_vector_big.npy");
+    /// let x = vector![1u16, 2, 3];
+    /// x.to_npy(&path).big_endian(true).write().unwrap();
+    /// let y: Vector<u16> = Vector::from_npy(&path).unwrap();
+    /// assert_eq!(x, y);
+    /// ```
+    pub fn big_endian(self, yes: bool) -> VectorWriterForNpy<'a, T> {
+        VectorWriterForNpy {
+            big_endian: yes,
+            ..self
+        }
+    }
+
+    /// Write numeric vector as NPY file. The file is created or truncated.
+    pub fn write(self) -> Result<(), VectorLoadError>
+    where
+        T: utils::BinaryElement,
+    {
+        let mut file = BufWriter::new(File::create(&self.file_path)?);
+        self.vector.write_npy(&mut file, self.big_endian)?;
+        file.flush()?;
+        Ok(())
+    }
+}
+
+/// Numeric vector writer for WAVE audio file.
+///
+/// See also: [`Vector::to_wav`].
+///
+/// [`Vector::to_wav`]: ../struct.Vector.html#method.to_wav
+pub struct VectorWriterForWav<'a, T>
+where
+    T: Num + Copy,
+{
+    channels: Vec<&'a Vector<T>>,
+    file_path: PathBuf,
+    sample_rate: u32,
+    bit_depth: Option<u16>,
+    float: Option<bool>,
+}
+
+impl<'a, T> VectorWriterForWav<'a, T>
+where
+    T: Num + Copy,
+{
+    /// Set the number of bits per sample, it should be 8, 16, 24 or 32 for
+    /// integer samples and 32 or 64 for floating point samples. By default,
+    /// it is the size of the numeric type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// # let path = std::env::temp_dir().join("crabsformer_doc_24bit.wav");
+    /// let x: Vector<i16> = vector![0, 256, -256];
+    /// Vector::to_wav(&[&x], &path, 8000).bit_depth(24).write().unwrap();
+    /// let (channels, _): (Vec<Vector<i16>>, u32) =
+    ///     Vector::from_wav(&path).unwrap();
+    /// assert_eq!(channels, vec![x]);
+    /// ```
+    pub fn bit_depth(self, bits: u16) -> VectorWriterForWav<'a, T> {
+        VectorWriterForWav {
+            bit_depth: Some(bits),
+            ..self
+        }
+    }
+
+    /// Set to true to write floating point samples and false to write
+    /// integer samples. By default, it is true for `f32` and `f64`.
+    pub fn float(self, yes: bool) -> VectorWriterForWav<'a, T> {
+        VectorWriterForWav {
+            float: Some(yes),
+            ..self
+        }
+    }
+
+    /// Write the channels as WAVE file. The file is created or truncated.
+    /// It returns an error if the channels do not have the same length or
+    /// if the bit depth is not supported.
+    pub fn write(self) -> Result<(), VectorLoadError>
+    where
+        T: WavSample,
+    {
+        let channels: Vec<&[T]> =
+            self.channels.iter().map(|x| x.as_slice()).collect();
+        let bits = self.bit_depth.unwrap_or(T::BITS);
+        let float = self.float.unwrap_or(T::FLOAT);
+        // Validate before creating the file, so it is left untouched
+        wav::check_wav(&channels, bits, float)?;
+        let mut file = BufWriter::new(File::create(&self.file_path)?);
+        wav::write_wav(&mut file, &channels, self.sample_rate, bits, float)?;
+        file.flush()?;
+        Ok(())
+    }
+}
+
+impl<T> Vector<T>
+where
+    T: Num + Copy,
+{
+    /// Load numeric vector from a column of CSV file. You need to explicitly
+    /// annotate the numeric type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let x: Vector<f32> =
+    ///     Vector::from_csv("tests/data/weight.csv").load().unwrap();
+    /// ```
+    pub fn from_csv<P>(file_path: P) -> VectorLoaderForCSV<T, P>
+    where
+        P: AsRef<Path>,
+    {
+        VectorLoaderForCSV {
+            file_path,
+            has_headers: false,
+            column: Column::Index(0),
+            delimiter: b',',
+            phantom: PhantomData,
+        }
+    }
+
+    /// Load numeric vector from text file that contains one element per
+    /// line. You need to explicitly annotate the numeric type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let x: Vector<i32> = Vector::from_txt("tests/data/vector.txt")
+    ///     .load()
+    ///     .unwrap();
+    /// ```
+    pub fn from_txt<P>(file_path: P) -> VectorLoaderForText<T, P>
+    where
+        P: AsRef<Path>,
+    {
+        VectorLoaderForText {
+            file_path,
+            has_headers: false,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Write numeric vector to CSV file, one element per line. The file is
+    /// created or truncated when [`write`] is called.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let x = vector![1.5, 2.0, 3.25];
+    /// # let path = std::env::temp_dir().join("crabsformer_doc_vector.csv");
+    /// x.to_csv(&path).header("x").write().unwrap();
+    /// ```
+    ///
+    /// [`write`]: loaders/struct.VectorWriterForCSV.html#method.write
+    pub fn to_csv<P>(&self, file_path: P) -> VectorWriterForCSV<'_, T>
+    where
+        P: AsRef<Path>,
+    {
+        let path = file_path.as_ref().to_path_buf();
+        self.csv_writer(utils::CSVDestination::Path(path))
+    }
+
+    /// Write numeric vector as CSV to any [`io::Write`], one element per line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let x = vector![1, 2, 3];
+    /// let mut buf = Vec::new();
+    /// x.to_csv_writer(&mut buf).write().unwrap();
+    /// assert_eq!(String::from_utf8(buf).unwrap(), "1\n2\n3\n");
+    /// ```
+    ///
+    /// [`io::Write`]: https://doc.rust-lang.org/std/io/trait.Write.html
+    pub fn to_csv_writer<'a, W>(
+        &'a self,
+        writer: W,
+    ) -> VectorWriterForCSV<'a, T>
+    where
+        W: io::Write + 'a,
+    {
+        self.csv_writer(utils::CSVDestination::Writer(Box::new(writer)))
+    }
+
+    fn csv_writer<'a>(
+        &'a self,
+        destination: utils::CSVDestination<'a>,
+    ) -> VectorWriterForCSV<'a, T> {
+        VectorWriterForCSV {
+            vector: self,
+            destination,
+            has_headers: false,
+            header: String::from("0"),
+            precision: None,
+            scientific: false,
+        }
+    }
+
+    /// Load numeric vector from NPY file written by `numpy.save`. The array
+    /// should be one-dimensional and its element type should match the
+    /// numeric type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let x: Vector<f64> = Vector::from_npy("tests/data/vector.npy").unwrap();
+    /// assert_eq!(x, vector![1.0, 2.5, -3.0]);
+    /// ```
+    pub fn from_npy<P>(file_path: P) -> Result<Vector<T>, VectorLoadError>
+    where
+        P: AsRef<Path>,
+        T: utils::BinaryElement,
+    {
+        let file = File::open(file_path)?;
+        Vector::read_npy(&mut BufReader::new(file))
+    }
+
+    /// Write numeric vector to NPY file, the file can be loaded using
+    /// `numpy.load`. The file is created or truncated when [`write`] is
+    /// called.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// # let path = std::env::temp_dir().join("crabsformer_doc_vector.npy");
+    /// let x = vector![1, 2, 3];
+    /// x.to_npy(&path).write().unwrap();
+    /// ```
+    ///
+    /// [`write`]: loaders/struct.VectorWriterForNpy.html#method.write
+    pub fn to_npy<P>(&self, file_path: P) -> VectorWriterForNpy<'_, T>
+    where
+        P: AsRef<Path>,
+    {
+        VectorWriterForNpy {
+            vector: self,
+            file_path: file_path.as_ref().to_path_buf(),
+            big_endian: false,
+        }
+    }
+
+    /// Save numeric vector in the [native binary format]. The file is
+    /// created or truncated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// # let path = std::env::temp_dir().join("crabsformer_doc_vector_save.bin");
+    /// let x = vector![1, 2, 3];
+    /// x.save(&path).unwrap();
+    /// ```
+    ///
+    /// [native binary format]: ../binary/index.html
+    pub fn save<P>(&self, file_path: P) -> Result<(), VectorLoadError>
+    where
+        P: AsRef<Path>,
+        T: utils::BinaryElement,
+    {
+        let mut file = BufWriter::new(File::create(file_path)?);
+        binary::write_array(&mut file, &[self.len()], self.as_slice())?;
+        file.flush()?;
+        Ok(())
+    }
+
+    /// Load numeric vector saved in the [native binary format]. It returns
+    /// an error if the element type does not match the numeric type, if the
+    /// file contains a matrix or if the file is corrupted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// # let path = std::env::temp_dir().join("crabsformer_doc_vector_load.bin");
+    /// vector![1.5, 2.5].save(&path).unwrap();
+    /// let x: Vector<f64> = Vector::load(&path).unwrap();
+    /// assert_eq!(x, vector![1.5, 2.5]);
+    ///
+    /// let result: Result<Vector<f32>, VectorLoadError> = Vector::load(&path);
+    /// assert!(result.is_err());
+    /// ```
+    ///
+    /// [native binary format]: ../binary/index.html
+    pub fn load<P>(file_path: P) -> Result<Vector<T>, VectorLoadError>
+    where
+        P: AsRef<Path>,
+        T: utils::BinaryElement,
+    {
+        let file = BufReader::new(File::open(file_path)?);
+        let (shape, data) = binary::read_array(file)?;
+        if shape.len() != 1 {
+            return Err(VectorLoadError::new(
+                VectorLoadErrorKind::InvalidShape,
+                format!(
+                    "expected one-dimensional array, got shape {:?}",
+                    shape
+                ),
+            ));
+        }
+        Ok(Vector::from(data))
+    }
+
+    /// Load PCM audio from WAVE file, returns one numeric vector per channel
+    /// and the sample rate. The samples are converted to the numeric type,
+    /// see [`WavSample`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let (channels, sample_rate): (Vec<Vector<i16>>, u32) =
+    ///     Vector::from_wav("tests/data/audio.wav").unwrap();
+    /// assert_eq!(sample_rate, 8000);
+    /// assert_eq!(channels[0], vector![0, 1000, -1000, 32767]);
+    /// assert_eq!(channels[1], vector![0, -1000, 1000, -32768]);
+    ///
+    /// let (channels, _): (Vec<Vector<f32>>, u32) =
+    ///     Vector::from_wav("tests/data/audio.wav").unwrap();
+    /// assert_eq!(channels[1][3], -1.0);
+    /// ```
+    ///
+    /// [`WavSample`]: ../wav/trait.WavSample.html
+    pub fn from_wav<P>(
+        file_path: P,
+    ) -> Result<(Vec<Vector<T>>, u32), VectorLoadError>
+    where
+        P: AsRef<Path>,
+        T: WavSample,
+    {
+        let file = BufReader::new(File::open(file_path)?);
+        let (sample_rate, channels) = wav::read_wav(file)?;
+        let channels = channels.into_iter().map(Vector::from).collect();
+        Ok((channels, sample_rate))
+    }
+
+    /// Write numeric vectors as channels of WAVE file with the given sample
+    /// rate. The vectors should have the same length. The file is created
+    /// or truncated when [`write`] is called.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// # let path = std::env::temp_dir().join("crabsformer_doc_vector.wav");
+    /// let left: Vector<f32> = vector![0.0, 0.5, -0.5];
+    /// let right: Vector<f32> = vector![0.0, -0.5, 0.5];
+    /// Vector::to_wav(&[&left, &right], &path, 44100).write().unwrap();
+    /// ```
+    ///
+    /// [`write`]: loaders/struct.VectorWriterForWav.html#method.write
+    pub fn to_wav<'a, P>(
+        channels: &[&'a Vector<T>],
+        file_path: P,
+        sample_rate: u32,
+    ) -> VectorWriterForWav<'a, T>
+    where
+        P: AsRef<Path>,
+    {
+        VectorWriterForWav {
+            channels: channels.to_vec(),
+            file_path: file_path.as_ref().to_path_buf(),
+            sample_rate,
+            bit_depth: None,
+            float: None,
+        }
+    }
+
+    pub(crate) fn read_npy<R>(
+        reader: &mut R,
+    ) -> Result<Vector<T>, VectorLoadError>
+    where
+        R: io::Read,
+        T: utils::BinaryElement,
+    {
+        let (shape, data) = npy::read_array(reader)?;
+        if shape.len() != 1 {
+            return Err(VectorLoadError::new(
+                VectorLoadErrorKind::InvalidShape,
+                format!(
+                    "expected one-dimensional array, got shape {:?}",
+                    shape
+                ),
+            ));
+        }
+        Ok(Vector::from(data))
+    }
+
+    pub(crate) fn write_npy<W>(
+        &self,
+        writer: &mut W,
+        big_endian: bool,
+    ) -> io::Result<()>
+    where
+        W: io::Write,
+        T: utils::BinaryElement,
+    {
+        npy::write_array(
+            writer,
+            &[self.len()],
+            self.as_slice(),
+            big_endian,
+            false,
+        )
+    }
+}
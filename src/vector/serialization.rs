@@ -0,0 +1,76 @@
+// Copyright (c) 2019, Bayu Aldi Yansyah <bayualdiyansyah@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Serialization of numeric vector, enabled by the `serde` feature.
+//!
+//! Numeric vector is serialized as its shape and its elements, for
+//! example `{"shape":[3],"data":[1,2,3]}` in JSON. The length of the data
+//! is validated against the shape when the numeric vector is deserialized.
+
+use crate::vector::Vector;
+use num::Num;
+use serde::de;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(Serialize)]
+#[serde(rename = "Vector")]
+struct VectorRef<'a, T> {
+    shape: [usize; 1],
+    data: &'a [T],
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "Vector")]
+struct VectorData<T> {
+    shape: [usize; 1],
+    data: Vec<T>,
+}
+
+impl<T> Serialize for Vector<T>
+where
+    T: Num + Copy + Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        VectorRef {
+            shape: [self.len()],
+            data: self.as_slice(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Vector<T>
+where
+    T: Num + Copy + Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Vector<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let VectorData { shape, data } =
+            VectorData::deserialize(deserializer)?;
+        if data.len() != shape[0] {
+            return Err(de::Error::custom(format!(
+                "expected {} elements for shape {:?}, got {}",
+                shape[0],
+                shape,
+                data.len()
+            )));
+        }
+        Ok(Vector::from(data))
+    }
+}
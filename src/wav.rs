@@ -0,0 +1,392 @@
+// Copyright (c) 2019, Bayu Aldi Yansyah <bayualdiyansyah@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! WAVE audio file format.
+//!
+//! PCM audio can be loaded as one numeric vector per channel using
+//! [`Vector::from_wav`], or as a matrix of channels × samples using
+//! [`Matrix::from_wav`]. Both return the sample rate of the audio. Integer
+//! PCM of 8, 16, 24 and 32 bits and IEEE floating point of 32 and 64 bits
+//! are supported, including files in the `WAVE_FORMAT_EXTENSIBLE` format.
+//!
+//! The samples are converted to the numeric type of the vector or the
+//! matrix, see [`WavSample`]. For example, 24-bit samples loaded as `i16`
+//! are rounded to 16 bits and samples loaded as `f32` are scaled to the
+//! range `-1.0..1.0`.
+//!
+//! Audio can be written using [`Vector::to_wav`] and [`Matrix::to_wav`]
+//! with the given sample rate. By default, the bit depth follows the
+//! numeric type, it can be changed using the `bit_depth` and `float`
+//! options of the writer.
+//!
+//! # Examples
+//! ```
+//! # use crabsformer::prelude::*;
+//! # let path = std::env::temp_dir().join("crabsformer_doc_wav.wav");
+//! // Stereo audio, one channel per row
+//! let w: Matrix<i16> = matrix![0, 100, 200; 0, -100, -200];
+//! w.to_wav(&path, 44100).write().unwrap();
+//! let (v, sample_rate): (Matrix<i16>, u32) = Matrix::from_wav(&path).unwrap();
+//! assert_eq!(v, w);
+//! assert_eq!(sample_rate, 44100);
+//! ```
+//!
+//! [`Vector::from_wav`]: ../vector/struct.Vector.html#method.from_wav
+//! [`Matrix::from_wav`]: ../matrix/struct.Matrix.html#method.from_wav
+//! [`Vector::to_wav`]: ../vector/struct.Vector.html#method.to_wav
+//! [`Matrix::to_wav`]: ../matrix/struct.Matrix.html#method.to_wav
+//! [`WavSample`]: trait.WavSample.html
+
+use crate::vector::errors::{VectorLoadError, VectorLoadErrorKind};
+use std::convert::TryInto;
+use std::io;
+
+const FORMAT_PCM: u16 = 1;
+const FORMAT_FLOAT: u16 = 3;
+const FORMAT_EXTENSIBLE: u16 = 0xFFFE;
+
+/// Numeric type that can be used as audio sample.
+///
+/// The samples are converted through their normalized value, integer
+/// samples of `n` bits are divided by `2^(n - 1)` and floating point samples
+/// are used as is. Converted values that are out of the range of an integer
+/// type are clamped.
+pub trait WavSample: Copy {
+    /// Default bit depth used to write the samples.
+    const BITS: u16;
+
+    /// True if the samples are written as floating point numbers by
+    /// default.
+    const FLOAT: bool;
+
+    /// Convert normalized value to the sample.
+    fn from_normalized(value: f64) -> Self;
+
+    /// Convert the sample to its normalized value.
+    fn to_normalized(self) -> f64;
+}
+
+macro_rules! implement_wav_sample_for_integer {
+    ($t: ty, $bits: expr) => {
+        impl WavSample for $t {
+            const BITS: u16 = $bits;
+            const FLOAT: bool = false;
+
+            fn from_normalized(value: f64) -> $t {
+                let scale = 2f64.powi($bits - 1);
+                (value * scale).round().max(-scale).min(scale - 1.0) as $t
+            }
+
+            fn to_normalized(self) -> f64 {
+                f64::from(self) / 2f64.powi($bits - 1)
+            }
+        }
+    };
+}
+
+implement_wav_sample_for_integer!(i16, 16);
+implement_wav_sample_for_integer!(i32, 32);
+
+impl WavSample for f32 {
+    const BITS: u16 = 32;
+    const FLOAT: bool = true;
+
+    fn from_normalized(value: f64) -> f32 {
+        value as f32
+    }
+
+    fn to_normalized(self) -> f64 {
+        f64::from(self)
+    }
+}
+
+impl WavSample for f64 {
+    const BITS: u16 = 64;
+    const FLOAT: bool = true;
+
+    fn from_normalized(value: f64) -> f64 {
+        value
+    }
+
+    fn to_normalized(self) -> f64 {
+        self
+    }
+}
+
+fn invalid_format(message: String) -> VectorLoadError {
+    VectorLoadError::new(VectorLoadErrorKind::InvalidFormat, message)
+}
+
+fn unexpected_eof() -> VectorLoadError {
+    invalid_format(String::from("unexpected end of file"))
+}
+
+fn u16_at(bytes: &[u8], pos: usize) -> u16 {
+    u16::from_le_bytes([bytes[pos], bytes[pos + 1]])
+}
+
+fn u32_at(bytes: &[u8], pos: usize) -> u32 {
+    u32::from_le_bytes(bytes[pos..pos + 4].try_into().unwrap())
+}
+
+// Check the sample encoding, returns an error for bit depth that is not
+// supported.
+fn check_encoding(bits: u16, float: bool) -> Result<(), VectorLoadError> {
+    match (float, bits) {
+        (false, 8) | (false, 16) | (false, 24) | (false, 32) => Ok(()),
+        (true, 32) | (true, 64) => Ok(()),
+        _ => Err(VectorLoadError::new(
+            VectorLoadErrorKind::InvalidDataType,
+            format!(
+                "unsupported bit depth {} for {} samples",
+                bits,
+                if float { "floating point" } else { "integer" }
+            ),
+        )),
+    }
+}
+
+// Decode normalized value of little endian sample
+fn decode(bytes: &[u8], float: bool) -> f64 {
+    match (float, bytes.len()) {
+        (true, 4) => f64::from(f32::from_le_bytes(bytes.try_into().unwrap())),
+        (true, _) => f64::from_le_bytes(bytes.try_into().unwrap()),
+        // 8-bit samples are unsigned
+        (false, 1) => (f64::from(bytes[0]) - 128.0) / 128.0,
+        (false, 2) => {
+            f64::from(i16::from_le_bytes([bytes[0], bytes[1]])) / 32768.0
+        }
+        (false, 3) => {
+            let sample =
+                i32::from_le_bytes([0, bytes[0], bytes[1], bytes[2]]);
+            f64::from(sample >> 8) / 8_388_608.0
+        }
+        (false, _) => {
+            let sample = i32::from_le_bytes(bytes.try_into().unwrap());
+            f64::from(sample) / 2_147_483_648.0
+        }
+    }
+}
+
+// Append the encoded normalized value in little endian to `buf`
+fn encode(value: f64, bits: u16, float: bool, buf: &mut Vec<u8>) {
+    if float {
+        match bits {
+            32 => buf.extend_from_slice(&(value as f32).to_le_bytes()),
+            _ => buf.extend_from_slice(&value.to_le_bytes()),
+        }
+        return;
+    }
+    let scale = 2f64.powi(i32::from(bits) - 1);
+    let sample = (value * scale).round().max(-scale).min(scale - 1.0) as i32;
+    match bits {
+        8 => buf.push((sample + 128) as u8),
+        16 => buf.extend_from_slice(&(sample as i16).to_le_bytes()),
+        24 => buf.extend_from_slice(&sample.to_le_bytes()[..3]),
+        _ => buf.extend_from_slice(&sample.to_le_bytes()),
+    }
+}
+
+// Read PCM audio from `reader`, returns the sample rate and the samples of
+// each channel.
+pub(crate) fn read_wav<T, R>(
+    mut reader: R,
+) -> Result<(u32, Vec<Vec<T>>), VectorLoadError>
+where
+    T: WavSample,
+    R: io::Read,
+{
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes)?;
+    if bytes.is_empty() {
+        return Err(VectorLoadError::new(
+            VectorLoadErrorKind::Empty,
+            String::from("Cannot load empty file"),
+        ));
+    }
+    if bytes.len() < 12 || &bytes[..4] != b"RIFF" || &bytes[8..12] != b"WAVE"
+    {
+        return Err(invalid_format(String::from("not a RIFF WAVE file")));
+    }
+
+    // (channels, sample rate, bits per sample, float)
+    let mut format: Option<(usize, u32, u16, bool)> = None;
+    let mut pos = 12;
+    while pos + 8 <= bytes.len() {
+        let id = &bytes[pos..pos + 4];
+        let size = u32_at(&bytes, pos + 4) as usize;
+        let body = bytes
+            .get(pos + 8..)
+            .and_then(|rest| rest.get(..size))
+            .ok_or_else(unexpected_eof)?;
+        // Chunks are aligned to two bytes
+        pos += 8 + size + size % 2;
+
+        if id == b"fmt " {
+            if size < 16 {
+                return Err(invalid_format(format!(
+                    "fmt chunk is too short ({} bytes)",
+                    size
+                )));
+            }
+            let mut code = u16_at(body, 0);
+            if code == FORMAT_EXTENSIBLE && size >= 26 {
+                // The format code is the first field of the sub format GUID
+                code = u16_at(body, 24);
+            }
+            let float = match code {
+                FORMAT_PCM => false,
+                FORMAT_FLOAT => true,
+                _ => {
+                    return Err(VectorLoadError::new(
+                        VectorLoadErrorKind::InvalidDataType,
+                        format!("unsupported audio format {:#06x}", code),
+                    ));
+                }
+            };
+            let channels = u16_at(body, 2) as usize;
+            let sample_rate = u32_at(body, 4);
+            let block_align = u16_at(body, 12) as usize;
+            let bits = u16_at(body, 14);
+            check_encoding(bits, float)?;
+            if channels == 0 {
+                return Err(invalid_format(String::from(
+                    "number of channels should not be zero",
+                )));
+            }
+            if block_align != channels * (bits as usize / 8) {
+                return Err(invalid_format(format!(
+                    "block align {} does not match {} channels of {} bits",
+                    block_align, channels, bits
+                )));
+            }
+            format = Some((channels, sample_rate, bits, float));
+        } else if id == b"data" {
+            let (channels, sample_rate, bits, float) =
+                format.ok_or_else(|| {
+                    invalid_format(String::from(
+                        "data chunk before fmt chunk",
+                    ))
+                })?;
+            let sample_size = bits as usize / 8;
+            let frame_size = channels * sample_size;
+            let nsamples = size / frame_size;
+            if nsamples * frame_size != size {
+                return Err(invalid_format(format!(
+                    "data size {} is not a multiple of the frame size {}",
+                    size, frame_size
+                )));
+            }
+            let mut samples = vec![Vec::with_capacity(nsamples); channels];
+            for (i, sample) in body.chunks(sample_size).enumerate() {
+                let value = decode(sample, float);
+                samples[i % channels].push(T::from_normalized(value));
+            }
+            return Ok((sample_rate, samples));
+        }
+    }
+    match format {
+        Some(_) => Err(invalid_format(String::from("missing data chunk"))),
+        None => Err(invalid_format(String::from("missing fmt chunk"))),
+    }
+}
+
+// Returns an error if the channels cannot be written as PCM audio with the
+// given encoding, otherwise returns the size of the audio data in bytes.
+pub(crate) fn check_wav<T>(
+    channels: &[&[T]],
+    bits: u16,
+    float: bool,
+) -> Result<usize, VectorLoadError> {
+    check_encoding(bits, float)?;
+    let invalid_shape = |message: &str| {
+        VectorLoadError::new(
+            VectorLoadErrorKind::InvalidShape,
+            String::from(message),
+        )
+    };
+    if channels.is_empty() || channels.len() > u16::MAX as usize {
+        return Err(invalid_shape("number of channels is out of range"));
+    }
+    let nsamples = channels[0].len();
+    if channels.iter().any(|channel| channel.len() != nsamples) {
+        return Err(invalid_shape("channels should have the same length"));
+    }
+    let block_align = channels.len() * (bits as usize / 8);
+    nsamples
+        .checked_mul(block_align)
+        .filter(|size| *size <= u32::MAX as usize - 64)
+        .ok_or_else(|| invalid_shape("audio is too large for WAVE file"))
+}
+
+// Write the samples of each channel as PCM audio with the given sample rate
+// and encoding.
+pub(crate) fn write_wav<T, W>(
+    writer: &mut W,
+    channels: &[&[T]],
+    sample_rate: u32,
+    bits: u16,
+    float: bool,
+) -> Result<(), VectorLoadError>
+where
+    T: WavSample,
+    W: io::Write,
+{
+    let size = check_wav(channels, bits, float)?;
+    let nsamples = channels[0].len();
+    let block_align = channels.len() * (bits as usize / 8);
+
+    // Floating point samples require the fact chunk
+    let fmt_size: u32 = if float { 18 } else { 16 };
+    let fact_size: u32 = if float { 12 } else { 0 };
+    let riff_size =
+        4 + 8 + fmt_size + fact_size + 8 + size as u32 + size as u32 % 2;
+    let mut header = Vec::with_capacity(58);
+    header.extend_from_slice(b"RIFF");
+    header.extend_from_slice(&riff_size.to_le_bytes());
+    header.extend_from_slice(b"WAVE");
+    header.extend_from_slice(b"fmt ");
+    header.extend_from_slice(&fmt_size.to_le_bytes());
+    let code = if float { FORMAT_FLOAT } else { FORMAT_PCM };
+    header.extend_from_slice(&code.to_le_bytes());
+    header.extend_from_slice(&(channels.len() as u16).to_le_bytes());
+    header.extend_from_slice(&sample_rate.to_le_bytes());
+    let byte_rate = sample_rate.wrapping_mul(block_align as u32);
+    header.extend_from_slice(&byte_rate.to_le_bytes());
+    header.extend_from_slice(&(block_align as u16).to_le_bytes());
+    header.extend_from_slice(&bits.to_le_bytes());
+    if float {
+        header.extend_from_slice(&0u16.to_le_bytes());
+        header.extend_from_slice(b"fact");
+        header.extend_from_slice(&4u32.to_le_bytes());
+        header.extend_from_slice(&(nsamples as u32).to_le_bytes());
+    }
+    header.extend_from_slice(b"data");
+    header.extend_from_slice(&(size as u32).to_le_bytes());
+    writer.write_all(&header)?;
+
+    // Interleave the channels
+    let mut buf = Vec::with_capacity(block_align);
+    for i in 0..nsamples {
+        buf.clear();
+        for channel in channels {
+            encode(channel[i].to_normalized(), bits, float, &mut buf);
+        }
+        writer.write_all(&buf)?;
+    }
+    if size % 2 == 1 {
+        writer.write_all(&[0])?;
+    }
+    Ok(())
+}
@@ -0,0 +1,156 @@
+// Copyright (c) 2019, Bayu Aldi Yansyah <bayualdiyansyah@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crabsformer::prelude::*;
+use std::fs;
+
+#[test]
+fn test_save_load() {
+    let path = std::env::temp_dir().join("crabsformer_test_save_load.bin");
+
+    let w = matrix![0.1, -2.5e300; 3.0, f64::INFINITY; 0.0, -0.0];
+    w.save(&path).unwrap();
+    let v: Matrix<f64> = Matrix::load(&path).unwrap();
+    assert_eq!(v, w);
+
+    let w = matrix![u128::MAX, 0, 1];
+    w.save(&path).unwrap();
+    let v: Matrix<u128> = Matrix::load(&path).unwrap();
+    assert_eq!(v, w);
+
+    let x = vector![i8::MIN, 0, i8::MAX];
+    x.save(&path).unwrap();
+    let y: Vector<i8> = Vector::load(&path).unwrap();
+    assert_eq!(y, x);
+
+    // Empty vector
+    let x: Vector<usize> = Vector::from(Vec::new());
+    x.save(&path).unwrap();
+    let y: Vector<usize> = Vector::load(&path).unwrap();
+    assert_eq!(y.len(), 0);
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_save_format() {
+    let path = std::env::temp_dir().join("crabsformer_test_format.bin");
+
+    let w = matrix![1u16, 2, 3; 4, 5, 6];
+    w.save(&path).unwrap();
+    let bytes = fs::read(&path).unwrap();
+    assert_eq!(bytes[..8], *b"CRABSFMT");
+    assert_eq!(
+        bytes[8..13],
+        [1, cfg!(target_endian = "big") as u8, 2, 2, 3]
+    );
+    assert_eq!(bytes[13..16], *b"u16");
+    assert_eq!(bytes[16..24], 2u64.to_le_bytes());
+    assert_eq!(bytes[24..32], 3u64.to_le_bytes());
+    assert_eq!(bytes[32..34], 1u16.to_ne_bytes());
+    assert_eq!(bytes.len(), 32 + 6 * 2 + 4);
+
+    // Elements in the other byte order are converted
+    let mut bytes = bytes;
+    bytes[9] = 1 - bytes[9];
+    for pair in bytes[32..44].chunks_mut(2) {
+        pair.swap(0, 1);
+    }
+    let n = bytes.len() - 4;
+    let crc = crc32(&bytes[..n]);
+    bytes[n..].copy_from_slice(&crc.to_le_bytes());
+    fs::write(&path, &bytes).unwrap();
+    let v: Matrix<u16> = Matrix::load(&path).unwrap();
+    assert_eq!(v, w);
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_load_invalid() {
+    let path = std::env::temp_dir().join("crabsformer_test_invalid.bin");
+    let w = matrix![1.0f32, 2.0; 3.0, 4.0];
+    w.save(&path).unwrap();
+    let bytes = fs::read(&path).unwrap();
+
+    // Element type does not match
+    let result: Result<Matrix<f64>, MatrixLoadError> = Matrix::load(&path);
+    match result.unwrap_err().kind() {
+        MatrixLoadErrorKind::InvalidDataType => (),
+        _ => panic!("expected invalid data type"),
+    }
+    let result: Result<Matrix<i32>, MatrixLoadError> = Matrix::load(&path);
+    match result.unwrap_err().kind() {
+        MatrixLoadErrorKind::InvalidDataType => (),
+        _ => panic!("expected invalid data type"),
+    }
+
+    // Number of dimensions does not match
+    let result: Result<Vector<f32>, VectorLoadError> = Vector::load(&path);
+    match result.unwrap_err().kind() {
+        VectorLoadErrorKind::InvalidShape => (),
+        _ => panic!("expected invalid shape"),
+    }
+
+    // Corrupted element
+    let mut corrupted = bytes.clone();
+    corrupted[40] ^= 1;
+    fs::write(&path, &corrupted).unwrap();
+    let result: Result<Matrix<f32>, MatrixLoadError> = Matrix::load(&path);
+    match result.unwrap_err().kind() {
+        MatrixLoadErrorKind::InvalidFormat => (),
+        _ => panic!("expected invalid format"),
+    }
+
+    // Truncated file
+    fs::write(&path, &bytes[..bytes.len() - 1]).unwrap();
+    let result: Result<Matrix<f32>, MatrixLoadError> = Matrix::load(&path);
+    match result.unwrap_err().kind() {
+        MatrixLoadErrorKind::InvalidFormat => (),
+        _ => panic!("expected invalid format"),
+    }
+
+    // Trailing data
+    let mut extended = bytes.clone();
+    extended.push(0);
+    fs::write(&path, &extended).unwrap();
+    let result: Result<Matrix<f32>, MatrixLoadError> = Matrix::load(&path);
+    assert!(result.is_err());
+
+    // Not a native binary file
+    let result: Result<Matrix<i32>, MatrixLoadError> =
+        Matrix::load("tests/data/matrix.npy");
+    match result.unwrap_err().kind() {
+        MatrixLoadErrorKind::InvalidFormat => (),
+        _ => panic!("expected invalid format"),
+    }
+
+    fs::remove_file(&path).unwrap();
+}
+
+// CRC-32 checksum (IEEE 802.3)
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for byte in data {
+        crc ^= u32::from(*byte);
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                0xedb8_8320 ^ (crc >> 1)
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
@@ -303,3 +303,724 @@ fn test_eye() {
     let a: Matrix<i32> = Matrix::eye([2, 2]);
     assert_eq!(a, matrix![1, 0; 0, 1]);
 }
+
+#[test]
+fn test_from_fn() {
+    let a = Matrix::from_fn([2, 3], |i, j| i + j);
+    assert_eq!(a, matrix![0, 1, 2; 1, 2, 3]);
+
+    let b = Matrix::from_fn([2, 2], |i, j| (i as f64) * 0.5 - (j as f64));
+    assert_eq!(b, matrix![0.0, -1.0; 0.5, -0.5]);
+
+    // Stateful closure
+    let mut counter = 0;
+    let c = Matrix::from_fn([2, 2], |_, _| {
+        counter += 1;
+        counter
+    });
+    assert_eq!(c, matrix![1, 2; 3, 4]);
+}
+
+#[test]
+fn test_meshgrid() {
+    let x = vector![0.0, 0.5, 1.0];
+    let y = vector![-1.0, 1.0];
+
+    let (xx, yy) = Matrix::meshgrid(&x, &y, MeshgridIndexing::XY);
+    assert_eq!(xx.shape(), [2, 3]);
+    assert_eq!(xx, matrix![0.0, 0.5, 1.0; 0.0, 0.5, 1.0]);
+    assert_eq!(yy, matrix![-1.0, -1.0, -1.0; 1.0, 1.0, 1.0]);
+
+    let (xx, yy) = Matrix::meshgrid(&x, &y, MeshgridIndexing::IJ);
+    assert_eq!(xx.shape(), [3, 2]);
+    assert_eq!(xx, matrix![0.0, 0.0; 0.5, 0.5; 1.0, 1.0]);
+    assert_eq!(yy, matrix![-1.0, 1.0; -1.0, 1.0; -1.0, 1.0]);
+}
+
+#[test]
+fn test_indices() {
+    let (rows, cols) = Matrix::indices([3, 2]);
+    assert_eq!(rows, matrix![0, 0; 1, 1; 2, 2]);
+    assert_eq!(cols, matrix![0, 1; 0, 1; 0, 1]);
+}
+
+#[test]
+fn test_diag() {
+    let a = Matrix::diag(&vector![3, 1, 4]);
+    assert_eq!(a, matrix![3, 0, 0; 0, 1, 0; 0, 0, 4]);
+
+    let b = Matrix::diag(&vector![2.5]);
+    assert_eq!(b, matrix![2.5]);
+}
+
+#[test]
+fn test_diagonal() {
+    let w = matrix![
+        1, 2, 3, 4;
+        5, 6, 7, 8;
+        9, 10, 11, 12;
+    ];
+    assert_eq!(w.diagonal(0), vector![1, 6, 11]);
+    assert_eq!(w.diagonal(1), vector![2, 7, 12]);
+    assert_eq!(w.diagonal(3), vector![4]);
+    assert_eq!(w.diagonal(-1), vector![5, 10]);
+    assert_eq!(w.diagonal(4).len(), 0);
+    assert_eq!(w.diagonal(-3).len(), 0);
+
+    // diag and diagonal are inverse of each other
+    let v = vector![1.0, 2.0, 3.0];
+    assert_eq!(Matrix::diag(&v).diagonal(0), v);
+}
+
+#[test]
+fn test_tri() {
+    let a: Matrix<i32> = Matrix::tri([3, 4], 0);
+    assert_eq!(a, matrix![1, 0, 0, 0; 1, 1, 0, 0; 1, 1, 1, 0]);
+
+    let b: Matrix<f64> = Matrix::tri([3, 3], 1);
+    assert_eq!(b, matrix![1.0, 1.0, 0.0; 1.0, 1.0, 1.0; 1.0, 1.0, 1.0]);
+
+    let c: Matrix<u8> = Matrix::tri([3, 3], -1);
+    assert_eq!(c, matrix![0, 0, 0; 1, 0, 0; 1, 1, 0]);
+}
+
+#[test]
+fn test_tril() {
+    let w = matrix![
+        1, 2, 3;
+        4, 5, 6;
+        7, 8, 9;
+        10, 11, 12;
+    ];
+    assert_eq!(w.tril(0), matrix![1, 0, 0; 4, 5, 0; 7, 8, 9; 10, 11, 12]);
+    assert_eq!(w.tril(1), matrix![1, 2, 0; 4, 5, 6; 7, 8, 9; 10, 11, 12]);
+    assert_eq!(w.tril(-2), matrix![0, 0, 0; 0, 0, 0; 7, 0, 0; 10, 11, 0]);
+}
+
+#[test]
+fn test_triu() {
+    let w = matrix![
+        1, 2, 3;
+        4, 5, 6;
+        7, 8, 9;
+        10, 11, 12;
+    ];
+    assert_eq!(w.triu(0), matrix![1, 2, 3; 0, 5, 6; 0, 0, 9; 0, 0, 0]);
+    assert_eq!(w.triu(-1), matrix![1, 2, 3; 4, 5, 6; 0, 8, 9; 0, 0, 12]);
+    assert_eq!(w.triu(2), matrix![0, 0, 3; 0, 0, 0; 0, 0, 0; 0, 0, 0]);
+}
+
+#[test]
+fn test_vander() {
+    let x = vector![1.0, 2.0, 3.0, 5.0];
+    let a = Matrix::vander(&x, 3, false);
+    assert_eq!(
+        a,
+        matrix![
+            1.0, 1.0, 1.0;
+            4.0, 2.0, 1.0;
+            9.0, 3.0, 1.0;
+            25.0, 5.0, 1.0;
+        ]
+    );
+
+    let b = Matrix::vander(&x, 4, true);
+    assert_eq!(
+        b,
+        matrix![
+            1.0, 1.0, 1.0, 1.0;
+            1.0, 2.0, 4.0, 8.0;
+            1.0, 3.0, 9.0, 27.0;
+            1.0, 5.0, 25.0, 125.0;
+        ]
+    );
+}
+
+#[test]
+fn test_toeplitz() {
+    let c = vector![1, 2, 3, 4];
+    let r = vector![1, 5, 6];
+    assert_eq!(
+        Matrix::toeplitz(&c, &r),
+        matrix![
+            1, 5, 6;
+            2, 1, 5;
+            3, 2, 1;
+            4, 3, 2;
+        ]
+    );
+
+    // Symmetric Toeplitz matrix
+    let c = vector![1.0, 2.0, 3.0];
+    assert_eq!(
+        Matrix::toeplitz(&c, &c),
+        matrix![1.0, 2.0, 3.0; 2.0, 1.0, 2.0; 3.0, 2.0, 1.0]
+    );
+}
+
+#[test]
+fn test_hankel() {
+    let c = vector![1, 2];
+    let r = vector![2, 7, 8, 9];
+    assert_eq!(
+        Matrix::hankel(&c, &r),
+        matrix![
+            1, 2, 7, 8;
+            2, 7, 8, 9;
+        ]
+    );
+}
+
+#[test]
+fn test_circulant() {
+    let c = vector![1, 2, 3, 4];
+    assert_eq!(
+        Matrix::circulant(&c),
+        matrix![
+            1, 4, 3, 2;
+            2, 1, 4, 3;
+            3, 2, 1, 4;
+            4, 3, 2, 1;
+        ]
+    );
+}
+
+#[test]
+fn test_block_diag() {
+    let a = matrix![1, 2; 3, 4];
+    let b = matrix![5, 6, 7];
+    let c = matrix![8];
+    let w = Matrix::block_diag(&[&a, &b, &c]);
+    assert_eq!(
+        w,
+        matrix![
+            1, 2, 0, 0, 0, 0;
+            3, 4, 0, 0, 0, 0;
+            0, 0, 5, 6, 7, 0;
+            0, 0, 0, 0, 0, 8;
+        ]
+    );
+}
+
+#[test]
+fn test_distributions() {
+    let mut rmb = RandomMatrixBuilder::new();
+
+    let a: Matrix<f32> = rmb.normal([3, 2], 0.0, 1.0).unwrap();
+    assert_eq!(a.shape(), [3, 2]);
+
+    let b: Matrix<f64> = rmb.log_normal([3, 2], 0.0, 1.0).unwrap();
+    assert_eq!(b.shape(), [3, 2]);
+
+    let c: Matrix<f64> = rmb.exponential([3, 2], 1.0).unwrap();
+    assert_eq!(c.shape(), [3, 2]);
+
+    let d: Matrix<f64> = rmb.gamma([3, 2], 1.0, 2.0).unwrap();
+    assert_eq!(d.shape(), [3, 2]);
+
+    let e: Matrix<f32> = rmb.beta([3, 2], 1.0, 2.0).unwrap();
+    for row in e.rows() {
+        for value in row.elements() {
+            assert!((0.0..=1.0).contains(&value));
+        }
+    }
+
+    let f: Matrix<f64> = rmb.chi_squared([3, 2], 2.0).unwrap();
+    assert_eq!(f.shape(), [3, 2]);
+
+    let g: Matrix<f64> = rmb.student_t([3, 2], 2.0).unwrap();
+    assert_eq!(g.shape(), [3, 2]);
+
+    let h = rmb.binomial([3, 2], 4, 1.0).unwrap();
+    assert_eq!(h, matrix![[3, 2] => 4]);
+
+    let i = rmb.poisson([3, 2], 2.0).unwrap();
+    assert_eq!(i.shape(), [3, 2]);
+
+    let j = rmb.geometric([3, 2], 1.0).unwrap();
+    assert_eq!(j, matrix![[3, 2] => 1]);
+
+    let k: Matrix<u8> = rmb.bernoulli([3, 2], 0.0).unwrap();
+    assert_eq!(k, matrix![[3, 2] => 0]);
+
+    let l = rmb.categorical([3, 2], &vector![0.0, 1.0]).unwrap();
+    assert_eq!(l, matrix![[3, 2] => 1]);
+}
+
+#[test]
+fn test_distributions_invalid_parameter() {
+    let mut rmb = RandomMatrixBuilder::new();
+
+    assert!(rmb.normal([2, 2], 0.0, -1.0).is_err());
+    assert!(rmb.log_normal([2, 2], 0.0, -1.0).is_err());
+    assert!(rmb.exponential([2, 2], 0.0).is_err());
+    assert!(rmb.gamma([2, 2], -1.0, 1.0).is_err());
+    assert!(rmb.beta([2, 2], 1.0, -1.0).is_err());
+    assert!(rmb.chi_squared([2, 2], 0.0).is_err());
+    assert!(rmb.student_t([2, 2], -2.0).is_err());
+    assert!(rmb.binomial([2, 2], 4, 1.5).is_err());
+    assert!(rmb.poisson([2, 2], -2.0).is_err());
+    assert!(rmb.geometric([2, 2], 0.0).is_err());
+    assert!(rmb.categorical([2, 2], &vector![0.0]).is_err());
+}
+
+#[test]
+fn test_shuffle_rows() {
+    let mut rmb = RandomMatrixBuilder::new();
+    let mut w = Matrix::from_fn([20, 3], |i, j| i * 10 + j);
+    rmb.shuffle_rows(&mut w);
+    assert_eq!(w.shape(), [20, 3]);
+
+    // Each row is kept intact
+    let mut first_col = Vec::new();
+    for row in w.rows() {
+        let elements: Vec<usize> = row.elements().collect();
+        assert_eq!(elements[1], elements[0] + 1);
+        assert_eq!(elements[2], elements[0] + 2);
+        first_col.push(elements[0]);
+    }
+    first_col.sort();
+    assert_eq!(first_col, (0..20).map(|i| i * 10).collect::<Vec<usize>>());
+
+    // Reproducible with the same seed
+    let mut rmb1 = RandomMatrixBuilder::new();
+    let mut rmb2 = RandomMatrixBuilder::new();
+    rmb1.seed(3);
+    rmb2.seed(3);
+    let mut a = Matrix::from_fn([10, 2], |i, j| i + j);
+    let mut b = Matrix::from_fn([10, 2], |i, j| i + j);
+    rmb1.shuffle_rows(&mut a);
+    rmb2.shuffle_rows(&mut b);
+    assert_eq!(a, b);
+}
+
+#[test]
+fn test_multivariate_normal() {
+    let mut rmb = RandomMatrixBuilder::new();
+    rmb.seed(1);
+
+    let mean = vector![1.0, -2.0];
+    let cov = matrix![2.0, 0.6; 0.6, 1.0];
+    let n = 20000;
+    let w = rmb.multivariate_normal(&mean, &cov, n).unwrap();
+    assert_eq!(w.shape(), [n, 2]);
+
+    // Sample mean and covariance are close to the parameters
+    let m0 = w.col(0).elements().sum::<f64>() / n as f64;
+    let m1 = w.col(1).elements().sum::<f64>() / n as f64;
+    assert!((m0 - 1.0).abs() < 0.05);
+    assert!((m1 + 2.0).abs() < 0.05);
+    let mut c = [0.0; 3];
+    for row in w.rows() {
+        let x: Vec<f64> = row.elements().collect();
+        c[0] += (x[0] - m0) * (x[0] - m0);
+        c[1] += (x[0] - m0) * (x[1] - m1);
+        c[2] += (x[1] - m1) * (x[1] - m1);
+    }
+    assert!((c[0] / n as f64 - 2.0).abs() < 0.1);
+    assert!((c[1] / n as f64 - 0.6).abs() < 0.1);
+    assert!((c[2] / n as f64 - 1.0).abs() < 0.1);
+
+    // Positive semi-definite covariance matrix
+    let cov = matrix![1.0, 1.0; 1.0, 1.0];
+    let w = rmb
+        .multivariate_normal(&vector![0.0, 0.0], &cov, 10)
+        .unwrap();
+    for row in w.rows() {
+        let x: Vec<f64> = row.elements().collect();
+        assert!((x[0] - x[1]).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn test_multivariate_normal_invalid() {
+    let mut rmb = RandomMatrixBuilder::new();
+    let mean = vector![0.0, 0.0];
+
+    // Shape mismatch
+    let cov = matrix![1.0, 0.0, 0.0; 0.0, 1.0, 0.0; 0.0, 0.0, 1.0];
+    assert!(rmb.multivariate_normal(&mean, &cov, 5).is_err());
+
+    // Not symmetric
+    let cov = matrix![1.0, 0.5; 0.0, 1.0];
+    assert!(rmb.multivariate_normal(&mean, &cov, 5).is_err());
+
+    // Not positive semi-definite
+    let cov = matrix![1.0, 2.0; 2.0, 1.0];
+    assert!(rmb.multivariate_normal(&mean, &cov, 5).is_err());
+}
+
+#[test]
+fn test_wishart() {
+    let mut rmb = RandomMatrixBuilder::new();
+    let scale = matrix![2.0, 0.5, 0.0; 0.5, 1.0, 0.2; 0.0, 0.2, 1.5];
+    let w = rmb.wishart(&scale, 4.0).unwrap();
+    assert_eq!(w.shape(), [3, 3]);
+    for i in 0..3 {
+        assert!(*w.at(i, i) > 0.0);
+        for j in 0..3 {
+            assert!((w.at(i, j) - w.at(j, i)).abs() < 1e-12);
+        }
+    }
+
+    // Invalid degrees of freedom
+    assert!(rmb.wishart(&scale, 2.0).is_err());
+    let empty: Matrix<f64> = "[]".parse().unwrap();
+    assert!(rmb.wishart(&empty, -2.0).is_err());
+    // Invalid scale matrix
+    let singular = matrix![1.0, 1.0; 1.0, 1.0];
+    assert!(rmb.wishart(&singular, 4.0).is_err());
+    let non_square = matrix![1.0, 0.0, 0.0; 0.0, 1.0, 0.0];
+    assert!(rmb.wishart(&non_square, 4.0).is_err());
+}
+
+#[test]
+fn test_orthogonal() {
+    let mut rmb = RandomMatrixBuilder::new();
+    let q = rmb.orthogonal(5);
+    assert_eq!(q.shape(), [5, 5]);
+    // Q^T * Q = I
+    for i in 0..5 {
+        for j in 0..5 {
+            let dot: f64 = q
+                .col(i)
+                .elements()
+                .zip(q.col(j).elements())
+                .map(|(a, b)| a * b)
+                .sum();
+            let expected = if i == j { 1.0 } else { 0.0 };
+            assert!((dot - expected).abs() < 1e-10);
+        }
+    }
+}
+
+#[test]
+fn test_symmetric_positive_definite() {
+    let mut rmb = RandomMatrixBuilder::new();
+    let w = rmb.symmetric_positive_definite(4);
+    assert_eq!(w.shape(), [4, 4]);
+    for i in 0..4 {
+        for j in 0..4 {
+            assert!((w.at(i, j) - w.at(j, i)).abs() < 1e-12);
+        }
+    }
+
+    // x^T * W * x > 0 for any non-zero x
+    let x = rmb.normal([4, 10], 0.0, 1.0).unwrap();
+    for c in x.cols() {
+        let v: Vec<f64> = c.elements().collect();
+        let mut quadratic_form = 0.0;
+        for i in 0..4 {
+            for j in 0..4 {
+                quadratic_form += v[i] * w.at(i, j) * v[j];
+            }
+        }
+        assert!(quadratic_form > 0.0);
+    }
+
+    // It is a valid covariance matrix
+    let mean = vector![0.0; 4];
+    assert!(rmb.multivariate_normal(&mean, &w, 5).is_ok());
+}
+
+#[test]
+fn test_sparse() {
+    let mut rmb = RandomMatrixBuilder::new();
+    let w = rmb.sparse([10, 20], 0.25, 1.0, 2.0).unwrap();
+    assert_eq!(w.shape(), [10, 20]);
+    let mut nnz = 0;
+    for row in w.rows() {
+        for value in row.elements() {
+            if value != 0.0 {
+                assert!((1.0..2.0).contains(&value));
+                nnz += 1;
+            }
+        }
+    }
+    assert_eq!(nnz, 50);
+
+    let empty: Matrix<i32> = rmb.sparse([3, 3], 0.0, 1, 5).unwrap();
+    assert_eq!(empty, matrix![[3, 3] => 0]);
+
+    // Reproducible with the same seed
+    let mut rmb1 = RandomMatrixBuilder::new();
+    let mut rmb2 = RandomMatrixBuilder::new();
+    rmb1.seed(11);
+    rmb2.seed(11);
+    assert_eq!(
+        rmb1.sparse([5, 5], 0.4, 0.0, 1.0).unwrap(),
+        rmb2.sparse([5, 5], 0.4, 0.0, 1.0).unwrap()
+    );
+
+    assert!(rmb.sparse([3, 3], 1.5, 1, 5).is_err());
+    assert!(rmb.sparse([3, 3], 0.5, 5, 1).is_err());
+}
+
+#[test]
+fn test_sobol() {
+    let mut qmb = QuasiRandomMatrixBuilder::new();
+    let w = qmb.sobol(8, 3).unwrap();
+    assert_eq!(
+        w,
+        matrix![
+            0.0, 0.0, 0.0;
+            0.5, 0.5, 0.5;
+            0.75, 0.25, 0.25;
+            0.25, 0.75, 0.75;
+            0.375, 0.375, 0.625;
+            0.875, 0.875, 0.125;
+            0.625, 0.125, 0.875;
+            0.125, 0.625, 0.375;
+        ]
+    );
+
+    let w = qmb.sobol(64, 21).unwrap();
+    assert_eq!(w.shape(), [64, 21]);
+    for row in w.rows() {
+        for value in row.elements() {
+            assert!((0.0..1.0).contains(&value));
+        }
+    }
+
+    assert!(qmb.sobol(8, 0).is_err());
+    assert!(qmb.sobol(8, 22).is_err());
+}
+
+#[test]
+fn test_sobol_scramble() {
+    let mut qmb1 = QuasiRandomMatrixBuilder::new();
+    let mut qmb2 = QuasiRandomMatrixBuilder::new();
+    qmb1.scramble(true);
+    qmb2.scramble(true);
+    qmb1.seed(5);
+    qmb2.seed(5);
+    let a = qmb1.sobol(16, 4).unwrap();
+    let b = qmb2.sobol(16, 4).unwrap();
+    assert_eq!(a, b);
+    assert_ne!(a, QuasiRandomMatrixBuilder::new().sobol(16, 4).unwrap());
+
+    // Each of the 16 intervals of each dimension contains one point
+    for col in a.cols() {
+        let mut cells: Vec<usize> =
+            col.elements().map(|x| (x * 16.0) as usize).collect();
+        cells.sort();
+        assert_eq!(cells, (0..16).collect::<Vec<usize>>());
+    }
+}
+
+#[test]
+fn test_halton() {
+    let mut qmb = QuasiRandomMatrixBuilder::new();
+    let w = qmb.halton(4, 2).unwrap();
+    let expected = matrix![
+        0.0, 0.0;
+        0.5, 1.0 / 3.0;
+        0.25, 2.0 / 3.0;
+        0.75, 1.0 / 9.0;
+    ];
+    for i in 0..4 {
+        for j in 0..2 {
+            assert!((w.at(i, j) - expected.at(i, j)).abs() < 1e-12);
+        }
+    }
+
+    // Scrambled points are reproducible and still stratified
+    let mut qmb1 = QuasiRandomMatrixBuilder::new();
+    let mut qmb2 = QuasiRandomMatrixBuilder::new();
+    qmb1.scramble(true);
+    qmb2.scramble(true);
+    qmb1.seed(9);
+    qmb2.seed(9);
+    let a = qmb1.halton(9, 5).unwrap();
+    let b = qmb2.halton(9, 5).unwrap();
+    assert_eq!(a, b);
+    let mut cells: Vec<usize> = a
+        .col(1)
+        .elements()
+        .map(|x| (x * 9.0 + 1e-9) as usize)
+        .collect();
+    cells.sort();
+    assert_eq!(cells, (0..9).collect::<Vec<usize>>());
+    for col in a.cols() {
+        for value in col.elements() {
+            assert!((0.0..1.0).contains(&value));
+        }
+    }
+
+    // Scrambling also changes the base 2 dimension
+    let w = qmb.halton(8, 1).unwrap();
+    qmb1.seed(9);
+    let a = qmb1.halton(8, 1).unwrap();
+    assert_ne!(a.col(0), w.col(0));
+    let mut cells: Vec<usize> =
+        a.col(0).elements().map(|x| (x * 8.0) as usize).collect();
+    cells.sort();
+    assert_eq!(cells, (0..8).collect::<Vec<usize>>());
+
+    assert!(qmb.halton(4, 0).is_err());
+}
+
+#[test]
+fn test_latin_hypercube() {
+    let mut qmb = QuasiRandomMatrixBuilder::new();
+    let w = qmb.latin_hypercube(10, 3).unwrap();
+    assert_eq!(w.shape(), [10, 3]);
+    for col in w.cols() {
+        let mut cells: Vec<usize> =
+            col.elements().map(|x| (x * 10.0) as usize).collect();
+        cells.sort();
+        assert_eq!(cells, (0..10).collect::<Vec<usize>>());
+        // Centered points
+        for value in col.elements() {
+            assert!(((value * 10.0).fract() - 0.5).abs() < 1e-9);
+        }
+    }
+
+    // Scrambled points are reproducible
+    let mut qmb1 = QuasiRandomMatrixBuilder::new();
+    let mut qmb2 = QuasiRandomMatrixBuilder::new();
+    qmb1.scramble(true);
+    qmb2.scramble(true);
+    qmb1.seed(2);
+    qmb2.seed(2);
+    let a = qmb1.latin_hypercube(10, 2).unwrap();
+    assert_eq!(a, qmb2.latin_hypercube(10, 2).unwrap());
+    for col in a.cols() {
+        let mut cells: Vec<usize> =
+            col.elements().map(|x| (x * 10.0) as usize).collect();
+        cells.sort();
+        assert_eq!(cells, (0..10).collect::<Vec<usize>>());
+    }
+
+    // The cells are assigned randomly even without scrambling, so only
+    // seeded builders agree
+    let a = QuasiRandomMatrixBuilder::new()
+        .latin_hypercube(10, 3)
+        .unwrap();
+    let b = QuasiRandomMatrixBuilder::new()
+        .latin_hypercube(10, 3)
+        .unwrap();
+    assert_ne!(a, b);
+    let mut qmb1 = QuasiRandomMatrixBuilder::new();
+    let mut qmb2 = QuasiRandomMatrixBuilder::new();
+    qmb1.seed(3);
+    qmb2.seed(3);
+    let a = qmb1.latin_hypercube(10, 3).unwrap();
+    assert_eq!(a, qmb2.latin_hypercube(10, 3).unwrap());
+
+    assert!(qmb.latin_hypercube(10, 0).is_err());
+}
+
+#[test]
+fn test_state() {
+    let mut rmb = RandomMatrixBuilder::new();
+    rmb.seed(4);
+    let state = rmb.state();
+    let a = rmb.uniform([3, 3], 0.0, 1.0).unwrap();
+    rmb.set_state(&state);
+    assert_eq!(rmb.uniform([3, 3], 0.0, 1.0).unwrap(), a);
+
+    // Split
+    let mut rmb1 = RandomMatrixBuilder::new();
+    let mut rmb2 = RandomMatrixBuilder::new();
+    rmb1.seed(4);
+    rmb2.seed(4);
+    let mut child = rmb1.split();
+    assert_eq!(child.uniform([3, 3], 0.0, 1.0).unwrap(), a);
+    rmb2.jump();
+    assert_eq!(rmb1.state(), rmb2.state());
+
+    // Quasi-random scrambling
+    let mut qmb = QuasiRandomMatrixBuilder::new();
+    qmb.scramble(true);
+    let state = qmb.state();
+    let a = qmb.sobol(8, 2).unwrap();
+    qmb.set_state(&state);
+    assert_eq!(qmb.sobol(8, 2).unwrap(), a);
+}
+
+#[test]
+fn test_parse() {
+    let w: Matrix<i32> = "1 2 3; 4 5 6".parse().unwrap();
+    assert_eq!(w, matrix![1, 2, 3; 4, 5, 6]);
+
+    let w: Matrix<i32> = "[1, 2, 3; 4, 5, 6;]".parse().unwrap();
+    assert_eq!(w, matrix![1, 2, 3; 4, 5, 6]);
+
+    // Rows separated by new lines
+    let w: Matrix<f64> = "\n  1.5 -2\n  3e2 4\n".parse().unwrap();
+    assert_eq!(w, matrix![1.5, -2.0; 300.0, 4.0]);
+
+    let w: Matrix<u8> = "[[1,2],[3,4]]".parse().unwrap();
+    assert_eq!(w, matrix![1, 2; 3, 4]);
+
+    // NumPy print format
+    let w: Matrix<f32> = "[[1.  2.5]\n [3.  4. ]]".parse().unwrap();
+    assert_eq!(w, matrix![1.0, 2.5; 3.0, 4.0]);
+
+    let w: Matrix<i64> = "[[1, 2],\n [3, 4],]".parse().unwrap();
+    assert_eq!(w, matrix![1, 2; 3, 4]);
+
+    // Single row
+    let w: Matrix<i32> = "[1, 2, 3]".parse().unwrap();
+    assert_eq!(w, matrix![1, 2, 3]);
+
+    let w: Matrix<i32> = "[]".parse().unwrap();
+    assert_eq!(w.shape(), [0, 0]);
+}
+
+#[test]
+fn test_parse_invalid() {
+    let parse = |s: &str| s.parse::<Matrix<i32>>().unwrap_err();
+
+    let error = parse("  \n ");
+    match error.kind() {
+        MatrixParseErrorKind::Empty => (),
+        _ => panic!("expected empty"),
+    }
+
+    let error = parse("1 2; 3 x");
+    match error.kind() {
+        MatrixParseErrorKind::InvalidElement => (),
+        _ => panic!("expected invalid element"),
+    }
+    assert_eq!(error.position(), 7);
+
+    let error = parse("1 2; 3");
+    match error.kind() {
+        MatrixParseErrorKind::InvalidShape => (),
+        _ => panic!("expected invalid shape"),
+    }
+    assert_eq!(error.position(), 5);
+
+    let error = parse("[[1, 2], [3]]");
+    match error.kind() {
+        MatrixParseErrorKind::InvalidShape => (),
+        _ => panic!("expected invalid shape"),
+    }
+    assert_eq!(error.position(), 9);
+
+    let error = parse("[[1, 2], [3, 4]");
+    match error.kind() {
+        MatrixParseErrorKind::InvalidSyntax => (),
+        _ => panic!("expected invalid syntax"),
+    }
+    assert_eq!(error.position(), 15);
+
+    let error = parse("[1, 2] 3");
+    match error.kind() {
+        MatrixParseErrorKind::InvalidSyntax => (),
+        _ => panic!("expected invalid syntax"),
+    }
+    assert_eq!(error.position(), 7);
+
+    assert_eq!(parse("1,,2").position(), 2);
+    assert_eq!(parse("[[1, 2] [3, [4]]]").position(), 12);
+    assert_eq!(parse("1 2]").position(), 3);
+
+    let error = format!("{}", parse("1 2; 3 x"));
+    assert_eq!(
+        error,
+        "Cannot parse Matrix, invalid element: `x` at position 7"
+    );
+}
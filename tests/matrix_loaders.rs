@@ -88,3 +88,1039 @@ fn test_load_valid_csv_with_header() {
         ]
     );
 }
+
+#[test]
+fn test_write_csv() {
+    let w = matrix![
+        1.5, -2.0;
+        3.25, 4.0;
+    ];
+
+    // Default
+    let mut buf = Vec::new();
+    w.to_csv_writer(&mut buf).write().unwrap();
+    assert_eq!(String::from_utf8(buf).unwrap(), "1.5,-2\n3.25,4\n");
+
+    // Headers, delimiter and precision
+    let mut buf = Vec::new();
+    w.to_csv_writer(&mut buf)
+        .headers(&["a", "b c"])
+        .delimiter(b';')
+        .precision(1)
+        .write()
+        .unwrap();
+    assert_eq!(
+        String::from_utf8(buf).unwrap(),
+        "a;b c\n1.5;-2.0\n3.2;4.0\n"
+    );
+
+    // Default header names
+    let mut buf = Vec::new();
+    w.to_csv_writer(&mut buf).has_headers(true).write().unwrap();
+    assert_eq!(String::from_utf8(buf).unwrap(), "0,1\n1.5,-2\n3.25,4\n");
+
+    // Headers can be disabled again
+    let mut buf = Vec::new();
+    w.to_csv_writer(&mut buf)
+        .headers(&["a", "b"])
+        .has_headers(false)
+        .write()
+        .unwrap();
+    assert_eq!(String::from_utf8(buf).unwrap(), "1.5,-2\n3.25,4\n");
+
+    // Scientific notation
+    let mut buf = Vec::new();
+    w.to_csv_writer(&mut buf)
+        .scientific(true)
+        .precision(2)
+        .write()
+        .unwrap();
+    assert_eq!(
+        String::from_utf8(buf).unwrap(),
+        "1.50e0,-2.00e0\n3.25e0,4.00e0\n"
+    );
+
+    // Invalid number of header names
+    let mut buf = Vec::new();
+    let result = w.to_csv_writer(&mut buf).headers(&["a"]).write();
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_write_csv_round_trip() {
+    let path = std::env::temp_dir().join("crabsformer_test_matrix.csv");
+
+    let w = matrix![
+        0.1, 1.0 / 3.0, -1e-10;
+        2.5e20, 7.0, 0.0;
+    ];
+    w.to_csv(&path).headers(&["x", "y", "z"]).write().unwrap();
+    let v: Matrix<f64> =
+        Matrix::from_csv(&path).has_headers(true).load().unwrap();
+    assert_eq!(v, w);
+
+    let w = matrix![-1, 2, 3; 4, -5, 6];
+    w.to_csv(&path).write().unwrap();
+    let v: Matrix<i32> = Matrix::from_csv(&path).load().unwrap();
+    assert_eq!(v, w);
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_write_csv_invalid_path() {
+    let w = matrix![1, 2; 3, 4];
+    let result = w.to_csv("tests/data/not_found/matrix.csv").write();
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_load_csv_options() {
+    // Comment, skip rows, delimiter and quoting
+    let w: Matrix<f64> = Matrix::from_csv("tests/data/matrix_options.csv")
+        .comment(Some(b'#'))
+        .skip_rows(1)
+        .delimiter(b';')
+        .has_headers(true)
+        .load()
+        .unwrap();
+    assert_eq!(
+        w,
+        matrix![
+            1.5, 2.0, 3.0;
+            4.0, 5.5, 6.0;
+            7.0, 8.0, 9.0;
+        ]
+    );
+
+    // Columns by name and max rows
+    let w: Matrix<f64> = Matrix::from_csv("tests/data/matrix_options.csv")
+        .comment(Some(b'#'))
+        .skip_rows(1)
+        .delimiter(b';')
+        .usecols_by_name(&["z", "y"])
+        .max_rows(2)
+        .load()
+        .unwrap();
+    assert_eq!(w, matrix![3.0, 2.0; 6.0, 5.5]);
+
+    // Columns by index
+    let w: Matrix<i32> =
+        Matrix::from_csv("tests/data/matrix_valid_without_header.csv")
+            .usecols(&[1, 1])
+            .skip_rows(1)
+            .load()
+            .unwrap();
+    assert_eq!(w, matrix![-5, -5; 8, 8]);
+
+    // Without quoting the quotes are part of the field
+    let result: Result<Matrix<f64>, MatrixLoadError> =
+        Matrix::from_csv("tests/data/matrix_options.csv")
+            .comment(Some(b'#'))
+            .skip_rows(1)
+            .delimiter(b';')
+            .has_headers(true)
+            .quoting(false)
+            .load();
+    assert!(result.is_err());
+
+    // Flexible rows are padded
+    let w: Matrix<i32> =
+        Matrix::from_csv("tests/data/matrix_invalid_inconsistent_column.csv")
+            .flexible(true)
+            .padding(-1)
+            .load()
+            .unwrap();
+    assert_eq!(w, matrix![1, 2, 3; 4, 2, -1; 3, 4, 5]);
+    let w: Matrix<i32> =
+        Matrix::from_csv("tests/data/matrix_invalid_inconsistent_column.csv")
+            .flexible(true)
+            .usecols(&[2])
+            .load()
+            .unwrap();
+    assert_eq!(w, matrix![3; 0; 5]);
+}
+
+#[test]
+fn test_load_csv_options_invalid() {
+    // Header name not found
+    let result: Result<Matrix<f64>, MatrixLoadError> =
+        Matrix::from_csv("tests/data/dataset.csv")
+            .usecols_by_name(&["feature3"])
+            .load();
+    assert!(result.is_err());
+
+    // Column out of bounds
+    let result: Result<Matrix<i32>, MatrixLoadError> =
+        Matrix::from_csv("tests/data/matrix_valid_without_header.csv")
+            .usecols(&[3])
+            .load();
+    assert!(result.is_err());
+
+    // Without comments the comment lines are loaded
+    let result: Result<Matrix<f64>, MatrixLoadError> =
+        Matrix::from_csv("tests/data/matrix_options.csv")
+            .skip_rows(2)
+            .delimiter(b';')
+            .has_headers(true)
+            .load();
+    assert!(result.is_err());
+
+    // All rows are skipped
+    let result: Result<Matrix<i32>, MatrixLoadError> =
+        Matrix::from_csv("tests/data/matrix_valid_without_header.csv")
+            .skip_rows(3)
+            .load();
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_load_csv_missing_values() {
+    // Missing values returns an error by default
+    let result: Result<Matrix<f64>, MatrixLoadError> =
+        Matrix::from_csv("tests/data/matrix_missing.csv")
+            .has_headers(true)
+            .fill_missing(0.0)
+            .load();
+    assert!(result.is_err());
+    let result: Result<Matrix<f64>, MatrixLoadError> =
+        Matrix::from_csv("tests/data/matrix_missing.csv")
+            .has_headers(true)
+            .missing_values(&["", "NA"])
+            .load();
+    assert!(result.is_err());
+
+    // Fill with a constant
+    let w: Matrix<i32> = Matrix::from_csv("tests/data/matrix_missing.csv")
+        .has_headers(true)
+        .missing_values(&["", "NA"])
+        .fill_missing(-1)
+        .load()
+        .unwrap();
+    assert_eq!(w, matrix![1, -1, 3; 4, 5, 6; -1, 8, -1]);
+
+    // Fill with NaN
+    let w: Matrix<f32> = Matrix::from_csv("tests/data/matrix_missing.csv")
+        .has_headers(true)
+        .missing_values(&["", "NA"])
+        .fill_nan()
+        .load()
+        .unwrap();
+    assert!(w.at(0, 1).is_nan());
+    assert!(w.at(2, 0).is_nan());
+    assert!(w.at(2, 2).is_nan());
+    assert_eq!(*w.at(1, 1), 5.0);
+
+    // Drop rows
+    let w: Matrix<i32> = Matrix::from_csv("tests/data/matrix_missing.csv")
+        .has_headers(true)
+        .missing_values(&["", "NA"])
+        .drop_missing()
+        .load()
+        .unwrap();
+    assert_eq!(w, matrix![4, 5, 6]);
+
+    // Only the selected columns are checked
+    let w: Matrix<i32> = Matrix::from_csv("tests/data/matrix_missing.csv")
+        .usecols_by_name(&["b"])
+        .missing_values(&["NA"])
+        .drop_missing()
+        .load()
+        .unwrap();
+    assert_eq!(w, matrix![5; 8]);
+}
+
+#[test]
+fn test_load_csv_with_mask() {
+    let (w, mask): (Matrix<i32>, Matrix<u8>) =
+        Matrix::from_csv("tests/data/matrix_missing.csv")
+            .has_headers(true)
+            .missing_values(&["", "NA"])
+            .load_with_mask()
+            .unwrap();
+    assert_eq!(w, matrix![1, 0, 3; 4, 5, 6; 0, 8, 0]);
+    assert_eq!(mask, matrix![0, 1, 0; 0, 0, 0; 1, 0, 1]);
+
+    let (w, mask): (Matrix<i32>, Matrix<u8>) =
+        Matrix::from_csv("tests/data/matrix_missing.csv")
+            .has_headers(true)
+            .missing_values(&["", "NA"])
+            .drop_missing()
+            .load_with_mask()
+            .unwrap();
+    assert_eq!(w, matrix![4, 5, 6]);
+    assert_eq!(mask, matrix![0, 0, 0]);
+
+    // Padded fields are masked
+    let (w, mask): (Matrix<i32>, Matrix<u8>) =
+        Matrix::from_csv("tests/data/matrix_invalid_inconsistent_column.csv")
+            .flexible(true)
+            .padding(9)
+            .load_with_mask()
+            .unwrap();
+    assert_eq!(w, matrix![1, 2, 3; 4, 2, 9; 3, 4, 5]);
+    assert_eq!(mask, matrix![0, 0, 0; 0, 0, 1; 0, 0, 0]);
+}
+
+#[test]
+fn test_load_csv_reader() {
+    let file = std::fs::File::open("tests/data/dataset.csv").unwrap();
+    let w: Matrix<f32> = Matrix::from_csv_reader(file)
+        .has_headers(true)
+        .load()
+        .unwrap();
+    assert_eq!(w, matrix![1.0, 2.0; 3.0, 4.0; 5.0, 6.0]);
+
+    let data = String::from("# weights\nx;y\n1;NA\n3;4\n");
+    let (w, mask): (Matrix<i64>, Matrix<u8>) =
+        Matrix::from_csv_reader(std::io::Cursor::new(data))
+            .comment(Some(b'#'))
+            .delimiter(b';')
+            .has_headers(true)
+            .missing_values(&["NA"])
+            .load_with_mask()
+            .unwrap();
+    assert_eq!(w, matrix![1, 0; 3, 4]);
+    assert_eq!(mask, matrix![0, 1; 0, 0]);
+}
+
+#[test]
+fn test_load_csv_str() {
+    let w: Matrix<i32> = Matrix::from_csv_str("1,2,3\n4,5,6").load().unwrap();
+    assert_eq!(w, matrix![1, 2, 3; 4, 5, 6]);
+
+    let w: Matrix<f64> = Matrix::from_csv_str("a,b,c\n1,2,3\n4,5,6\n")
+        .usecols_by_name(&["c", "a"])
+        .load()
+        .unwrap();
+    assert_eq!(w, matrix![3.0, 1.0; 6.0, 4.0]);
+
+    // Round trip through the CSV writer
+    let w = matrix![0.1, -2.5; 1e-7, 3.0];
+    let mut buf = Vec::new();
+    w.to_csv_writer(&mut buf).has_headers(true).write().unwrap();
+    let data = String::from_utf8(buf).unwrap();
+    let v: Matrix<f64> = Matrix::from_csv_str(&data)
+        .has_headers(true)
+        .load()
+        .unwrap();
+    assert_eq!(v, w);
+
+    // Same errors as the file loader
+    let result: Result<Matrix<i32>, MatrixLoadError> =
+        Matrix::from_csv_str("").load();
+    assert!(result.is_err());
+    let result: Result<Matrix<u32>, MatrixLoadError> =
+        Matrix::from_csv_str("1,-2").load();
+    assert!(result.is_err());
+    let result: Result<Matrix<u32>, MatrixLoadError> =
+        Matrix::from_csv_str("1,2\n3").load();
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_load_csv_error_location() {
+    // Invalid element
+    let result: Result<Matrix<f64>, MatrixLoadError> =
+        Matrix::from_csv_str("x,y,z\n1,2,3\n\n4,oops,6\n")
+            .has_headers(true)
+            .load();
+    let error = result.unwrap_err();
+    match error.kind() {
+        MatrixLoadErrorKind::InvalidElement => (),
+        _ => panic!("expected invalid element"),
+    }
+    assert_eq!(error.line(), Some(4));
+    assert_eq!(error.record(), Some(3));
+    assert_eq!(error.column(), Some(2));
+    assert_eq!(error.header(), Some("y"));
+    assert!(error.errors().is_empty());
+    assert_eq!(
+        error.to_string(),
+        "Cannot load Matrix, invalid element: \"oops\" is not valid f64 \
+         (at line 4, record 3, column 2, header \"y\")"
+    );
+
+    // CRLF terminators and comments
+    let result: Result<Matrix<f64>, MatrixLoadError> =
+        Matrix::from_csv_str("# data\r\nx,y\r\n1,2\r\n# skip\r\n\r\n3,z\r\n")
+            .comment(Some(b'#'))
+            .has_headers(true)
+            .load();
+    let error = result.unwrap_err();
+    assert_eq!(error.line(), Some(6));
+    assert_eq!(error.record(), Some(3));
+    assert_eq!(error.column(), Some(2));
+
+    // Quoted fields spanning multiple lines
+    let result: Result<Matrix<f64>, MatrixLoadError> =
+        Matrix::from_csv_str("\"1\n\",2\n3,x\n").load();
+    let error = result.unwrap_err();
+    assert_eq!(error.line(), Some(3));
+
+    // Column numbers refer to the file, not the selected columns
+    let result: Result<Matrix<f64>, MatrixLoadError> =
+        Matrix::from_csv_str("1,2,3\n4,5,\n")
+            .usecols(&[2, 0])
+            .load();
+    let error = result.unwrap_err();
+    match error.kind() {
+        MatrixLoadErrorKind::MissingValue => (),
+        _ => panic!("expected missing value"),
+    }
+    assert_eq!(error.line(), Some(2));
+    assert_eq!(error.column(), Some(3));
+    assert_eq!(error.header(), None);
+
+    // Inconsistent row has no column
+    let result: Result<Matrix<i32>, MatrixLoadError> =
+        Matrix::from_csv("tests/data/matrix_invalid_inconsistent_column.csv")
+            .load();
+    let error = result.unwrap_err();
+    assert_eq!(error.line(), Some(2));
+    assert_eq!(error.record(), Some(2));
+    assert_eq!(error.column(), None);
+
+    // Errors without location
+    let result: Result<Matrix<i32>, MatrixLoadError> =
+        Matrix::from_csv("tests/data/random_file.csv").load();
+    let error = result.unwrap_err();
+    assert_eq!(error.line(), None);
+    assert_eq!(error.record(), None);
+    assert_eq!(error.column(), None);
+}
+
+#[test]
+fn test_load_csv_lenient() {
+    let result: Result<Matrix<u8>, MatrixLoadError> =
+        Matrix::from_csv_str("a,b,c\n1,x,3\n4,5\n-1,8,\n9,9,9\n")
+            .has_headers(true)
+            .lenient(true)
+            .load();
+    let error = result.unwrap_err();
+    let errors = error.errors();
+    assert_eq!(errors.len(), 4);
+    let locations: Vec<(Option<u64>, Option<usize>)> =
+        errors.iter().map(|e| (e.line(), e.column())).collect();
+    assert_eq!(
+        locations,
+        vec![
+            (Some(2), Some(2)),
+            (Some(3), None),
+            (Some(4), Some(1)),
+            (Some(4), Some(3))
+        ]
+    );
+    assert_eq!(errors[3].header(), Some("c"));
+    match errors[3].kind() {
+        MatrixLoadErrorKind::MissingValue => (),
+        _ => panic!("expected missing value"),
+    }
+
+    // The returned error is the first one
+    assert_eq!(error.line(), Some(2));
+    assert!(error.to_string().ends_with("and 3 more errors"));
+
+    // Without errors the matrix is loaded
+    let w: Matrix<u8> = Matrix::from_csv_str("1,2\n3,4")
+        .lenient(true)
+        .load()
+        .unwrap();
+    assert_eq!(w, matrix![1, 2; 3, 4]);
+}
+
+#[test]
+fn test_load_csv_parsing() {
+    // Floats in scientific notation, infinity and NaN
+    let w: Matrix<f64> =
+        Matrix::from_csv_str("1E+03,-inf,.5\nNaN,Infinity,2.5e-3\n")
+            .load()
+            .unwrap();
+    assert_eq!(*w.at(0, 0), 1000.0);
+    assert_eq!(*w.at(0, 1), f64::NEG_INFINITY);
+    assert_eq!(*w.at(0, 2), 0.5);
+    assert!(w.at(1, 0).is_nan());
+    assert_eq!(*w.at(1, 1), f64::INFINITY);
+    assert_eq!(*w.at(1, 2), 0.0025);
+
+    // Hexadecimal, octal and binary integers
+    let w: Matrix<i32> = Matrix::from_csv_str("0xFF,-0x10,0o17,0b101,+7")
+        .load()
+        .unwrap();
+    assert_eq!(w, matrix![255, -16, 15, 5, 7]);
+
+    // Spreadsheet exports with decimal commas and thousands separators
+    let data = "a;b\n\"1.234,5\";0,25\n-2;1.000\n";
+    let w: Matrix<f32> = Matrix::from_csv_str(data)
+        .has_headers(true)
+        .delimiter(b';')
+        .thousands(Some('.'))
+        .decimal(',')
+        .load()
+        .unwrap();
+    assert_eq!(w, matrix![1234.5, 0.25; -2.0, 1000.0]);
+
+    // Custom parser
+    let w: Matrix<u8> = Matrix::from_csv_str("yes,no\nno,yes")
+        .parser(|value| match value {
+            "yes" => Some(1),
+            "no" => Some(0),
+            _ => None,
+        })
+        .load()
+        .unwrap();
+    assert_eq!(w, matrix![1, 0; 0, 1]);
+
+    // Missing values are handled before the custom parser
+    let w: Matrix<f64> = Matrix::from_csv_str("1,NA\n2,3")
+        .missing_values(&["NA"])
+        .fill_missing(-1.0)
+        .parser(|value| value.parse().ok())
+        .load()
+        .unwrap();
+    assert_eq!(w, matrix![1.0, -1.0; 2.0, 3.0]);
+
+    // Invalid elements of the custom parser
+    let result: Result<Matrix<u8>, MatrixLoadError> =
+        Matrix::from_csv_str("yes,maybe")
+            .parser(|value| if value == "yes" { Some(1) } else { None })
+            .load();
+    let error = result.unwrap_err();
+    assert_eq!(error.column(), Some(2));
+}
+
+#[test]
+fn test_load_csv_chunks() {
+    let chunks: Vec<Matrix<i32>> =
+        Matrix::from_csv("tests/data/matrix_valid_without_header.csv")
+            .chunks(2)
+            .map(|chunk| chunk.unwrap())
+            .collect();
+    assert_eq!(chunks, vec![matrix![-1, 2, 3; 4, -5, 6], matrix![7, 8, -9]]);
+
+    // Chunk larger than the file
+    let chunks: Vec<Matrix<i32>> =
+        Matrix::from_csv("tests/data/matrix_valid_without_header.csv")
+            .chunks(10_000)
+            .map(|chunk| chunk.unwrap())
+            .collect();
+    let w: Matrix<i32> =
+        Matrix::from_csv("tests/data/matrix_valid_without_header.csv")
+            .load()
+            .unwrap();
+    assert_eq!(chunks, vec![w]);
+
+    // Options are applied across the chunks
+    let data = "# comment\nskip\na,b,c\n1,2,3\n4,NA,6\n7,8,9\n10,11,12\n";
+    let chunks: Vec<Matrix<f64>> = Matrix::from_csv_str(data)
+        .comment(Some(b'#'))
+        .skip_rows(1)
+        .has_headers(true)
+        .usecols_by_name(&["c", "b"])
+        .missing_values(&["NA"])
+        .drop_missing()
+        .max_rows(3)
+        .chunks(1)
+        .map(|chunk| chunk.unwrap())
+        .collect();
+    assert_eq!(chunks, vec![matrix![3.0, 2.0], matrix![9.0, 8.0]]);
+
+    // Flexible rows are padded to the widest row loaded so far
+    let chunks: Vec<Matrix<u8>> = Matrix::from_csv_str("1\n2,3\n4\n5,6,7")
+        .flexible(true)
+        .chunks(2)
+        .map(|chunk| chunk.unwrap())
+        .collect();
+    assert_eq!(chunks, vec![matrix![1, 0; 2, 3], matrix![4, 0, 0; 5, 6, 7]]);
+
+    // Empty file yields no chunks
+    let mut chunks = Matrix::<u8>::from_csv_str("").chunks(2);
+    assert!(chunks.next().is_none());
+}
+
+#[test]
+fn test_load_csv_chunks_invalid() {
+    // Loading stops at the first error
+    let mut chunks =
+        Matrix::<u8>::from_csv_str("1,2\n3,4\n5,x\n7,8\n").chunks(2);
+    assert!(chunks.next().unwrap().is_ok());
+    let error = chunks.next().unwrap().unwrap_err();
+    assert_eq!(error.line(), Some(3));
+    assert!(chunks.next().is_none());
+
+    // Lenient mode continues with the next chunk
+    let mut chunks = Matrix::<u8>::from_csv_str("1,2\n3,4\n5,x\n7,8\n9,9\n")
+        .lenient(true)
+        .chunks(2);
+    assert_eq!(chunks.next().unwrap().unwrap(), matrix![1, 2; 3, 4]);
+    let error = chunks.next().unwrap().unwrap_err();
+    assert_eq!(error.errors().len(), 1);
+    assert_eq!(chunks.next().unwrap().unwrap(), matrix![9, 9]);
+    assert!(chunks.next().is_none());
+
+    // Errors while opening the file
+    let mut chunks =
+        Matrix::<u8>::from_csv("tests/data/unknown.csv").chunks(2);
+    match chunks.next().unwrap().unwrap_err().kind() {
+        MatrixLoadErrorKind::IOError => (),
+        _ => panic!("expected IO error"),
+    }
+    assert!(chunks.next().is_none());
+}
+
+#[test]
+#[should_panic]
+fn test_load_csv_chunks_zero() {
+    let _chunks = Matrix::<u8>::from_csv_str("1,2").chunks(0);
+}
+
+#[test]
+fn test_load_mtx() {
+    let w: Matrix<i32> =
+        Matrix::from_mtx("tests/data/matrix_array.mtx").unwrap();
+    assert_eq!(w, matrix![1, 2, 3; 4, 5, 6]);
+
+    let w: Matrix<f64> =
+        Matrix::from_mtx("tests/data/matrix_symmetric.mtx").unwrap();
+    assert_eq!(w, matrix![1.0, 0.0, 2.5; 0.0, 3.0, 0.0; 2.5, 0.0, 4.0]);
+
+    let w: Matrix<f32> =
+        Matrix::from_mtx("tests/data/matrix_skew.mtx").unwrap();
+    assert_eq!(w, matrix![0.0, -1.5, 2.0; 1.5, 0.0, -0.5; -2.0, 0.5, 0.0]);
+
+    let w: Matrix<u8> =
+        Matrix::from_mtx("tests/data/matrix_pattern.mtx").unwrap();
+    assert_eq!(w, matrix![1, 1, 0; 0, 0, 1]);
+}
+
+#[test]
+fn test_load_mtx_invalid() {
+    let path = std::env::temp_dir().join("crabsformer_test_invalid.mtx");
+    let load = |content: &str| -> MatrixLoadError {
+        std::fs::write(&path, content).unwrap();
+        Matrix::<i32>::from_mtx(&path).unwrap_err()
+    };
+
+    let error =
+        load("%%MatrixMarket matrix array complex general\n1 1\n1 0\n");
+    match error.kind() {
+        MatrixLoadErrorKind::InvalidDataType => (),
+        _ => panic!("expected invalid data type"),
+    }
+
+    let error = load("1 2\n3 4\n");
+    match error.kind() {
+        MatrixLoadErrorKind::InvalidFormat => (),
+        _ => panic!("expected invalid format"),
+    }
+    assert_eq!(error.line(), Some(1));
+
+    let error = load("%%MatrixMarket matrix coordinate integer general\n2 2 2\n1 1 1\n3 1 2\n");
+    match error.kind() {
+        MatrixLoadErrorKind::InvalidFormat => (),
+        _ => panic!("expected invalid format"),
+    }
+    assert_eq!((error.line(), error.column()), (Some(4), Some(1)));
+
+    let error = load("%%MatrixMarket matrix coordinate integer general\n2 2 3\n1 1 1\n2 1 2\n");
+    assert!(error.to_string().contains("expected 3 entries, got 2"));
+
+    let error =
+        load("%%MatrixMarket matrix array integer general\n2 1\n1\n2\n3\n");
+    assert_eq!(error.line(), Some(5));
+
+    let error =
+        load("%%MatrixMarket matrix array integer general\n2 1\n1\n2.5\n");
+    match error.kind() {
+        MatrixLoadErrorKind::InvalidElement => (),
+        _ => panic!("expected invalid element"),
+    }
+    assert_eq!(error.line(), Some(4));
+
+    let error = load("%%MatrixMarket matrix array integer symmetric\n2 3\n");
+    match error.kind() {
+        MatrixLoadErrorKind::InvalidShape => (),
+        _ => panic!("expected invalid shape"),
+    }
+
+    let error = load("%%MatrixMarket matrix coordinate integer skew-symmetric\n2 2 1\n1 1 1\n");
+    match error.kind() {
+        MatrixLoadErrorKind::InvalidFormat => (),
+        _ => panic!("expected invalid format"),
+    }
+
+    // Skew-symmetric matrix of unsigned integers can not be negated
+    std::fs::write(
+        &path,
+        "%%MatrixMarket matrix array integer skew-symmetric\n2 2\n1\n",
+    )
+    .unwrap();
+    assert!(Matrix::<u32>::from_mtx(&path).is_err());
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_write_mtx_round_trip() {
+    let path = std::env::temp_dir().join("crabsformer_test_round_trip.mtx");
+
+    let w = matrix![0.1, 0.0, -3.5e-8; 0.0, 0.0, 2.0];
+    for format in &[MatrixMarketFormat::Array, MatrixMarketFormat::Coordinate]
+    {
+        w.to_mtx(&path).format(*format).write().unwrap();
+        let v: Matrix<f64> = Matrix::from_mtx(&path).unwrap();
+        assert_eq!(v, w);
+    }
+
+    let w = matrix![0, -2, 0; 2, 0, 3; 0, -3, 0];
+    let result = w
+        .to_mtx(&path)
+        .symmetry(MatrixMarketSymmetry::Symmetric)
+        .write();
+    assert!(result.is_err());
+    for format in &[MatrixMarketFormat::Array, MatrixMarketFormat::Coordinate]
+    {
+        w.to_mtx(&path)
+            .format(*format)
+            .symmetry(MatrixMarketSymmetry::SkewSymmetric)
+            .write()
+            .unwrap();
+        let v: Matrix<i64> = Matrix::from_mtx(&path).unwrap();
+        assert_eq!(v, w);
+    }
+    let content = std::fs::read_to_string(&path).unwrap();
+    assert_eq!(
+        content,
+        "%%MatrixMarket matrix coordinate integer skew-symmetric\n3 3 2\n2 1 2\n3 2 -3\n"
+    );
+
+    // Symmetric matrix should be square
+    let w = matrix![1, 2];
+    let result = w
+        .to_mtx(&path)
+        .symmetry(MatrixMarketSymmetry::Symmetric)
+        .write();
+    match result.unwrap_err().kind() {
+        MatrixLoadErrorKind::InvalidShape => (),
+        _ => panic!("expected invalid shape"),
+    }
+
+    // Precision
+    let w = matrix![1.0 / 3.0];
+    w.to_mtx(&path).precision(3).write().unwrap();
+    let content = std::fs::read_to_string(&path).unwrap();
+    assert!(content.ends_with("\n1 1\n0.333\n"));
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_load_libsvm() {
+    let (x, y) = Matrix::from_libsvm("tests/data/dataset.svm")
+        .load()
+        .unwrap();
+    assert_eq!(x, matrix![0.5, 0.0, 1.0; 0.0, 2.0, 0.0; 0.0, 0.0, 0.0]);
+    assert_eq!(y, vector![1.0, -1.0, 1.0]);
+
+    let (x, y) = Matrix::from_libsvm("tests/data/dataset_zero_based.svm")
+        .zero_based(true)
+        .n_features(3)
+        .load()
+        .unwrap();
+    assert_eq!(x, matrix![0.5, 0.0, 0.0; 0.0, 2.0, 0.0]);
+    assert_eq!(y, vector![1.0, -1.0]);
+}
+
+#[test]
+fn test_load_libsvm_invalid() {
+    let path = std::env::temp_dir().join("crabsformer_test_invalid.svm");
+    let load = |content: &str, zero_based: bool| -> MatrixLoadError {
+        std::fs::write(&path, content).unwrap();
+        Matrix::from_libsvm(&path)
+            .zero_based(zero_based)
+            .n_features(2)
+            .load()
+            .unwrap_err()
+    };
+
+    // Index zero is invalid for one-based indices
+    let error = load("1 1:1\n0 0:1\n", false);
+    match error.kind() {
+        MatrixLoadErrorKind::InvalidColumn => (),
+        _ => panic!("expected invalid column"),
+    }
+    assert_eq!((error.line(), error.column()), (Some(2), Some(2)));
+
+    // Index out of bounds of the number of features
+    let error = load("1 0:1 2:1\n", true);
+    assert_eq!((error.line(), error.column()), (Some(1), Some(3)));
+
+    let error = load("1 1:x\n", false);
+    match error.kind() {
+        MatrixLoadErrorKind::InvalidElement => (),
+        _ => panic!("expected invalid element"),
+    }
+
+    let error = load("yes 1:1\n", false);
+    assert_eq!(error.column(), Some(1));
+
+    let error = load("1 1 2\n", false);
+    match error.kind() {
+        MatrixLoadErrorKind::InvalidFormat => (),
+        _ => panic!("expected invalid format"),
+    }
+
+    let error = load("# only comments\n\n", false);
+    match error.kind() {
+        MatrixLoadErrorKind::Empty => (),
+        _ => panic!("expected empty"),
+    }
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_load_pgm() {
+    let w: Matrix<u8> = Matrix::from_pgm("tests/data/image.pgm").unwrap();
+    assert_eq!(w, matrix![0, 64, 128; 192, 255, 32]);
+
+    let w: Matrix<f32> = Matrix::from_pgm("tests/data/image.pgm").unwrap();
+    assert_eq!(w, matrix![0.0, 64.0, 128.0; 192.0, 255.0, 32.0]);
+
+    // ASCII image with comments in the header
+    let path = std::env::temp_dir().join("crabsformer_test_ascii.pgm");
+    std::fs::write(&path, "P2\n# comment\n2 # width\n1\n15\n3 15\n").unwrap();
+    let w: Matrix<u8> = Matrix::from_pgm(&path).unwrap();
+    assert_eq!(w, matrix![3, 15]);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_load_ppm() {
+    let [r, g, b]: [Matrix<u8>; 3] =
+        Matrix::from_ppm("tests/data/image.ppm").unwrap();
+    assert_eq!(r, matrix![255, 0; 0, 255]);
+    assert_eq!(g, matrix![0, 255; 0, 255]);
+    assert_eq!(b, matrix![0, 0; 255, 255]);
+}
+
+#[test]
+fn test_write_pgm() {
+    let path = std::env::temp_dir().join("crabsformer_test_write.pgm");
+    let w: Matrix<u8> = matrix![0, 128, 255; 1, 2, 3];
+    w.to_pgm(&path).write().unwrap();
+    let bytes = std::fs::read(&path).unwrap();
+    assert_eq!(&bytes[..11], b"P5\n3 2\n255\n");
+    assert_eq!(&bytes[11..], &[0, 128, 255, 1, 2, 3]);
+    let v: Matrix<u8> = Matrix::from_pgm(&path).unwrap();
+    assert_eq!(v, w);
+
+    w.to_pgm(&path).ascii(true).write().unwrap();
+    let v: Matrix<u8> = Matrix::from_pgm(&path).unwrap();
+    assert_eq!(v, w);
+
+    // Integral floating point elements are written as they are
+    let w: Matrix<f64> = matrix![0.0, 128.0, 255.0; 1.0, 2.0, 3.0];
+    w.to_pgm(&path).max_value(255).write().unwrap();
+    let v: Matrix<u8> = Matrix::from_pgm(&path).unwrap();
+    assert_eq!(v, matrix![0, 128, 255; 1, 2, 3]);
+
+    // 16-bit samples are written in big endian
+    let w: Matrix<u16> = matrix![0, 65535; 256, 1];
+    w.to_pgm(&path).write().unwrap();
+    let bytes = std::fs::read(&path).unwrap();
+    assert_eq!(&bytes[..13], b"P5\n2 2\n65535\n");
+    assert_eq!(&bytes[13..], &[0, 0, 255, 255, 1, 0, 0, 1]);
+    let v: Matrix<u16> = Matrix::from_pgm(&path).unwrap();
+    assert_eq!(v, w);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_write_ppm() {
+    let path = std::env::temp_dir().join("crabsformer_test_write.ppm");
+    let r: Matrix<u8> = matrix![1, 2; 3, 4];
+    let g: Matrix<u8> = matrix![5, 6; 7, 8];
+    let b: Matrix<u8> = matrix![9, 10; 11, 12];
+    for ascii in &[false, true] {
+        Matrix::to_ppm([&r, &g, &b], &path)
+            .ascii(*ascii)
+            .write()
+            .unwrap();
+        let [x, y, z]: [Matrix<u8>; 3] = Matrix::from_ppm(&path).unwrap();
+        assert_eq!(x, r);
+        assert_eq!(y, g);
+        assert_eq!(z, b);
+    }
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_netpbm_invalid() {
+    let path = std::env::temp_dir().join("crabsformer_test_invalid.pgm");
+    let load = |content: &[u8]| -> MatrixLoadError {
+        std::fs::write(&path, content).unwrap();
+        Matrix::<u8>::from_pgm(&path).unwrap_err()
+    };
+
+    let error = load(b"");
+    match error.kind() {
+        MatrixLoadErrorKind::Empty => (),
+        _ => panic!("expected empty"),
+    }
+
+    // PPM image is not PGM image
+    let error = load(b"P6\n1 1\n255\n\x00\x00\x00");
+    match error.kind() {
+        MatrixLoadErrorKind::InvalidFormat => (),
+        _ => panic!("expected invalid format"),
+    }
+
+    let error = load(b"P5\n2 2\n255\n\x00\x00");
+    match error.kind() {
+        MatrixLoadErrorKind::InvalidFormat => (),
+        _ => panic!("expected invalid format"),
+    }
+
+    let error = load(b"P2\n2 1\n15\n3 16\n");
+    match error.kind() {
+        MatrixLoadErrorKind::InvalidElement => (),
+        _ => panic!("expected invalid element"),
+    }
+
+    // 16-bit image does not fit in u8
+    let error = load(b"P2\n1 1\n65535\n0\n");
+    match error.kind() {
+        MatrixLoadErrorKind::InvalidDataType => (),
+        _ => panic!("expected invalid data type"),
+    }
+
+    let w: Matrix<i32> = matrix![1, -1];
+    let error = w.to_pgm(&path).write().unwrap_err();
+    match error.kind() {
+        MatrixLoadErrorKind::InvalidElement => (),
+        _ => panic!("expected invalid element"),
+    }
+
+    // Floating point elements are not truncated
+    let w: Matrix<f64> = matrix![0.0, 0.7];
+    let error = w.to_pgm(&path).max_value(255).write().unwrap_err();
+    match error.kind() {
+        MatrixLoadErrorKind::InvalidElement => (),
+        _ => panic!("expected invalid element"),
+    }
+    let w: Matrix<f32> = matrix![255.9, 1.0];
+    let error = w.to_pgm(&path).max_value(255).write().unwrap_err();
+    match error.kind() {
+        MatrixLoadErrorKind::InvalidElement => (),
+        _ => panic!("expected invalid element"),
+    }
+
+    let w: Matrix<u8> = matrix![1, 2];
+    let v: Matrix<u8> = matrix![1; 2];
+    let error = Matrix::to_ppm([&w, &w, &v], &path).write().unwrap_err();
+    match error.kind() {
+        MatrixLoadErrorKind::InvalidShape => (),
+        _ => panic!("expected invalid shape"),
+    }
+    std::fs::remove_file(&path).ok();
+}
+
+#[test]
+fn test_write_pgm_ascii_wrap() {
+    let path = std::env::temp_dir().join("crabsformer_test_wrap.pgm");
+    let w: Matrix<u16> = Matrix::full([2, 40], 65535);
+    w.to_pgm(&path).ascii(true).write().unwrap();
+    let content = std::fs::read_to_string(&path).unwrap();
+    assert!(content.lines().all(|line| line.len() <= 70));
+    let v: Matrix<u16> = Matrix::from_pgm(&path).unwrap();
+    assert_eq!(v, w);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_load_libsvm_large_index() {
+    let path = std::env::temp_dir().join("crabsformer_test_large.svm");
+
+    std::fs::write(&path, "1 18446744073709551615:1\n").unwrap();
+    let error = Matrix::from_libsvm(&path)
+        .zero_based(true)
+        .load()
+        .unwrap_err();
+    match error.kind() {
+        MatrixLoadErrorKind::InvalidColumn => (),
+        _ => panic!("expected invalid column"),
+    }
+    assert_eq!((error.line(), error.column()), (Some(1), Some(2)));
+
+    std::fs::write(&path, "1 1:1\n1 18446744073709551615:1\n").unwrap();
+    let error = Matrix::from_libsvm(&path).load().unwrap_err();
+    match error.kind() {
+        MatrixLoadErrorKind::InvalidShape => (),
+        _ => panic!("expected invalid shape"),
+    }
+    assert_eq!((error.line(), error.column()), (Some(2), Some(2)));
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_load_mtx_too_large() {
+    let path = std::env::temp_dir().join("crabsformer_test_large.mtx");
+    let content = "%%MatrixMarket matrix coordinate real general\n\
+                   1000000000 1000000000 1\n\
+                   1 1 1.0\n";
+    std::fs::write(&path, content).unwrap();
+    let error = Matrix::<f64>::from_mtx(&path).unwrap_err();
+    match error.kind() {
+        MatrixLoadErrorKind::InvalidShape => (),
+        _ => panic!("expected invalid shape"),
+    }
+    assert_eq!(error.line(), Some(2));
+
+    let content = "%%MatrixMarket matrix array real general\n\
+                   18446744073709551615 2\n";
+    std::fs::write(&path, content).unwrap();
+    let error = Matrix::<f64>::from_mtx(&path).unwrap_err();
+    match error.kind() {
+        MatrixLoadErrorKind::InvalidShape => (),
+        _ => panic!("expected invalid shape"),
+    }
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_write_mtx_skew_symmetric() {
+    let path = std::env::temp_dir().join("crabsformer_test_skew.mtx");
+    let skew = MatrixMarketSymmetry::SkewSymmetric;
+
+    // Unsigned matrix cannot be skew-symmetric
+    let w: Matrix<u8> = matrix![0, 200; 100, 0];
+    let error = w.to_mtx(&path).symmetry(skew).write().unwrap_err();
+    match error.kind() {
+        MatrixLoadErrorKind::InvalidDataType => (),
+        _ => panic!("expected invalid data type"),
+    }
+
+    // Negating the elements would overflow
+    let w: Matrix<i8> = matrix![0, 100; 100, 0];
+    let error = w.to_mtx(&path).symmetry(skew).write().unwrap_err();
+    match error.kind() {
+        MatrixLoadErrorKind::InvalidElement => (),
+        _ => panic!("expected invalid element"),
+    }
+    let w: Matrix<i8> = matrix![0, -128; 127, 0];
+    assert!(w.to_mtx(&path).symmetry(skew).write().is_err());
+
+    let w: Matrix<i8> = matrix![0, -127; 127, 0];
+    w.to_mtx(&path).symmetry(skew).write().unwrap();
+    let v: Matrix<i8> = Matrix::from_mtx(&path).unwrap();
+    assert_eq!(v, w);
+
+    let w = matrix![0.0, 1.5; -1.5, -0.0];
+    w.to_mtx(&path).symmetry(skew).write().unwrap();
+    let v: Matrix<f64> = Matrix::from_mtx(&path).unwrap();
+    assert_eq!(v, w);
+
+    std::fs::remove_file(&path).unwrap();
+}
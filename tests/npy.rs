@@ -0,0 +1,208 @@
+// Copyright (c) 2019, Bayu Aldi Yansyah <bayualdiyansyah@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crabsformer::prelude::*;
+use std::fs;
+
+#[test]
+fn test_load_npy() {
+    let x: Vector<f64> = Vector::from_npy("tests/data/vector.npy").unwrap();
+    assert_eq!(x, vector![1.0, 2.5, -3.0]);
+
+    let w: Matrix<i32> = Matrix::from_npy("tests/data/matrix.npy").unwrap();
+    assert_eq!(w, matrix![1, 2, 3; 4, 5, 6]);
+
+    // Big endian, Fortran order and format version 2.0
+    let w: Matrix<f32> =
+        Matrix::from_npy("tests/data/matrix_fortran.npy").unwrap();
+    assert_eq!(w, matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0]);
+}
+
+#[test]
+fn test_load_npy_invalid() {
+    // Element type does not match
+    let result: Result<Vector<f32>, VectorLoadError> =
+        Vector::from_npy("tests/data/vector.npy");
+    match result.unwrap_err().kind() {
+        VectorLoadErrorKind::InvalidDataType => (),
+        _ => panic!("expected invalid data type"),
+    }
+
+    // Number of dimensions does not match
+    let result: Result<Vector<i32>, VectorLoadError> =
+        Vector::from_npy("tests/data/matrix.npy");
+    match result.unwrap_err().kind() {
+        VectorLoadErrorKind::InvalidShape => (),
+        _ => panic!("expected invalid shape"),
+    }
+    let result: Result<Matrix<f64>, MatrixLoadError> =
+        Matrix::from_npy("tests/data/vector.npy");
+    match result.unwrap_err().kind() {
+        MatrixLoadErrorKind::InvalidShape => (),
+        _ => panic!("expected invalid shape"),
+    }
+
+    // Not a NPY file
+    let result: Result<Matrix<f64>, MatrixLoadError> =
+        Matrix::from_npy("tests/data/dataset.csv");
+    match result.unwrap_err().kind() {
+        MatrixLoadErrorKind::InvalidFormat => (),
+        _ => panic!("expected invalid format"),
+    }
+
+    // Truncated data
+    let path = std::env::temp_dir().join("crabsformer_test_truncated.npy");
+    let bytes = fs::read("tests/data/matrix.npy").unwrap();
+    fs::write(&path, &bytes[..bytes.len() - 1]).unwrap();
+    let result: Result<Matrix<i32>, MatrixLoadError> =
+        Matrix::from_npy(&path);
+    match result.unwrap_err().kind() {
+        MatrixLoadErrorKind::InvalidFormat => (),
+        _ => panic!("expected invalid format"),
+    }
+    fs::remove_file(&path).unwrap();
+
+    let result: Result<Matrix<i32>, MatrixLoadError> =
+        Matrix::from_npy("tests/data/unknown.npy");
+    match result.unwrap_err().kind() {
+        MatrixLoadErrorKind::IOError => (),
+        _ => panic!("expected IO error"),
+    }
+}
+
+#[test]
+fn test_write_npy() {
+    let path = std::env::temp_dir().join("crabsformer_test_write.npy");
+
+    // Same bytes as numpy.save
+    let w = matrix![1, 2, 3; 4, 5, 6];
+    w.to_npy(&path).write().unwrap();
+    assert_eq!(
+        fs::read(&path).unwrap(),
+        fs::read("tests/data/matrix.npy").unwrap()
+    );
+    let x = vector![1.0, 2.5, -3.0];
+    x.to_npy(&path).write().unwrap();
+    assert_eq!(
+        fs::read(&path).unwrap(),
+        fs::read("tests/data/vector.npy").unwrap()
+    );
+
+    // The data is aligned to 64 bytes
+    let w = matrix![1.0f32, 2.0, 3.0; 4.0, 5.0, 6.0];
+    w.to_npy(&path)
+        .big_endian(true)
+        .fortran_order(true)
+        .write()
+        .unwrap();
+    let bytes = fs::read(&path).unwrap();
+    assert_eq!(bytes.len(), 128 + 6 * 4);
+    assert_eq!(bytes[128..132], 1.0f32.to_be_bytes());
+    assert_eq!(bytes[132..136], 4.0f32.to_be_bytes());
+    let v: Matrix<f32> = Matrix::from_npy(&path).unwrap();
+    assert_eq!(v, w);
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_write_npy_round_trip() {
+    let path = std::env::temp_dir().join("crabsformer_test_round_trip.npy");
+
+    let x = vector![i8::MIN, 0, i8::MAX];
+    x.to_npy(&path).write().unwrap();
+    assert_eq!(Vector::<i8>::from_npy(&path).unwrap(), x);
+
+    let x = vector![u128::MAX, 1];
+    x.to_npy(&path).big_endian(true).write().unwrap();
+    assert_eq!(Vector::<u128>::from_npy(&path).unwrap(), x);
+
+    let w = matrix![0.1f64, -1e300; std::f64::consts::PI, 0.0];
+    w.to_npy(&path).fortran_order(true).write().unwrap();
+    assert_eq!(Matrix::<f64>::from_npy(&path).unwrap(), w);
+
+    let w = matrix![1usize, 2, 3];
+    w.to_npy(&path).write().unwrap();
+    assert_eq!(Matrix::<usize>::from_npy(&path).unwrap(), w);
+
+    // Empty vector
+    let x: Vector<u16> = Vector::from(Vec::new());
+    x.to_npy(&path).write().unwrap();
+    assert_eq!(Vector::<u16>::from_npy(&path).unwrap().len(), 0);
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_npz() {
+    // Written by zipfile with ZIP64 local headers, as numpy.savez does
+    let mut archive = NpzReader::open("tests/data/arrays.npz").unwrap();
+    assert_eq!(archive.names(), vec!["x", "w"]);
+    let x: Vector<i64> = archive.vector("x").unwrap();
+    assert_eq!(x, vector![1, -2, 3, -4]);
+    let w: Matrix<u8> = archive.matrix("w.npy").unwrap();
+    assert_eq!(w, matrix![1, 2; 3, 255]);
+
+    let result: Result<Vector<i64>, VectorLoadError> = archive.vector("y");
+    match result.unwrap_err().kind() {
+        VectorLoadErrorKind::InvalidName => (),
+        _ => panic!("expected invalid name"),
+    }
+    let result: Result<Matrix<i64>, MatrixLoadError> = archive.matrix("x");
+    match result.unwrap_err().kind() {
+        MatrixLoadErrorKind::InvalidShape => (),
+        _ => panic!("expected invalid shape"),
+    }
+
+    // Compressed archives are not supported
+    let mut archive =
+        NpzReader::open("tests/data/arrays_compressed.npz").unwrap();
+    let result: Result<Vector<i64>, VectorLoadError> = archive.vector("x");
+    match result.unwrap_err().kind() {
+        VectorLoadErrorKind::InvalidFormat => (),
+        _ => panic!("expected invalid format"),
+    }
+
+    // Not a ZIP archive
+    let result = NpzReader::open("tests/data/matrix.npy");
+    match result.unwrap_err().kind() {
+        VectorLoadErrorKind::InvalidFormat => (),
+        _ => panic!("expected invalid format"),
+    }
+}
+
+#[test]
+fn test_write_npz() {
+    let path = std::env::temp_dir().join("crabsformer_test_write.npz");
+
+    let x = vector![1.5, -2.0];
+    let w = matrix![1, 2; 3, 4];
+    NpzWriter::new()
+        .vector("x", &vector![0.0])
+        .matrix("w", &w)
+        .vector("x", &x)
+        .write(&path)
+        .unwrap();
+    let mut archive = NpzReader::open(&path).unwrap();
+    assert_eq!(archive.names(), vec!["w", "x"]);
+    assert_eq!(archive.vector::<f64>("x").unwrap(), x);
+    assert_eq!(archive.matrix::<i32>("w").unwrap(), w);
+
+    // Empty archive
+    NpzWriter::new().write(&path).unwrap();
+    let archive = NpzReader::open(&path).unwrap();
+    assert!(archive.names().is_empty());
+
+    fs::remove_file(&path).unwrap();
+}
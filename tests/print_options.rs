@@ -0,0 +1,39 @@
+// Copyright (c) 2019, Bayu Aldi Yansyah <bayualdiyansyah@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// The print options are global, so they are tested in their own test binary
+// to not interfere with the other printing tests.
+use crabsformer::prelude::*;
+
+#[test]
+fn test_print_options() {
+    assert_eq!(print_options(), PrintOptions::default());
+    let options = PrintOptions {
+        precision: Some(3),
+        ..PrintOptions::default()
+    };
+    set_print_options(options);
+    assert_eq!(print_options(), options);
+    assert_eq!(format!("{}", matrix![0.5, 1.0]), "[[0.500 1.000]]");
+    // Formatter precision takes priority
+    assert_eq!(format!("{:.1}", vector![0.5, 1.0]), "[0.5 1.0]");
+
+    // Options are shared by all threads
+    let other = std::thread::spawn(|| format!("{}", vector![0.25]))
+        .join()
+        .unwrap();
+    assert_eq!(other, "[0.250]");
+    set_print_options(PrintOptions::default());
+    assert_eq!(print_options(), PrintOptions::default());
+}
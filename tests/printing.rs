@@ -0,0 +1,82 @@
+// Copyright (c) 2019, Bayu Aldi Yansyah <bayualdiyansyah@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crabsformer::prelude::*;
+
+#[test]
+fn test_display_vector() {
+    assert_eq!(format!("{}", vector![1, 2, 3]), "[1 2 3]");
+    assert_eq!(format!("{}", vector![1, -20, 300]), "[  1 -20 300]");
+    assert_eq!(format!("{}", vector![0.5, 1.0]), "[0.5   1]");
+    assert_eq!(format!("{:.2}", vector![0.5, 1.0]), "[0.50 1.00]");
+    let x: Vector<f64> = Vector::from(Vec::new());
+    assert_eq!(format!("{}", x), "[]");
+}
+
+#[test]
+fn test_display_matrix() {
+    let w = matrix![1, 2, 3; 4, 5, 6];
+    assert_eq!(format!("{}", w), "[[1 2 3]\n [4 5 6]]");
+
+    let w = matrix![1.5, -2.0; 300.0, 4.25; 5.0, 6.0];
+    assert_eq!(
+        format!("{}", w),
+        "[[ 1.5   -2]\n [ 300 4.25]\n [   5    6]]"
+    );
+    assert_eq!(
+        format!("{:.1}", w),
+        "[[  1.5  -2.0]\n [300.0   4.2]\n [  5.0   6.0]]"
+    );
+
+    let options = PrintOptions {
+        scientific: true,
+        precision: Some(1),
+        ..PrintOptions::default()
+    };
+    assert_eq!(
+        format!("{}", w.display(options)),
+        "[[ 1.5e0 -2.0e0]\n [ 3.0e2  4.2e0]\n [ 5.0e0  6.0e0]]"
+    );
+}
+
+#[test]
+fn test_display_summarize() {
+    let x: Vector<i32> = Vector::from((0..1001).collect::<Vec<i32>>());
+    assert_eq!(format!("{}", x), "[   0    1    2 ...  998  999 1000]");
+
+    // Not summarized at the threshold
+    let x: Vector<i32> = Vector::from((0..5).collect::<Vec<i32>>());
+    let options = PrintOptions {
+        threshold: 5,
+        edge_items: 1,
+        ..PrintOptions::default()
+    };
+    assert_eq!(format!("{}", x.display(options)), "[0 1 2 3 4]");
+
+    let w: Matrix<i32> = Matrix::from(
+        (0..6)
+            .map(|i| (0..6).map(|j| i * 10 + j).collect())
+            .collect::<Vec<Vec<i32>>>(),
+    );
+    let options = PrintOptions {
+        threshold: 10,
+        edge_items: 2,
+        ..PrintOptions::default()
+    };
+    assert_eq!(
+        format!("{}", w.display(options)),
+        "[[ 0  1 ...  4  5]\n [10 11 ... 14 15]\n ...\n \
+         [40 41 ... 44 45]\n [50 51 ... 54 55]]"
+    );
+}
@@ -0,0 +1,149 @@
+// Copyright (c) 2019, Bayu Aldi Yansyah <bayualdiyansyah@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crabsformer::prelude::*;
+use rand::{RngCore, SeedableRng};
+
+fn state(words: [u64; 4]) -> RandomState {
+    let mut bytes = Vec::new();
+    for word in words.iter() {
+        bytes.extend_from_slice(&word.to_le_bytes());
+    }
+    RandomState::from_bytes(&bytes).unwrap()
+}
+
+#[test]
+fn test_xoshiro256starstar() {
+    // Reference values from the xoshiro256** reference implementation
+    let mut rng = Xoshiro256StarStar::from_state(&state([1, 2, 3, 4]));
+    assert_eq!(rng.next_u64(), 11520);
+    assert_eq!(rng.next_u64(), 0);
+    assert_eq!(rng.next_u64(), 1509978240);
+    assert_eq!(rng.next_u64(), 1215971899390074240);
+    assert_eq!(rng.next_u64(), 1216172134540287360);
+}
+
+#[test]
+fn test_xoshiro256plusplus() {
+    // Reference values from the xoshiro256++ reference implementation
+    let mut rng = Xoshiro256PlusPlus::from_state(&state([1, 2, 3, 4]));
+    assert_eq!(rng.next_u64(), 41943041);
+    assert_eq!(rng.next_u64(), 58720359);
+    assert_eq!(rng.next_u64(), 3588806011781223);
+    assert_eq!(rng.next_u64(), 3591011842654386);
+    assert_eq!(rng.next_u64(), 9228616714210784205);
+
+    // Both generators share the state and the jump functions
+    let mut a = Xoshiro256StarStar::seed_from_u64(3);
+    let mut b = Xoshiro256PlusPlus::seed_from_u64(3);
+    assert_eq!(a.state(), b.state());
+    a.jump();
+    b.jump();
+    assert_eq!(a.state(), b.state());
+}
+
+#[test]
+fn test_builder_generator() {
+    let mut rvb1 = RandomVectorBuilder::new();
+    let mut rvb2 =
+        RandomVectorBuilder::with_generator(Generator::Xoshiro256PlusPlus);
+    assert_eq!(rvb1.generator(), Generator::Xoshiro256StarStar);
+    assert_eq!(rvb2.generator(), Generator::Xoshiro256PlusPlus);
+
+    // Seeding and restoring the state keep the generator
+    rvb1.seed(7);
+    rvb2.seed(7);
+    assert_eq!(rvb1.state(), rvb2.state());
+    assert_eq!(rvb2.generator(), Generator::Xoshiro256PlusPlus);
+    assert_ne!(rvb1.permutation(10), rvb2.permutation(10));
+    let state = rvb2.state();
+    let x = rvb2.uniform(5, 0.0, 1.0).unwrap();
+    rvb2.set_state(&state);
+    assert_eq!(rvb2.generator(), Generator::Xoshiro256PlusPlus);
+    assert_eq!(rvb2.uniform(5, 0.0, 1.0).unwrap(), x);
+
+    let rmb =
+        RandomMatrixBuilder::with_generator(Generator::Xoshiro256PlusPlus);
+    assert_eq!(rmb.generator(), Generator::Xoshiro256PlusPlus);
+}
+
+#[test]
+fn test_seed_from_u64() {
+    // The seed is expanded using SplitMix64
+    let rng = Xoshiro256StarStar::seed_from_u64(0);
+    let expected = state([
+        0xe220a8397b1dcdaf,
+        0x6e789e6aa1b965f4,
+        0x06c45d188009454f,
+        0xf88bb8a8724c81ec,
+    ]);
+    assert_eq!(rng.state(), expected);
+
+    let mut rng = Xoshiro256StarStar::seed_from_u64(0);
+    assert_eq!(rng.next_u64(), 11091344671253066420);
+    assert_eq!(rng.next_u64(), 13793997310169335082);
+    assert_eq!(rng.next_u64(), 1900383378846508768);
+
+    // The all-zero seed is not a valid state
+    let mut rng = Xoshiro256StarStar::from_seed([0; 32]);
+    assert_ne!(rng.next_u64(), 0);
+}
+
+#[test]
+fn test_jump() {
+    let mut rng = Xoshiro256StarStar::from_state(&state([1, 2, 3, 4]));
+    rng.jump();
+    let expected = state([
+        10122426448480695249,
+        8079205330032121950,
+        7289065458748526725,
+        9477464255293849680,
+    ]);
+    assert_eq!(rng.state(), expected);
+
+    let mut rng = Xoshiro256StarStar::from_state(&state([1, 2, 3, 4]));
+    rng.long_jump();
+    let expected = state([
+        678511610814637056,
+        15850499779492529430,
+        6002989639035333134,
+        3559352929785830385,
+    ]);
+    assert_eq!(rng.state(), expected);
+}
+
+#[test]
+fn test_fill_bytes() {
+    let mut rng1 = Xoshiro256StarStar::seed_from_u64(5);
+    let mut rng2 = Xoshiro256StarStar::seed_from_u64(5);
+    let mut bytes = [0; 12];
+    rng1.fill_bytes(&mut bytes);
+    let a = rng2.next_u64().to_le_bytes();
+    let b = rng2.next_u64().to_le_bytes();
+    assert_eq!(bytes[..8], a[..]);
+    assert_eq!(bytes[8..], b[..4]);
+}
+
+#[test]
+fn test_random_state_bytes() {
+    let rng = Xoshiro256StarStar::seed_from_u64(42);
+    let bytes = rng.state().to_bytes();
+    let restored = RandomState::from_bytes(&bytes).unwrap();
+    assert_eq!(restored, rng.state());
+
+    // Invalid states
+    assert!(RandomState::from_bytes(&[0; 32]).is_err());
+    assert!(RandomState::from_bytes(&bytes[..31]).is_err());
+    assert!(RandomState::from_bytes(&[1; 33]).is_err());
+}
@@ -0,0 +1,80 @@
+// Copyright (c) 2019, Bayu Aldi Yansyah <bayualdiyansyah@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "serde")]
+
+use crabsformer::prelude::*;
+
+#[test]
+fn test_serialize() {
+    let x = vector![1, 2, 3];
+    let json = serde_json::to_string(&x).unwrap();
+    assert_eq!(json, r#"{"shape":[3],"data":[1,2,3]}"#);
+
+    let w = matrix![1.5, 2.0; 3.0, 4.0; 5.0, 6.0];
+    let json = serde_json::to_string(&w).unwrap();
+    assert_eq!(json, r#"{"shape":[3,2],"data":[1.5,2.0,3.0,4.0,5.0,6.0]}"#);
+}
+
+#[test]
+fn test_deserialize() {
+    let x: Vector<i32> =
+        serde_json::from_str(r#"{"shape":[3],"data":[1,2,3]}"#).unwrap();
+    assert_eq!(x, vector![1, 2, 3]);
+
+    let w: Matrix<u8> =
+        serde_json::from_str(r#"{"data":[1,2,3,4,5,6],"shape":[2,3]}"#)
+            .unwrap();
+    assert_eq!(w, matrix![1, 2, 3; 4, 5, 6]);
+
+    // Round trip
+    let w = matrix![0.1, -2.5e300; f64::MAX, 0.0];
+    let json = serde_json::to_string(&w).unwrap();
+    let v: Matrix<f64> = serde_json::from_str(&json).unwrap();
+    assert_eq!(v, w);
+
+    // Embedded in other types
+    let items: Vec<Vector<i8>> =
+        serde_json::from_str(r#"[{"shape":[1],"data":[-1]}]"#).unwrap();
+    assert_eq!(items, vec![vector![-1]]);
+}
+
+#[test]
+fn test_deserialize_invalid() {
+    let result: Result<Vector<i32>, _> =
+        serde_json::from_str(r#"{"shape":[2],"data":[1,2,3]}"#);
+    let error = result.unwrap_err().to_string();
+    assert!(error.contains("expected 2 elements for shape [2], got 3"));
+
+    let result: Result<Matrix<i32>, _> =
+        serde_json::from_str(r#"{"shape":[2,2],"data":[1,2,3]}"#);
+    assert!(result.is_err());
+
+    // Shape overflows usize
+    let json = format!(r#"{{"shape":[{},2],"data":[]}}"#, usize::MAX);
+    let result: Result<Matrix<i32>, _> = serde_json::from_str(&json);
+    assert!(result.is_err());
+
+    let result: Result<Matrix<i32>, _> =
+        serde_json::from_str(r#"{"shape":[3],"data":[1,2,3]}"#);
+    assert!(result.is_err());
+
+    let result: Result<Vector<u8>, _> =
+        serde_json::from_str(r#"{"shape":[1],"data":[256]}"#);
+    assert!(result.is_err());
+
+    let result: Result<Vector<u8>, _> =
+        serde_json::from_str(r#"{"data":[]}"#);
+    assert!(result.is_err());
+}
@@ -169,8 +169,69 @@ fn test_range() {
 
 #[test]
 fn test_linspace() {
-    let a = Vector::linspace(5, 1.0, 10.0);
+    let a = Vector::linspace(5, 1.0, 10.0, true).unwrap();
     assert_eq!(a, vector![1.0, 3.25, 5.5, 7.75, 10.0]);
+
+    // Exclude the endpoint
+    let b = Vector::linspace(5, 0.0, 1.0, false).unwrap();
+    assert_eq!(b, vector![0.0, 0.2, 0.4, 0.6000000000000001, 0.8]);
+
+    // Decreasing sequences
+    let c = Vector::linspace(3, 2.0, -2.0, true).unwrap();
+    assert_eq!(c, vector![2.0, 0.0, -2.0]);
+
+    // Single element without endpoint
+    let d = Vector::linspace(1, 3.0, 4.0, false).unwrap();
+    assert_eq!(d, vector![3.0]);
+
+    // Invalid length
+    let e = Vector::linspace(1, 0.0, 1.0, true);
+    assert!(e.is_err());
+    let f = Vector::linspace(0, 0.0, 1.0, false);
+    assert!(f.is_err());
+}
+
+#[test]
+fn test_logspace() {
+    let a = Vector::logspace(4, 0.0, 3.0, 10.0, true).unwrap();
+    assert_eq!(a, vector![1.0, 10.0, 100.0, 1000.0]);
+
+    let b = Vector::logspace(3, 0.0, 3.0, 2.0, false).unwrap();
+    assert_eq!(b, vector![1.0, 2.0, 4.0]);
+
+    let c = Vector::logspace(1, 0.0, 3.0, 10.0, true);
+    assert!(c.is_err());
+}
+
+#[test]
+fn test_geomspace() {
+    let a: Vector<f64> = Vector::geomspace(4, 1.0, 1000.0, true).unwrap();
+    let expected = vector![1.0, 10.0, 100.0, 1000.0];
+    for (x, y) in a.elements().zip(expected.elements()) {
+        assert!((x - y).abs() < 1e-9);
+    }
+    assert_eq!(a[0], 1.0);
+    assert_eq!(a[3], 1000.0);
+
+    let b: Vector<f64> = Vector::geomspace(3, 1.0, 8.0, false).unwrap();
+    let expected = vector![1.0, 2.0, 4.0];
+    for (x, y) in b.elements().zip(expected.elements()) {
+        assert!((x - y).abs() < 1e-9);
+    }
+
+    // Negative start and stop
+    let c: Vector<f64> = Vector::geomspace(3, -1.0, -100.0, true).unwrap();
+    assert_eq!(c[0], -1.0);
+    assert!((c[1] + 10.0).abs() < 1e-9);
+    assert_eq!(c[2], -100.0);
+
+    // Zero is not allowed
+    let d = Vector::geomspace(3, 0.0, 100.0, true);
+    assert!(d.is_err());
+
+    // Different sign is not allowed
+    let e = Vector::geomspace(3, -1.0, 100.0, true);
+    assert!(e.is_err());
 }
 
 #[test]
@@ -271,3 +332,428 @@ fn test_normal() {
     assert_eq!(a.len(), b.len());
     assert_ne!(a, b);
 }
+
+#[test]
+fn test_normal_data_types() {
+    let mut rvb = RandomVectorBuilder::new();
+
+    let a: Vector<f32> = rvb.normal(5, 0.0, 1.0).unwrap();
+    assert_eq!(a.len(), 5);
+
+    let b: Vector<f64> = rvb.normal(5, 0.0, 1.0).unwrap();
+    assert_eq!(b.len(), 5);
+
+    // Negative standard deviation
+    let c = rvb.normal(5, 0.0, -1.0);
+    assert!(c.is_err());
+}
+
+#[test]
+fn test_log_normal() {
+    let mut rvb = RandomVectorBuilder::new();
+
+    let a: Vector<f32> = rvb.log_normal(100, 0.0, 1.0).unwrap();
+    for value in a.elements() {
+        assert!(*value > 0.0);
+    }
+
+    let b = rvb.log_normal(5, 0.0, -1.0);
+    assert!(b.is_err());
+}
+
+#[test]
+fn test_non_finite_parameters() {
+    let mut rvb = RandomVectorBuilder::new();
+
+    assert!(rvb.normal(5, 0.0, f64::NAN).is_err());
+    assert!(rvb.normal(5, f64::NAN, 1.0).is_err());
+    assert!(rvb.normal(5, 0.0, f64::INFINITY).is_err());
+    assert!(rvb.log_normal(5, f64::INFINITY, 1.0).is_err());
+    assert!(rvb.log_normal(5, 0.0, f32::NAN).is_err());
+
+    assert!(rvb.exponential(5, f64::INFINITY).is_err());
+    assert!(rvb.gamma(5, 1.0, f64::NAN).is_err());
+}
+
+#[test]
+fn test_exponential() {
+    let mut rvb = RandomVectorBuilder::new();
+
+    let a: Vector<f64> = rvb.exponential(100, 2.0).unwrap();
+    for value in a.elements() {
+        assert!(*value >= 0.0);
+    }
+
+    assert!(rvb.exponential(5, 0.0).is_err());
+    assert!(rvb.exponential(5, -1.0).is_err());
+}
+
+#[test]
+fn test_gamma() {
+    let mut rvb = RandomVectorBuilder::new();
+
+    let a: Vector<f32> = rvb.gamma(100, 2.0, 3.0).unwrap();
+    for value in a.elements() {
+        assert!(*value >= 0.0);
+    }
+
+    assert!(rvb.gamma(5, 0.0, 1.0).is_err());
+    assert!(rvb.gamma(5, 1.0, -1.0).is_err());
+}
+
+#[test]
+fn test_beta() {
+    let mut rvb = RandomVectorBuilder::new();
+
+    let a: Vector<f64> = rvb.beta(100, 0.5, 0.5).unwrap();
+    for value in a.elements() {
+        assert!((0.0 <= *value) && (*value <= 1.0));
+    }
+
+    assert!(rvb.beta(5, 0.0, 1.0).is_err());
+    assert!(rvb.beta(5, 1.0, 0.0).is_err());
+}
+
+#[test]
+fn test_chi_squared() {
+    let mut rvb = RandomVectorBuilder::new();
+
+    let a: Vector<f64> = rvb.chi_squared(100, 3.0).unwrap();
+    for value in a.elements() {
+        assert!(*value >= 0.0);
+    }
+
+    assert!(rvb.chi_squared(5, -3.0).is_err());
+}
+
+#[test]
+fn test_student_t() {
+    let mut rvb = RandomVectorBuilder::new();
+
+    let a: Vector<f32> = rvb.student_t(100, 5.0).unwrap();
+    assert_eq!(a.len(), 100);
+
+    assert!(rvb.student_t(5, 0.0).is_err());
+}
+
+#[test]
+fn test_binomial() {
+    let mut rvb = RandomVectorBuilder::new();
+
+    let a = rvb.binomial(100, 10, 0.5).unwrap();
+    for value in a.elements() {
+        assert!(*value <= 10);
+    }
+
+    // Always fail and always succeed
+    assert_eq!(rvb.binomial(5, 10, 0.0).unwrap(), vector![0; 5]);
+    assert_eq!(rvb.binomial(5, 10, 1.0).unwrap(), vector![10; 5]);
+
+    assert!(rvb.binomial(5, 10, -0.1).is_err());
+    assert!(rvb.binomial(5, 10, 1.1).is_err());
+}
+
+#[test]
+fn test_poisson() {
+    let mut rvb = RandomVectorBuilder::new();
+
+    let a = rvb.poisson(100, 3.0).unwrap();
+    assert_eq!(a.len(), 100);
+
+    assert!(rvb.poisson(5, 0.0).is_err());
+}
+
+#[test]
+fn test_geometric() {
+    let mut rvb = RandomVectorBuilder::new();
+
+    let a = rvb.geometric(100, 0.3).unwrap();
+    for value in a.elements() {
+        assert!(*value >= 1);
+    }
+
+    // Always succeed on the first trial
+    assert_eq!(rvb.geometric(5, 1.0).unwrap(), vector![1; 5]);
+
+    assert!(rvb.geometric(5, 0.0).is_err());
+    assert!(rvb.geometric(5, 1.5).is_err());
+}
+
+#[test]
+fn test_geometric_small_probability() {
+    let mut rvb = RandomVectorBuilder::new();
+    rvb.seed(1);
+
+    // `1 - p` rounds to one for such a small `p`
+    let p = 1e-17;
+    let n = 10000;
+    let a = rvb.geometric(n, p).unwrap();
+    let mean = a.elements().map(|x| *x as f64).sum::<f64>() / n as f64;
+    assert!((mean * p - 1.0).abs() < 0.05);
+}
+
+#[test]
+fn test_bernoulli() {
+    let mut rvb = RandomVectorBuilder::new();
+
+    let a: Vector<u8> = rvb.bernoulli(100, 0.5).unwrap();
+    for value in a.elements() {
+        assert!(*value == 0 || *value == 1);
+    }
+
+    let b: Vector<f64> = rvb.bernoulli(5, 1.0).unwrap();
+    assert_eq!(b, vector![1.0; 5]);
+
+    let c: Vector<i32> = rvb.bernoulli(5, 0.0).unwrap();
+    assert_eq!(c, vector![0; 5]);
+
+    let d: Result<Vector<u8>, VectorBuilderError> = rvb.bernoulli(5, 2.0);
+    assert!(d.is_err());
+}
+
+#[test]
+fn test_categorical() {
+    let mut rvb = RandomVectorBuilder::new();
+
+    let a = rvb.categorical(100, &vector![1.0, 0.0, 3.0]).unwrap();
+    for value in a.elements() {
+        assert!(*value == 0 || *value == 2);
+    }
+
+    assert!(rvb.categorical(5, &vector![]).is_err());
+    assert!(rvb.categorical(5, &vector![0.0, 0.0]).is_err());
+    assert!(rvb.categorical(5, &vector![1.0, -1.0]).is_err());
+    assert!(rvb.categorical(5, &vector![1.0, f64::INFINITY]).is_err());
+    assert!(rvb.categorical(5, &vector![1e308, 1e308]).is_err());
+}
+
+#[test]
+fn test_seed_distributions() {
+    let mut rvb1 = RandomVectorBuilder::new();
+    let mut rvb2 = RandomVectorBuilder::new();
+    rvb1.seed(42);
+    rvb2.seed(42);
+
+    let a: Vector<f64> = rvb1.gamma(10, 2.0, 2.0).unwrap();
+    let b: Vector<f64> = rvb2.gamma(10, 2.0, 2.0).unwrap();
+    assert_eq!(a, b);
+
+    let c = rvb1.poisson(10, 5.0).unwrap();
+    let d = rvb2.poisson(10, 5.0).unwrap();
+    assert_eq!(c, d);
+}
+
+#[test]
+fn test_choice() {
+    let mut rvb = RandomVectorBuilder::new();
+    let x = vector![3, 1, 4, 1, 5];
+
+    // With replacement
+    let a = rvb.choice(&x, 20, true, None).unwrap();
+    assert_eq!(a.len(), 20);
+    for value in a.elements() {
+        assert!(x.elements().any(|v| v == value));
+    }
+
+    // Without replacement, every element is selected once
+    let y = vector![1, 2, 3, 4, 5];
+    let b = rvb.choice(&y, 5, false, None).unwrap();
+    let mut selected: Vec<i32> = b.elements().cloned().collect();
+    selected.sort();
+    assert_eq!(selected, vec![1, 2, 3, 4, 5]);
+
+    // Weighted with replacement
+    let weights = vector![0.0, 1.0, 0.0, 0.0, 0.0];
+    let c = rvb.choice(&y, 5, true, Some(&weights)).unwrap();
+    assert_eq!(c, vector![2, 2, 2, 2, 2]);
+
+    // Weighted without replacement
+    let weights = vector![0.0, 1.0, 0.0, 2.0, 0.0];
+    let d = rvb.choice(&y, 2, false, Some(&weights)).unwrap();
+    let mut selected: Vec<i32> = d.elements().cloned().collect();
+    selected.sort();
+    assert_eq!(selected, vec![2, 4]);
+}
+
+#[test]
+fn test_choice_empty() {
+    let mut rvb = RandomVectorBuilder::new();
+    let empty: Vector<f64> = vector![];
+    let x = vector![1.0, 2.0, 3.0];
+
+    assert_eq!(rvb.choice(&empty, 0, true, None).unwrap(), empty);
+    assert_eq!(rvb.choice(&empty, 0, false, None).unwrap(), empty);
+    assert_eq!(rvb.choice(&x, 0, true, None).unwrap(), empty);
+}
+
+#[test]
+fn test_choice_invalid() {
+    let mut rvb = RandomVectorBuilder::new();
+    let x = vector![1.0, 2.0, 3.0];
+
+    // Empty numeric vector
+    let empty: Vector<f64> = vector![];
+    assert!(rvb.choice(&empty, 1, true, None).is_err());
+
+    // Not enough elements without replacement
+    assert!(rvb.choice(&x, 4, false, None).is_err());
+
+    // Invalid weights
+    let w1 = vector![1.0, 2.0];
+    assert!(rvb.choice(&x, 2, true, Some(&w1)).is_err());
+    let w2 = vector![1.0, -2.0, 1.0];
+    assert!(rvb.choice(&x, 2, true, Some(&w2)).is_err());
+    let w3 = vector![1.0, f64::INFINITY, 1.0];
+    assert!(rvb.choice(&x, 2, true, Some(&w3)).is_err());
+    let w4 = vector![1e308, 1e308];
+    assert!(rvb.choice(&vector![1, 2], 2, true, Some(&w4)).is_err());
+
+    // Not enough non-zero weights without replacement
+    let w5 = vector![1.0, 0.0, 0.0];
+    assert!(rvb.choice(&x, 2, false, Some(&w5)).is_err());
+}
+
+#[test]
+fn test_shuffle() {
+    let mut rvb = RandomVectorBuilder::new();
+    let mut x = Vector::from((0..100).collect::<Vec<i32>>());
+    rvb.shuffle(&mut x);
+    assert_eq!(x.len(), 100);
+    let mut elements: Vec<i32> = x.elements().cloned().collect();
+    elements.sort();
+    assert_eq!(elements, (0..100).collect::<Vec<i32>>());
+}
+
+#[test]
+fn test_permutation() {
+    let mut rvb = RandomVectorBuilder::new();
+    let p = rvb.permutation(10);
+    let mut elements: Vec<usize> = p.elements().cloned().collect();
+    elements.sort();
+    assert_eq!(elements, (0..10).collect::<Vec<usize>>());
+
+    assert_eq!(rvb.permutation(0).len(), 0);
+}
+
+#[test]
+fn test_seed_permutations() {
+    let mut rvb1 = RandomVectorBuilder::new();
+    let mut rvb2 = RandomVectorBuilder::new();
+    rvb1.seed(7);
+    rvb2.seed(7);
+
+    assert_eq!(rvb1.permutation(50), rvb2.permutation(50));
+
+    let x = Vector::from((0..50).collect::<Vec<i32>>());
+    let a = rvb1.choice(&x, 10, false, None).unwrap();
+    let b = rvb2.choice(&x, 10, false, None).unwrap();
+    assert_eq!(a, b);
+
+    let mut y1 = x.clone();
+    let mut y2 = x.clone();
+    rvb1.shuffle(&mut y1);
+    rvb2.shuffle(&mut y2);
+    assert_eq!(y1, y2);
+}
+
+#[test]
+fn test_state() {
+    let mut rvb = RandomVectorBuilder::new();
+    rvb.seed(3);
+    let state = rvb.state();
+    let a = rvb.normal(100, 0.0, 1.0).unwrap();
+    let b = rvb.normal(100, 0.0, 1.0).unwrap();
+    assert_ne!(a, b);
+
+    // Restore the state from its serialised bytes
+    let bytes = state.to_bytes();
+    let mut rvb = RandomVectorBuilder::new();
+    rvb.set_state(&RandomState::from_bytes(&bytes).unwrap());
+    assert_eq!(rvb.normal(100, 0.0, 1.0).unwrap(), a);
+    assert_eq!(rvb.normal(100, 0.0, 1.0).unwrap(), b);
+}
+
+#[test]
+fn test_split() {
+    let mut rvb1 = RandomVectorBuilder::new();
+    let mut rvb2 = RandomVectorBuilder::new();
+    rvb1.seed(8);
+    rvb2.seed(8);
+
+    // The child continues from the parent state
+    let state = rvb1.state();
+    let mut child = rvb1.split();
+    assert_eq!(child.state(), state);
+    assert_ne!(rvb1.state(), state);
+
+    // The parent jumps ahead by 2^128 steps
+    rvb2.jump();
+    assert_eq!(rvb1.state(), rvb2.state());
+
+    // Sub-streams are reproducible and different
+    let mut rvb3 = RandomVectorBuilder::new();
+    rvb3.seed(8);
+    let mut children: Vec<RandomVectorBuilder> =
+        (0..3).map(|_| rvb3.split()).collect();
+    let a = child.uniform(10, 0.0, 1.0).unwrap();
+    assert_eq!(children[0].uniform(10, 0.0, 1.0).unwrap(), a);
+    assert_ne!(children[1].uniform(10, 0.0, 1.0).unwrap(), a);
+}
+
+#[test]
+fn test_parse() {
+    let x: Vector<i32> = "1 2 3".parse().unwrap();
+    assert_eq!(x, vector![1, 2, 3]);
+
+    let x: Vector<f64> = "[1.0, 2.5]".parse().unwrap();
+    assert_eq!(x, vector![1.0, 2.5]);
+
+    let x: Vector<i32> = "[1, -0x1F, +3,]".parse().unwrap();
+    assert_eq!(x, vector![1, -31, 3]);
+
+    // Column vector
+    let x: Vector<i32> = "[1; 2; 3]".parse().unwrap();
+    assert_eq!(x, vector![1, 2, 3]);
+
+    let x: Vector<f32> = "[]".parse().unwrap();
+    assert_eq!(x.len(), 0);
+}
+
+#[test]
+fn test_parse_invalid() {
+    let parse = |s: &str| s.parse::<Vector<u8>>().unwrap_err();
+
+    let error = parse("");
+    match error.kind() {
+        VectorParseErrorKind::Empty => (),
+        _ => panic!("expected empty"),
+    }
+
+    let error = parse("[1, 2, 256]");
+    match error.kind() {
+        VectorParseErrorKind::InvalidElement => (),
+        _ => panic!("expected invalid element"),
+    }
+    assert_eq!(error.position(), 7);
+
+    let error = parse("[[1, 2]]");
+    match error.kind() {
+        VectorParseErrorKind::InvalidShape => (),
+        _ => panic!("expected invalid shape"),
+    }
+    assert_eq!(error.position(), 1);
+
+    let error = parse("1 2; 3 4");
+    match error.kind() {
+        VectorParseErrorKind::InvalidShape => (),
+        _ => panic!("expected invalid shape"),
+    }
+    assert_eq!(error.position(), 0);
+
+    let error = parse("[1, 2");
+    match error.kind() {
+        VectorParseErrorKind::InvalidSyntax => (),
+        _ => panic!("expected invalid syntax"),
+    }
+    assert_eq!(error.position(), 5);
+}
@@ -0,0 +1,182 @@
+// Copyright (c) 2019, Bayu Aldi Yansyah <bayualdiyansyah@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crabsformer::prelude::*;
+
+#[test]
+fn test_write_csv() {
+    let x = vector![1.5, -2.0, 1.0 / 3.0];
+
+    // Default
+    let mut buf = Vec::new();
+    x.to_csv_writer(&mut buf).write().unwrap();
+    assert_eq!(
+        String::from_utf8(buf).unwrap(),
+        "1.5\n-2\n0.3333333333333333\n"
+    );
+
+    // Header and precision
+    let mut buf = Vec::new();
+    x.to_csv_writer(&mut buf)
+        .header("x")
+        .precision(2)
+        .write()
+        .unwrap();
+    assert_eq!(String::from_utf8(buf).unwrap(), "x\n1.50\n-2.00\n0.33\n");
+
+    // Default header name
+    let mut buf = Vec::new();
+    x.to_csv_writer(&mut buf)
+        .has_headers(true)
+        .scientific(true)
+        .precision(1)
+        .write()
+        .unwrap();
+    assert_eq!(
+        String::from_utf8(buf).unwrap(),
+        "0\n1.5e0\n-2.0e0\n3.3e-1\n"
+    );
+
+    // Empty vector
+    let x: Vector<i32> = vector![];
+    let mut buf = Vec::new();
+    x.to_csv_writer(&mut buf).write().unwrap();
+    assert_eq!(String::from_utf8(buf).unwrap(), "");
+}
+
+#[test]
+fn test_write_csv_file() {
+    let path = std::env::temp_dir().join("crabsformer_test_vector.csv");
+    let x = vector![1, 2, 3];
+    x.to_csv(&path).write().unwrap();
+    let content = std::fs::read_to_string(&path).unwrap();
+    assert_eq!(content, "1\n2\n3\n");
+    std::fs::remove_file(&path).unwrap();
+
+    // Invalid path
+    let result = x.to_csv("tests/data/not_found/vector.csv").write();
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_load_csv() {
+    // First column by default
+    let x: Vector<i32> =
+        Vector::from_csv("tests/data/matrix_valid_without_header.csv")
+            .load()
+            .unwrap();
+    assert_eq!(x, vector![-1, 4, 7]);
+
+    // Column by index
+    let x: Vector<i32> =
+        Vector::from_csv("tests/data/matrix_valid_without_header.csv")
+            .column(2)
+            .load()
+            .unwrap();
+    assert_eq!(x, vector![3, 6, -9]);
+
+    // Column by header name
+    let x: Vector<f64> = Vector::from_csv("tests/data/dataset.csv")
+        .column_name("feature2")
+        .load()
+        .unwrap();
+    assert_eq!(x, vector![2.0, 4.0, 6.0]);
+
+    // Skip headers
+    let x: Vector<f64> = Vector::from_csv("tests/data/dataset.csv")
+        .has_headers(true)
+        .load()
+        .unwrap();
+    assert_eq!(x, vector![1.0, 3.0, 5.0]);
+
+    // Delimiter
+    let x: Vector<u8> = Vector::from_csv("tests/data/vector_semicolon.csv")
+        .delimiter(b';')
+        .column(1)
+        .load()
+        .unwrap();
+    assert_eq!(x, vector![2, 5]);
+}
+
+#[test]
+fn test_load_csv_invalid() {
+    // File not found
+    let result: Result<Vector<i32>, VectorLoadError> =
+        Vector::from_csv("tests/data/random_file.csv").load();
+    assert!(result.is_err());
+
+    // Empty file
+    let result: Result<Vector<i32>, VectorLoadError> =
+        Vector::from_csv("tests/data/matrix_invalid_empty.csv").load();
+    assert!(result.is_err());
+
+    // Header name not found
+    let result: Result<Vector<f64>, VectorLoadError> =
+        Vector::from_csv("tests/data/dataset.csv")
+            .column_name("feature3")
+            .load();
+    assert!(result.is_err());
+
+    // Column out of bounds
+    let result: Result<Vector<i32>, VectorLoadError> =
+        Vector::from_csv("tests/data/matrix_valid_without_header.csv")
+            .column(3)
+            .load();
+    assert!(result.is_err());
+
+    // Invalid element
+    let result: Result<Vector<u32>, VectorLoadError> =
+        Vector::from_csv("tests/data/matrix_valid_without_header.csv").load();
+    assert!(result.is_err());
+
+    // Headers are not numbers
+    let result: Result<Vector<f64>, VectorLoadError> =
+        Vector::from_csv("tests/data/dataset.csv").load();
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_load_txt() {
+    let x: Vector<i32> =
+        Vector::from_txt("tests/data/vector.txt").load().unwrap();
+    assert_eq!(x, vector![1, -2, 3, 4]);
+
+    let x: Vector<f32> = Vector::from_txt("tests/data/vector_header.txt")
+        .has_headers(true)
+        .load()
+        .unwrap();
+    assert_eq!(x, vector![0.5, 1.5]);
+
+    // Invalid
+    let result: Result<Vector<f32>, VectorLoadError> =
+        Vector::from_txt("tests/data/vector_header.txt").load();
+    assert!(result.is_err());
+    let result: Result<Vector<u32>, VectorLoadError> =
+        Vector::from_txt("tests/data/vector.txt").load();
+    assert!(result.is_err());
+    let result: Result<Vector<f32>, VectorLoadError> =
+        Vector::from_txt("tests/data/matrix_invalid_empty.csv").load();
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_write_load_round_trip() {
+    let path = std::env::temp_dir().join("crabsformer_test_round_trip.csv");
+    let x = vector![0.1, 1.0 / 3.0, -2.5e-8];
+    x.to_csv(&path).header("x").write().unwrap();
+    let y: Vector<f64> =
+        Vector::from_csv(&path).column_name("x").load().unwrap();
+    assert_eq!(x, y);
+    std::fs::remove_file(&path).unwrap();
+}
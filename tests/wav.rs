@@ -0,0 +1,230 @@
+// Copyright (c) 2019, Bayu Aldi Yansyah <bayualdiyansyah@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crabsformer::prelude::*;
+use std::fs;
+
+// Build WAVE file with the given fmt chunk fields and data chunk
+fn wave(code: u16, channels: u16, bits: u16, data: &[u8]) -> Vec<u8> {
+    let block_align = channels * bits / 8;
+    let mut body = b"WAVEfmt ".to_vec();
+    body.extend_from_slice(&16u32.to_le_bytes());
+    body.extend_from_slice(&code.to_le_bytes());
+    body.extend_from_slice(&channels.to_le_bytes());
+    body.extend_from_slice(&8000u32.to_le_bytes());
+    body.extend_from_slice(&(8000 * u32::from(block_align)).to_le_bytes());
+    body.extend_from_slice(&block_align.to_le_bytes());
+    body.extend_from_slice(&bits.to_le_bytes());
+    body.extend_from_slice(b"data");
+    body.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    body.extend_from_slice(data);
+    let mut bytes = b"RIFF".to_vec();
+    bytes.extend_from_slice(&(body.len() as u32).to_le_bytes());
+    bytes.extend_from_slice(&body);
+    bytes
+}
+
+#[test]
+fn test_load_wav() {
+    let (w, sample_rate): (Matrix<i16>, u32) =
+        Matrix::from_wav("tests/data/audio.wav").unwrap();
+    assert_eq!(sample_rate, 8000);
+    assert_eq!(w, matrix![0, 1000, -1000, 32767; 0, -1000, 1000, -32768]);
+
+    let (w, _): (Matrix<f32>, u32) =
+        Matrix::from_wav("tests/data/audio.wav").unwrap();
+    assert_eq!(*w.at(1, 1), -0.030517578);
+    assert_eq!(*w.at(1, 3), -1.0);
+
+    let (channels, sample_rate): (Vec<Vector<i32>>, u32) =
+        Vector::from_wav("tests/data/audio.wav").unwrap();
+    assert_eq!(sample_rate, 8000);
+    assert_eq!(channels.len(), 2);
+    assert_eq!(
+        channels[0],
+        vector![0, 1000 << 16, -1000 << 16, 32767 << 16]
+    );
+}
+
+#[test]
+fn test_load_wav_bit_depth() {
+    let path = std::env::temp_dir().join("crabsformer_test_bit_depth.wav");
+
+    // 8-bit samples are unsigned
+    fs::write(&path, wave(1, 1, 8, &[128, 255, 0, 129])).unwrap();
+    let (w, _): (Matrix<i16>, u32) = Matrix::from_wav(&path).unwrap();
+    assert_eq!(w, matrix![0, 32512, -32768, 256]);
+
+    fs::write(&path, wave(1, 1, 24, &[0xFF, 0x7F, 0x00, 0x00, 0x00, 0x80]))
+        .unwrap();
+    let (w, _): (Matrix<i16>, u32) = Matrix::from_wav(&path).unwrap();
+    assert_eq!(w, matrix![128, -32768]);
+
+    let data: Vec<u8> = [0.25f32, -2.0]
+        .iter()
+        .flat_map(|x| x.to_le_bytes().to_vec())
+        .collect();
+    fs::write(&path, wave(3, 1, 32, &data)).unwrap();
+    let (w, _): (Matrix<f64>, u32) = Matrix::from_wav(&path).unwrap();
+    assert_eq!(w, matrix![0.25, -2.0]);
+    // Out of range samples are clamped
+    let (w, _): (Matrix<i16>, u32) = Matrix::from_wav(&path).unwrap();
+    assert_eq!(w, matrix![8192, -32768]);
+
+    // WAVE_FORMAT_EXTENSIBLE with PCM sub format
+    let mut bytes = wave(0xFFFE, 1, 16, &[1, 0]);
+    let mut fmt = bytes[20..36].to_vec();
+    fmt.extend_from_slice(&22u16.to_le_bytes());
+    fmt.extend_from_slice(&16u16.to_le_bytes());
+    fmt.extend_from_slice(&4u32.to_le_bytes());
+    fmt.extend_from_slice(&1u16.to_le_bytes());
+    fmt.extend_from_slice(&[0; 14]);
+    bytes.splice(16..36, [&40u32.to_le_bytes()[..], &fmt].concat());
+    let size = (bytes.len() - 8) as u32;
+    bytes.splice(4..8, size.to_le_bytes().iter().cloned());
+    fs::write(&path, bytes).unwrap();
+    let (w, _): (Matrix<i16>, u32) = Matrix::from_wav(&path).unwrap();
+    assert_eq!(w, matrix![1]);
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_write_wav() {
+    let path = std::env::temp_dir().join("crabsformer_test_write.wav");
+
+    let w: Matrix<i16> = matrix![0, 1000, -1000; 32767, -32768, 1];
+    w.to_wav(&path, 44100).write().unwrap();
+    let bytes = fs::read(&path).unwrap();
+    assert_eq!(bytes.len(), 44 + 12);
+    assert_eq!(&bytes[24..28], &44100u32.to_le_bytes());
+    assert_eq!(&bytes[44..48], &[0, 0, 0xFF, 0x7F]);
+    let (v, sample_rate): (Matrix<i16>, u32) =
+        Matrix::from_wav(&path).unwrap();
+    assert_eq!((v, sample_rate), (w, 44100));
+
+    // Integer samples are not changed by the bit depth of 16 and more
+    let w: Matrix<i16> = matrix![0, 256, -256, 32767, -32768];
+    for bits in &[16, 24, 32] {
+        w.to_wav(&path, 8000).bit_depth(*bits).write().unwrap();
+        let (v, _): (Matrix<i16>, u32) = Matrix::from_wav(&path).unwrap();
+        assert_eq!(v, w);
+    }
+    // 8-bit audio has odd data size, it is padded
+    w.to_wav(&path, 8000).bit_depth(8).write().unwrap();
+    assert_eq!(fs::metadata(&path).unwrap().len(), 44 + 6);
+    let (v, _): (Matrix<i16>, u32) = Matrix::from_wav(&path).unwrap();
+    assert_eq!(v, matrix![0, 256, -256, 32512, -32768]);
+
+    let w: Matrix<f64> = matrix![0.1, -0.7, 1.5];
+    w.to_wav(&path, 8000).write().unwrap();
+    let (v, _): (Matrix<f64>, u32) = Matrix::from_wav(&path).unwrap();
+    assert_eq!(v, w);
+    w.to_wav(&path, 8000).bit_depth(32).write().unwrap();
+    let (v, _): (Matrix<f32>, u32) = Matrix::from_wav(&path).unwrap();
+    assert_eq!(v, matrix![0.1, -0.7, 1.5]);
+
+    let left: Vector<f32> = vector![0.0, 0.5];
+    let right: Vector<f32> = vector![1.0, -1.0];
+    Vector::to_wav(&[&left, &right], &path, 22050)
+        .float(false)
+        .bit_depth(16)
+        .write()
+        .unwrap();
+    let (channels, sample_rate): (Vec<Vector<i16>>, u32) =
+        Vector::from_wav(&path).unwrap();
+    assert_eq!(sample_rate, 22050);
+    assert_eq!(channels, vec![vector![0, 16384], vector![32767, -32768]]);
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_wav_invalid() {
+    let path = std::env::temp_dir().join("crabsformer_test_invalid.wav");
+    let load = |bytes: &[u8]| -> MatrixLoadError {
+        fs::write(&path, bytes).unwrap();
+        Matrix::<i16>::from_wav(&path).unwrap_err()
+    };
+
+    let error = load(b"");
+    match error.kind() {
+        MatrixLoadErrorKind::Empty => (),
+        _ => panic!("expected empty"),
+    }
+
+    let error = load(b"RIFF\x04\x00\x00\x00AVI ");
+    match error.kind() {
+        MatrixLoadErrorKind::InvalidFormat => (),
+        _ => panic!("expected invalid format"),
+    }
+
+    // ADPCM is not supported
+    let error = load(&wave(2, 1, 16, &[0, 0]));
+    match error.kind() {
+        MatrixLoadErrorKind::InvalidDataType => (),
+        _ => panic!("expected invalid data type"),
+    }
+
+    let error = load(&wave(1, 1, 12, &[0, 0]));
+    match error.kind() {
+        MatrixLoadErrorKind::InvalidDataType => (),
+        _ => panic!("expected invalid data type"),
+    }
+
+    // Partial frame
+    let error = load(&wave(1, 2, 16, &[0, 0, 0]));
+    match error.kind() {
+        MatrixLoadErrorKind::InvalidFormat => (),
+        _ => panic!("expected invalid format"),
+    }
+
+    // Truncated data chunk
+    let mut bytes = wave(1, 1, 16, &[0, 0, 0, 0]);
+    bytes.truncate(bytes.len() - 2);
+    let error = load(&bytes);
+    match error.kind() {
+        MatrixLoadErrorKind::InvalidFormat => (),
+        _ => panic!("expected invalid format"),
+    }
+
+    // Missing data chunk
+    let bytes = wave(1, 1, 16, &[]);
+    let error = load(&bytes[..36]);
+    match error.kind() {
+        MatrixLoadErrorKind::InvalidFormat => (),
+        _ => panic!("expected invalid format"),
+    }
+
+    // Invalid input leaves the existing file untouched
+    fs::write(&path, b"keep").unwrap();
+    let w: Matrix<i16> = matrix![1, 2];
+    let error = w.to_wav(&path, 8000).bit_depth(12).write().unwrap_err();
+    match error.kind() {
+        MatrixLoadErrorKind::InvalidDataType => (),
+        _ => panic!("expected invalid data type"),
+    }
+    assert_eq!(fs::read(&path).unwrap(), b"keep");
+
+    let x: Vector<i16> = vector![1, 2];
+    let y: Vector<i16> = vector![1];
+    let error = Vector::to_wav(&[&x, &y], &path, 8000).write().unwrap_err();
+    match error.kind() {
+        VectorLoadErrorKind::InvalidShape => (),
+        _ => panic!("expected invalid shape"),
+    }
+    assert_eq!(fs::read(&path).unwrap(), b"keep");
+
+    fs::remove_file(&path).ok();
+}